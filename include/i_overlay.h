@@ -0,0 +1,218 @@
+/* i_overlay C API, generated by cbindgen from src/capi. Do not edit. */
+
+#ifndef I_OVERLAY_H
+#define I_OVERLAY_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#define IOVERLAY_FILL_RULE_EVEN_ODD 0
+
+#define IOVERLAY_FILL_RULE_NON_ZERO 1
+
+#define IOVERLAY_FILL_RULE_POSITIVE 2
+
+#define IOVERLAY_FILL_RULE_NEGATIVE 3
+
+#define IOVERLAY_OVERLAY_RULE_SUBJECT 0
+
+#define IOVERLAY_OVERLAY_RULE_CLIP 1
+
+#define IOVERLAY_OVERLAY_RULE_INTERSECT 2
+
+#define IOVERLAY_OVERLAY_RULE_UNION 3
+
+#define IOVERLAY_OVERLAY_RULE_DIFFERENCE 4
+
+#define IOVERLAY_OVERLAY_RULE_INVERSE_DIFFERENCE 5
+
+#define IOVERLAY_OVERLAY_RULE_XOR 6
+
+// Result code of every exported function.
+typedef enum IOverlayStatus {
+  // The operation succeeded.
+  I_OVERLAY_STATUS_OK = 0,
+  // A required pointer is null.
+  I_OVERLAY_STATUS_NULL_POINTER = 1,
+  // A rule is unknown, offsets are out of order, a coordinate is not finite or the result is not empty.
+  I_OVERLAY_STATUS_INVALID_ARGUMENT = 2,
+  // The operation panicked, the output is empty.
+  I_OVERLAY_STATUS_PANIC = 3,
+} IOverlayStatus;
+
+// Integer paths returned by the library, release with `ioverlay_int_paths_result_free`.
+typedef struct IOverlayIntPathsResult {
+  int32_t *xy;
+  size_t points_count;
+  size_t *offsets;
+  size_t paths_count;
+} IOverlayIntPathsResult;
+
+// Integer shapes returned by the library, release with `ioverlay_int_shapes_result_free`.
+// The first path of every shape is the outer contour, the rest are holes.
+typedef struct IOverlayIntShapesResult {
+  int32_t *xy;
+  size_t points_count;
+  size_t *path_offsets;
+  size_t paths_count;
+  size_t *shape_offsets;
+  size_t shapes_count;
+} IOverlayIntShapesResult;
+
+// Float paths returned by the library, release with `ioverlay_float_paths_result_free`.
+typedef struct IOverlayFloatPathsResult {
+  double *xy;
+  size_t points_count;
+  size_t *offsets;
+  size_t paths_count;
+} IOverlayFloatPathsResult;
+
+// Float shapes returned by the library, release with `ioverlay_float_shapes_result_free`.
+// The first path of every shape is the outer contour, the rest are holes.
+typedef struct IOverlayFloatShapesResult {
+  double *xy;
+  size_t points_count;
+  size_t *path_offsets;
+  size_t paths_count;
+  size_t *shape_offsets;
+  size_t shapes_count;
+} IOverlayFloatShapesResult;
+
+// Integer paths, `xy` holds `2 * points_count` values and `offsets` holds `paths_count` values.
+typedef struct IOverlayIntPaths {
+  const int32_t *xy;
+  size_t points_count;
+  const size_t *offsets;
+  size_t paths_count;
+} IOverlayIntPaths;
+
+// Float paths, `xy` holds `2 * points_count` values and `offsets` holds `paths_count` values.
+typedef struct IOverlayFloatPaths {
+  const double *xy;
+  size_t points_count;
+  const size_t *offsets;
+  size_t paths_count;
+} IOverlayFloatPaths;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Releases the buffers of `result` and resets it to empty. Null is ignored.
+//
+// # Safety
+// `result` must be null or point to a result filled by this library.
+void ioverlay_int_paths_result_free(struct IOverlayIntPathsResult *result);
+
+// Releases the buffers of `result` and resets it to empty. Null is ignored.
+//
+// # Safety
+// `result` must be null or point to a result filled by this library.
+void ioverlay_int_shapes_result_free(struct IOverlayIntShapesResult *result);
+
+// Releases the buffers of `result` and resets it to empty. Null is ignored.
+//
+// # Safety
+// `result` must be null or point to a result filled by this library.
+void ioverlay_float_paths_result_free(struct IOverlayFloatPathsResult *result);
+
+// Releases the buffers of `result` and resets it to empty. Null is ignored.
+//
+// # Safety
+// `result` must be null or point to a result filled by this library.
+void ioverlay_float_shapes_result_free(struct IOverlayFloatShapesResult *result);
+
+// Applies a boolean operation to subject and clip contours.
+// - `subj`, `clip`: The subject and clip contours.
+// - `overlay_rule`: One of `IOVERLAY_OVERLAY_RULE_*`.
+// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+// - `result`: Receives the shapes, release it with `ioverlay_int_shapes_result_free`.
+//
+// # Safety
+// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+enum IOverlayStatus ioverlay_int_overlay(struct IOverlayIntPaths subj,
+                                         struct IOverlayIntPaths clip,
+                                         uint32_t overlay_rule,
+                                         uint32_t fill_rule,
+                                         struct IOverlayIntShapesResult *result);
+
+// Clips string lines by shape contours.
+// - `lines`: The open paths to clip.
+// - `shape`: The contours of the clipping area.
+// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+// - `invert`: Keeps the parts outside of the area instead of inside.
+// - `boundary_included`: Treats the parts on the area boundary as inside.
+// - `result`: Receives the clipped paths, release it with `ioverlay_int_paths_result_free`.
+//
+// # Safety
+// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+enum IOverlayStatus ioverlay_int_clip(struct IOverlayIntPaths lines,
+                                      struct IOverlayIntPaths shape,
+                                      uint32_t fill_rule,
+                                      bool invert,
+                                      bool boundary_included,
+                                      struct IOverlayIntPathsResult *result);
+
+// Slices shape contours by string lines.
+// - `shape`: The contours to slice.
+// - `lines`: The open paths to slice by.
+// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+// - `result`: Receives the shapes, release it with `ioverlay_int_shapes_result_free`.
+//
+// # Safety
+// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+enum IOverlayStatus ioverlay_int_slice(struct IOverlayIntPaths shape,
+                                       struct IOverlayIntPaths lines,
+                                       uint32_t fill_rule,
+                                       struct IOverlayIntShapesResult *result);
+
+// Applies a boolean operation to subject and clip contours.
+// - `subj`, `clip`: The subject and clip contours.
+// - `overlay_rule`: One of `IOVERLAY_OVERLAY_RULE_*`.
+// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+// - `result`: Receives the shapes, release it with `ioverlay_float_shapes_result_free`.
+//
+// # Safety
+// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+enum IOverlayStatus ioverlay_float_overlay(struct IOverlayFloatPaths subj,
+                                           struct IOverlayFloatPaths clip,
+                                           uint32_t overlay_rule,
+                                           uint32_t fill_rule,
+                                           struct IOverlayFloatShapesResult *result);
+
+// Clips string lines by shape contours.
+// - `lines`: The open paths to clip.
+// - `shape`: The contours of the clipping area.
+// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+// - `invert`: Keeps the parts outside of the area instead of inside.
+// - `boundary_included`: Treats the parts on the area boundary as inside.
+// - `result`: Receives the clipped paths, release it with `ioverlay_float_paths_result_free`.
+//
+// # Safety
+// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+enum IOverlayStatus ioverlay_float_clip(struct IOverlayFloatPaths lines,
+                                        struct IOverlayFloatPaths shape,
+                                        uint32_t fill_rule,
+                                        bool invert,
+                                        bool boundary_included,
+                                        struct IOverlayFloatPathsResult *result);
+
+// Slices shape contours by string lines.
+// - `shape`: The contours to slice.
+// - `lines`: The open paths to slice by.
+// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+// - `result`: Receives the shapes, release it with `ioverlay_float_shapes_result_free`.
+//
+// # Safety
+// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+enum IOverlayStatus ioverlay_float_slice(struct IOverlayFloatPaths shape,
+                                         struct IOverlayFloatPaths lines,
+                                         uint32_t fill_rule,
+                                         struct IOverlayFloatShapesResult *result);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* I_OVERLAY_H */
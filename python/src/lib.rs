@@ -0,0 +1,236 @@
+//! Python bindings for `i_overlay`.
+//!
+//! Contours and lines are numpy arrays of shape `(n, 2)`, shapes are lists of contours
+//! where the first one is the outer contour and the rest are holes.
+//! All operations use the same float API as the Rust crate.
+
+use i_overlay::core::fill_rule;
+use i_overlay::core::overlay_rule;
+use i_overlay::float::clip::FloatClip;
+use i_overlay::float::simplify::SimplifyShape;
+use i_overlay::float::single::SingleFloatOverlay;
+use i_overlay::float::slice::FloatSlice;
+use i_overlay::i_shape::base::data::{Path, Paths, Shapes};
+use i_overlay::string::clip::ClipRule;
+use numpy::ndarray::{Array2, ArrayView2};
+use numpy::{IntoPyArray, PyArray2, PyReadonlyArray2};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Fill rule to determine filled areas.
+#[pyclass(eq, eq_int, frozen, module = "i_overlay")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FillRule {
+    EvenOdd,
+    NonZero,
+    Positive,
+    Negative,
+}
+
+/// Boolean operation to apply.
+#[pyclass(eq, eq_int, frozen, module = "i_overlay")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OverlayRule {
+    Subject,
+    Clip,
+    Intersect,
+    Union,
+    Difference,
+    InverseDifference,
+    Xor,
+}
+
+impl From<FillRule> for fill_rule::FillRule {
+    fn from(rule: FillRule) -> Self {
+        match rule {
+            FillRule::EvenOdd => fill_rule::FillRule::EvenOdd,
+            FillRule::NonZero => fill_rule::FillRule::NonZero,
+            FillRule::Positive => fill_rule::FillRule::Positive,
+            FillRule::Negative => fill_rule::FillRule::Negative,
+        }
+    }
+}
+
+impl From<OverlayRule> for overlay_rule::OverlayRule {
+    fn from(rule: OverlayRule) -> Self {
+        match rule {
+            OverlayRule::Subject => overlay_rule::OverlayRule::Subject,
+            OverlayRule::Clip => overlay_rule::OverlayRule::Clip,
+            OverlayRule::Intersect => overlay_rule::OverlayRule::Intersect,
+            OverlayRule::Union => overlay_rule::OverlayRule::Union,
+            OverlayRule::Difference => overlay_rule::OverlayRule::Difference,
+            OverlayRule::InverseDifference => overlay_rule::OverlayRule::InverseDifference,
+            OverlayRule::Xor => overlay_rule::OverlayRule::Xor,
+        }
+    }
+}
+
+/// Applies a boolean operation between subject and clip contours.
+///
+/// Returns a list of shapes, every shape is a list of `(n, 2)` arrays.
+#[pyfunction]
+#[pyo3(signature = (subj, clip, overlay_rule, fill_rule = FillRule::NonZero))]
+fn overlay<'py>(
+    py: Python<'py>,
+    subj: Vec<PyReadonlyArray2<'py, f64>>,
+    clip: Vec<PyReadonlyArray2<'py, f64>>,
+    overlay_rule: OverlayRule,
+    fill_rule: FillRule,
+) -> PyResult<Vec<Vec<Bound<'py, PyArray2<f64>>>>> {
+    let subj = read_paths(&subj)?;
+    let clip = read_paths(&clip)?;
+    if is_empty(&subj) && is_empty(&clip) {
+        return Ok(Vec::new());
+    }
+
+    let shapes = py.detach(|| subj.overlay(&clip, overlay_rule.into(), fill_rule.into()));
+    Ok(write_shapes(py, shapes))
+}
+
+/// Resolves self-intersections and removes collinear points and contours smaller than `min_area`.
+///
+/// Returns a list of shapes, every shape is a list of `(n, 2)` arrays.
+#[pyfunction]
+#[pyo3(signature = (contours, fill_rule = FillRule::NonZero, min_area = 0.0))]
+fn simplify_shape<'py>(
+    py: Python<'py>,
+    contours: Vec<PyReadonlyArray2<'py, f64>>,
+    fill_rule: FillRule,
+    min_area: f64,
+) -> PyResult<Vec<Vec<Bound<'py, PyArray2<f64>>>>> {
+    let contours = read_paths(&contours)?;
+    if is_empty(&contours) {
+        return Ok(Vec::new());
+    }
+
+    let shapes = py.detach(|| contours.simplify_shape(fill_rule.into(), min_area));
+    Ok(write_shapes(py, shapes))
+}
+
+/// Clips lines by the area of the shape contours.
+///
+/// Returns a list of `(n, 2)` arrays.
+#[pyfunction]
+#[pyo3(signature = (lines, shape, fill_rule = FillRule::NonZero, invert = false, boundary_included = false))]
+fn clip_by<'py>(
+    py: Python<'py>,
+    lines: Vec<PyReadonlyArray2<'py, f64>>,
+    shape: Vec<PyReadonlyArray2<'py, f64>>,
+    fill_rule: FillRule,
+    invert: bool,
+    boundary_included: bool,
+) -> PyResult<Vec<Bound<'py, PyArray2<f64>>>> {
+    let lines = read_paths(&lines)?;
+    let shape = read_paths(&shape)?;
+    if is_empty(&lines) {
+        return Ok(Vec::new());
+    }
+
+    let clip_rule = ClipRule { invert, boundary_included };
+    let paths = py.detach(|| lines.clip_by(&shape, fill_rule.into(), clip_rule));
+    Ok(paths.into_iter().map(|path| write_path(py, path)).collect())
+}
+
+/// Slices the shape contours by lines.
+///
+/// Returns a list of shapes, every shape is a list of `(n, 2)` arrays.
+#[pyfunction]
+#[pyo3(signature = (shape, lines, fill_rule = FillRule::NonZero))]
+fn slice_by<'py>(
+    py: Python<'py>,
+    shape: Vec<PyReadonlyArray2<'py, f64>>,
+    lines: Vec<PyReadonlyArray2<'py, f64>>,
+    fill_rule: FillRule,
+) -> PyResult<Vec<Vec<Bound<'py, PyArray2<f64>>>>> {
+    let shape = read_paths(&shape)?;
+    let lines = read_paths(&lines)?;
+    if is_empty(&shape) {
+        return Ok(Vec::new());
+    }
+
+    let shapes = py.detach(|| shape.slice_by(&lines, fill_rule.into()));
+    Ok(write_shapes(py, shapes))
+}
+
+fn read_paths(arrays: &[PyReadonlyArray2<'_, f64>]) -> PyResult<Paths<[f64; 2]>> {
+    arrays.iter()
+        .map(|array| read_path(array.as_array()).map_err(PyValueError::new_err))
+        .collect()
+}
+
+/// Reads an `(n, 2)` array of finite values.
+fn read_path(array: ArrayView2<'_, f64>) -> Result<Path<[f64; 2]>, String> {
+    if array.ncols() != 2 {
+        return Err(format!("expected an array of shape (n, 2), got {:?}", array.shape()));
+    }
+
+    let path: Path<[f64; 2]> = array.rows().into_iter().map(|row| [row[0], row[1]]).collect();
+    if path.iter().flatten().all(|v| v.is_finite()) {
+        Ok(path)
+    } else {
+        Err("coordinates must be finite".to_string())
+    }
+}
+
+#[inline]
+fn is_empty(paths: &Paths<[f64; 2]>) -> bool {
+    paths.iter().all(|path| path.is_empty())
+}
+
+fn write_shapes(py: Python<'_>, shapes: Shapes<[f64; 2]>) -> Vec<Vec<Bound<'_, PyArray2<f64>>>> {
+    shapes.into_iter()
+        .map(|shape| shape.into_iter().map(|path| write_path(py, path)).collect())
+        .collect()
+}
+
+#[inline]
+fn write_path(py: Python<'_>, path: Path<[f64; 2]>) -> Bound<'_, PyArray2<f64>> {
+    to_array(path).into_pyarray(py)
+}
+
+fn to_array(path: Path<[f64; 2]>) -> Array2<f64> {
+    let n = path.len();
+    let flat: Vec<f64> = path.into_iter().flatten().collect();
+    Array2::from_shape_vec((n, 2), flat).expect("the length is 2 * n")
+}
+
+#[pymodule]
+#[pyo3(name = "i_overlay")]
+fn py_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<FillRule>()?;
+    m.add_class::<OverlayRule>()?;
+    m.add_function(wrap_pyfunction!(overlay, m)?)?;
+    m.add_function(wrap_pyfunction!(simplify_shape, m)?)?;
+    m.add_function(wrap_pyfunction!(clip_by, m)?)?;
+    m.add_function(wrap_pyfunction!(slice_by, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use numpy::ndarray::{array, Array2};
+    use crate::{read_path, to_array};
+
+    #[test]
+    fn test_read_path() {
+        let array = array![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0]];
+        assert_eq!(read_path(array.view()).unwrap(), vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+
+        // a transposed view is not contiguous
+        let transposed = array![[0.0, 1.0], [2.0, 3.0]];
+        assert_eq!(read_path(transposed.t()).unwrap(), vec![[0.0, 2.0], [1.0, 3.0]]);
+
+        let wrong_shape = Array2::<f64>::zeros((3, 3));
+        assert!(read_path(wrong_shape.view()).is_err());
+
+        let nan = array![[0.0, f64::NAN]];
+        assert!(read_path(nan.view()).is_err());
+    }
+
+    #[test]
+    fn test_to_array() {
+        let array = to_array(vec![[0.0, 1.0], [2.0, 3.0]]);
+        assert_eq!(array, array![[0.0, 1.0], [2.0, 3.0]]);
+        assert_eq!(to_array(Vec::new()).shape(), &[0, 2]);
+    }
+}
@@ -0,0 +1,56 @@
+import numpy as np
+import pytest
+
+import i_overlay as io
+
+
+def square(x, y, size):
+    return np.array([[x, y], [x, y + size], [x + size, y + size], [x + size, y]], dtype=np.float64)
+
+
+def area(contour):
+    x, y = contour[:, 0], contour[:, 1]
+    return 0.5 * abs(np.dot(x, np.roll(y, -1)) - np.dot(y, np.roll(x, -1)))
+
+
+def test_union():
+    shapes = io.overlay([square(0, 0, 2)], [square(1, 1, 2)], io.OverlayRule.Union)
+    assert len(shapes) == 1
+    assert len(shapes[0]) == 1
+    assert shapes[0][0].shape == (8, 2)
+    assert area(shapes[0][0]) == pytest.approx(7.0)
+
+
+def test_difference_with_hole():
+    shapes = io.overlay([square(0, 0, 4)], [square(1, 1, 2)], io.OverlayRule.Difference, io.FillRule.EvenOdd)
+    assert len(shapes) == 1
+    assert len(shapes[0]) == 2
+
+
+def test_empty():
+    assert io.overlay([], [], io.OverlayRule.Union) == []
+    assert io.simplify_shape([]) == []
+
+
+def test_simplify_shape():
+    shapes = io.simplify_shape([square(0, 0, 2), square(1, 1, 2)])
+    assert len(shapes) == 1
+    assert area(shapes[0][0]) == pytest.approx(7.0)
+
+
+def test_clip_by():
+    lines = io.clip_by([np.array([[-1.0, 1.0], [3.0, 1.0]])], [square(0, 0, 2)])
+    assert len(lines) == 1
+    assert sorted(lines[0][:, 0].tolist()) == [0.0, 2.0]
+
+
+def test_slice_by():
+    shapes = io.slice_by([square(0, 0, 2)], [np.array([[1.0, -1.0], [1.0, 3.0]])])
+    assert len(shapes) == 2
+
+
+def test_invalid_input():
+    with pytest.raises(ValueError):
+        io.overlay([np.zeros((3, 3))], [], io.OverlayRule.Union)
+    with pytest.raises(ValueError):
+        io.overlay([np.array([[0.0, np.nan]])], [], io.OverlayRule.Union)
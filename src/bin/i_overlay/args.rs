@@ -0,0 +1,292 @@
+use std::path::PathBuf;
+use i_overlay::core::fill_rule::FillRule;
+use i_overlay::core::overlay_rule::OverlayRule;
+use i_overlay::core::solver::{Precision, Solver, Strategy};
+
+pub const USAGE: &str = "\
+Usage:
+  i_overlay overlay <rule> [options] <input> [<clip>]
+  i_overlay regress [options] [<dir>]
+
+Commands:
+  overlay   Applies a boolean operation and writes the result.
+            JSON input is a single file with `subjPaths` and `clipPaths` (the layout of `tests/boolean`).
+            GeoJSON input is a subject file and an optional clip file with Polygon/MultiPolygon geometries.
+  regress   Re-runs every `test_*.json` of the corpus (default `tests/boolean`) with all overlay rules
+            and reports the results that differ from the expected ones.
+
+Rules:
+  subject, clip, intersect, union, difference, inverse-difference, xor
+
+Options:
+  --fill <rule>        even-odd, non-zero, positive or negative.
+                       Default: `fillRule` of a JSON file or even-odd, non-zero for GeoJSON.
+  --solver <strategy>  auto, list, tree or frag. `regress` uses all of them by default.
+  --precision <mode>   auto, absolute, average or snap-rounding. Default: auto.
+  --format <format>    json or geojson. Default: by the input file extension.
+  -o, --output <file>  Writes the result to a file instead of stdout.
+  --repeat <count>     Runs the operation several times to measure the time. Default: 1.
+  -v, --verbose        Prints the actual result of every failed regression check.
+  -h, --help           Prints this message.
+";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Json,
+    GeoJson,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Overlay(OverlayArgs),
+    Regress(RegressArgs),
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct OverlayArgs {
+    pub overlay_rule: OverlayRule,
+    pub fill_rule: Option<FillRule>,
+    pub strategy: Strategy,
+    pub precision: Precision,
+    pub format: Option<Format>,
+    pub inputs: Vec<PathBuf>,
+    pub output: Option<PathBuf>,
+    pub repeat: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RegressArgs {
+    pub dir: PathBuf,
+    pub strategies: Vec<Strategy>,
+    pub precision: Precision,
+    pub verbose: bool,
+}
+
+impl OverlayArgs {
+    pub fn solver(&self) -> Solver {
+        solver(self.strategy, self.precision)
+    }
+
+    pub fn format(&self) -> Format {
+        if let Some(format) = self.format {
+            return format;
+        }
+
+        let is_geojson = self.inputs.first()
+            .and_then(|path| path.extension())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("geojson"));
+
+        if is_geojson { Format::GeoJson } else { Format::Json }
+    }
+}
+
+pub fn solver(strategy: Strategy, precision: Precision) -> Solver {
+    Solver { strategy, precision, ..Default::default() }
+}
+
+pub fn parse<I: Iterator<Item=String>>(mut args: I) -> Result<Command, String> {
+    let command = match args.next() {
+        Some(command) => command,
+        None => return Ok(Command::Help),
+    };
+
+    match command.as_str() {
+        "overlay" => parse_overlay(args),
+        "regress" => parse_regress(args),
+        "-h" | "--help" | "help" => Ok(Command::Help),
+        _ => Err(format!("unknown command `{}`", command)),
+    }
+}
+
+fn parse_overlay<I: Iterator<Item=String>>(mut args: I) -> Result<Command, String> {
+    let mut overlay_rule = None;
+    let mut fill_rule = None;
+    let mut strategy = Strategy::Auto;
+    let mut precision = Precision::Auto;
+    let mut format = None;
+    let mut inputs = Vec::new();
+    let mut output = None;
+    let mut repeat = 1;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--fill" => fill_rule = Some(parse_fill_rule(&value(&mut args, &arg)?)?),
+            "--solver" => strategy = parse_strategy(&value(&mut args, &arg)?)?,
+            "--precision" => precision = parse_precision(&value(&mut args, &arg)?)?,
+            "--format" => format = Some(parse_format(&value(&mut args, &arg)?)?),
+            "-o" | "--output" => output = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--repeat" => {
+                let text = value(&mut args, &arg)?;
+                repeat = match text.parse::<usize>() {
+                    Ok(count) if count > 0 => count,
+                    _ => return Err(format!("invalid repeat count `{}`", text)),
+                };
+            }
+            "-h" | "--help" => return Ok(Command::Help),
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{}`", arg)),
+            _ if overlay_rule.is_none() => overlay_rule = Some(parse_overlay_rule(&arg)?),
+            _ => inputs.push(PathBuf::from(arg)),
+        }
+    }
+
+    let overlay_rule = overlay_rule.ok_or("missing overlay rule")?;
+    if inputs.is_empty() {
+        return Err("missing input file".to_string());
+    }
+    if inputs.len() > 2 {
+        return Err("expected at most two input files".to_string());
+    }
+
+    Ok(Command::Overlay(OverlayArgs { overlay_rule, fill_rule, strategy, precision, format, inputs, output, repeat }))
+}
+
+fn parse_regress<I: Iterator<Item=String>>(mut args: I) -> Result<Command, String> {
+    let mut dir = None;
+    let mut strategies = vec![Strategy::List, Strategy::Tree, Strategy::Frag, Strategy::Auto];
+    let mut precision = Precision::Auto;
+    let mut verbose = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--solver" => strategies = vec![parse_strategy(&value(&mut args, &arg)?)?],
+            "--precision" => precision = parse_precision(&value(&mut args, &arg)?)?,
+            "-v" | "--verbose" => verbose = true,
+            "-h" | "--help" => return Ok(Command::Help),
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{}`", arg)),
+            _ if dir.is_none() => dir = Some(PathBuf::from(arg)),
+            _ => return Err(format!("unexpected argument `{}`", arg)),
+        }
+    }
+
+    let dir = dir.unwrap_or_else(|| PathBuf::from("tests/boolean"));
+
+    Ok(Command::Regress(RegressArgs { dir, strategies, precision, verbose }))
+}
+
+fn value<I: Iterator<Item=String>>(args: &mut I, option: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("missing value for `{}`", option))
+}
+
+/// Lowercases and drops `-` and `_`, so `inverse-difference`, `InverseDifference` and `inverse_difference` are the same.
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+pub fn parse_overlay_rule(text: &str) -> Result<OverlayRule, String> {
+    match normalize(text).as_str() {
+        "subject" => Ok(OverlayRule::Subject),
+        "clip" => Ok(OverlayRule::Clip),
+        "intersect" => Ok(OverlayRule::Intersect),
+        "union" => Ok(OverlayRule::Union),
+        "difference" => Ok(OverlayRule::Difference),
+        "inversedifference" => Ok(OverlayRule::InverseDifference),
+        "xor" => Ok(OverlayRule::Xor),
+        _ => Err(format!("unknown overlay rule `{}`", text)),
+    }
+}
+
+pub fn parse_fill_rule(text: &str) -> Result<FillRule, String> {
+    match normalize(text).as_str() {
+        "evenodd" => Ok(FillRule::EvenOdd),
+        "nonzero" => Ok(FillRule::NonZero),
+        "positive" => Ok(FillRule::Positive),
+        "negative" => Ok(FillRule::Negative),
+        _ => Err(format!("unknown fill rule `{}`", text)),
+    }
+}
+
+fn parse_strategy(text: &str) -> Result<Strategy, String> {
+    match normalize(text).as_str() {
+        "auto" => Ok(Strategy::Auto),
+        "list" => Ok(Strategy::List),
+        "tree" => Ok(Strategy::Tree),
+        "frag" => Ok(Strategy::Frag),
+        _ => Err(format!("unknown solver `{}`", text)),
+    }
+}
+
+fn parse_precision(text: &str) -> Result<Precision, String> {
+    match normalize(text).as_str() {
+        "auto" => Ok(Precision::Auto),
+        "absolute" => Ok(Precision::Absolute),
+        "average" => Ok(Precision::Average),
+        "snaprounding" => Ok(Precision::SnapRounding),
+        _ => Err(format!("unknown precision `{}`", text)),
+    }
+}
+
+fn parse_format(text: &str) -> Result<Format, String> {
+    match normalize(text).as_str() {
+        "json" => Ok(Format::Json),
+        "geojson" => Ok(Format::GeoJson),
+        _ => Err(format!("unknown format `{}`", text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::{Precision, Strategy};
+    use crate::args::{parse, Command, Format, OverlayArgs, RegressArgs};
+
+    fn run(args: &[&str]) -> Result<Command, String> {
+        parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_overlay() {
+        let command = run(&["overlay", "inverse-difference", "--fill", "NonZero", "--solver", "tree", "a.geojson", "b.geojson", "-o", "c.geojson"]).unwrap();
+        let Command::Overlay(args) = command else { panic!("expected overlay") };
+
+        assert_eq!(args, OverlayArgs {
+            overlay_rule: OverlayRule::InverseDifference,
+            fill_rule: Some(FillRule::NonZero),
+            strategy: Strategy::Tree,
+            precision: Precision::Auto,
+            format: None,
+            inputs: vec![PathBuf::from("a.geojson"), PathBuf::from("b.geojson")],
+            output: Some(PathBuf::from("c.geojson")),
+            repeat: 1,
+        });
+        assert_eq!(args.format(), Format::GeoJson);
+    }
+
+    #[test]
+    fn test_overlay_format() {
+        let Command::Overlay(args) = run(&["overlay", "union", "test_0.json"]).unwrap() else { panic!("expected overlay") };
+        assert_eq!(args.format(), Format::Json);
+
+        let Command::Overlay(args) = run(&["overlay", "union", "--format", "geojson", "a.txt"]).unwrap() else { panic!("expected overlay") };
+        assert_eq!(args.format(), Format::GeoJson);
+    }
+
+    #[test]
+    fn test_regress() {
+        let command = run(&["regress", "--solver", "list", "-v"]).unwrap();
+        assert_eq!(command, Command::Regress(RegressArgs {
+            dir: PathBuf::from("tests/boolean"),
+            strategies: vec![Strategy::List],
+            precision: Precision::Auto,
+            verbose: true,
+        }));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(run(&["overlay"]).is_err());
+        assert!(run(&["overlay", "union"]).is_err());
+        assert!(run(&["overlay", "merge", "a.json"]).is_err());
+        assert!(run(&["overlay", "union", "a.json", "--repeat", "0"]).is_err());
+        assert!(run(&["overlay", "union", "a.json", "--fill"]).is_err());
+        assert!(run(&["overlay", "union", "a", "b", "c"]).is_err());
+        assert!(run(&["regress", "--unknown"]).is_err());
+        assert!(run(&["split"]).is_err());
+        assert_eq!(run(&[]).unwrap(), Command::Help);
+    }
+}
@@ -0,0 +1,133 @@
+use std::fs;
+use std::path::Path;
+use i_overlay::core::fill_rule::FillRule;
+use i_overlay::core::overlay_rule::OverlayRule;
+use i_overlay::i_shape::int::shape::{IntContour, IntShapes};
+use serde::{Deserialize, Deserializer};
+
+/// A boolean test case in the layout of `tests/boolean/*.json`.
+///
+/// Every expected field holds all acceptable variants of the result.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BooleanCase {
+    #[serde(default, deserialize_with = "deserialize_fill_rule")]
+    pub fill_rule: Option<FillRule>,
+    pub subj_paths: Vec<IntContour>,
+    pub clip_paths: Vec<IntContour>,
+    #[serde(default)]
+    pub subject: Vec<IntShapes>,
+    #[serde(default)]
+    pub clip: Vec<IntShapes>,
+    #[serde(default)]
+    pub intersect: Vec<IntShapes>,
+    #[serde(default)]
+    pub union: Vec<IntShapes>,
+    #[serde(default)]
+    pub difference: Vec<IntShapes>,
+    #[serde(default)]
+    pub inverse_difference: Vec<IntShapes>,
+    #[serde(default)]
+    pub xor: Vec<IntShapes>,
+}
+
+/// The corpus stores the rule as an index (`0` even-odd, `1` non-zero, ...) or by its name.
+fn deserialize_fill_rule<'de, D>(deserializer: D) -> Result<Option<FillRule>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Index(u32),
+        Rule(FillRule),
+    }
+
+    match Option::<Value>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Value::Rule(fill_rule)) => Ok(Some(fill_rule)),
+        Some(Value::Index(0)) => Ok(Some(FillRule::EvenOdd)),
+        Some(Value::Index(1)) => Ok(Some(FillRule::NonZero)),
+        Some(Value::Index(2)) => Ok(Some(FillRule::Positive)),
+        Some(Value::Index(3)) => Ok(Some(FillRule::Negative)),
+        Some(Value::Index(index)) => Err(serde::de::Error::custom(format!("invalid fillRule {}", index))),
+    }
+}
+
+pub const OVERLAY_RULES: [OverlayRule; 7] = [
+    OverlayRule::Subject,
+    OverlayRule::Clip,
+    OverlayRule::Intersect,
+    OverlayRule::Union,
+    OverlayRule::Difference,
+    OverlayRule::InverseDifference,
+    OverlayRule::Xor,
+];
+
+impl BooleanCase {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// A missing value means even-odd.
+    pub fn fill_rule(&self) -> FillRule {
+        self.fill_rule.unwrap_or(FillRule::EvenOdd)
+    }
+
+    pub fn expected(&self, overlay_rule: OverlayRule) -> &[IntShapes] {
+        match overlay_rule {
+            OverlayRule::Subject => &self.subject,
+            OverlayRule::Clip => &self.clip,
+            OverlayRule::Intersect => &self.intersect,
+            OverlayRule::Union => &self.union,
+            OverlayRule::Difference => &self.difference,
+            OverlayRule::InverseDifference => &self.inverse_difference,
+            OverlayRule::Xor => &self.xor,
+        }
+    }
+}
+
+/// The corpus key of a result, e.g. `inverseDifference`.
+pub fn key(overlay_rule: OverlayRule) -> &'static str {
+    match overlay_rule {
+        OverlayRule::Subject => "subject",
+        OverlayRule::Clip => "clip",
+        OverlayRule::Intersect => "intersect",
+        OverlayRule::Union => "union",
+        OverlayRule::Difference => "difference",
+        OverlayRule::InverseDifference => "inverseDifference",
+        OverlayRule::Xor => "xor",
+    }
+}
+
+/// Writes shapes with `[x, y]` points like the corpus does.
+pub fn shapes_to_json(shapes: &IntShapes) -> String {
+    let shapes: Vec<Vec<Vec<[i32; 2]>>> = shapes.iter()
+        .map(|shape| shape.iter()
+            .map(|contour| contour.iter().map(|p| [p.x, p.y]).collect())
+            .collect())
+        .collect();
+
+    serde_json::to_string(&shapes).expect("plain arrays are serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use i_overlay::core::fill_rule::FillRule;
+    use crate::corpus::BooleanCase;
+
+    fn fill_rule(json: &str) -> Result<FillRule, serde_json::Error> {
+        let text = format!(r#"{{ {} "subjPaths": [], "clipPaths": [] }}"#, json);
+        serde_json::from_str::<BooleanCase>(&text).map(|case| case.fill_rule())
+    }
+
+    #[test]
+    fn test_fill_rule() {
+        assert_eq!(fill_rule(r#""fillRule": 0,"#).unwrap(), FillRule::EvenOdd);
+        assert_eq!(fill_rule(r#""fillRule": 1,"#).unwrap(), FillRule::NonZero);
+        assert_eq!(fill_rule(r#""fillRule": "Positive","#).unwrap(), FillRule::Positive);
+        assert_eq!(fill_rule("").unwrap(), FillRule::EvenOdd);
+        assert!(fill_rule(r#""fillRule": 7,"#).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+//! Command-line tool for boolean operations on files.
+//!
+//! Run `i_overlay --help` for the usage.
+
+mod args;
+mod corpus;
+mod regress;
+
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+use i_overlay::core::fill_rule::FillRule;
+use i_overlay::core::overlay::Overlay;
+use i_overlay::float::filter::ContourFilter;
+use i_overlay::float::overlay::FloatOverlay;
+use i_overlay::i_shape::base::data::Shapes;
+use i_overlay::io::geojson::{shapes_from_geojson, shapes_to_geojson};
+use crate::args::{Command, Format, OverlayArgs, USAGE};
+use crate::corpus::{key, shapes_to_json, BooleanCase};
+
+fn main() -> ExitCode {
+    let command = match args::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match command {
+        Command::Overlay(args) => overlay(&args).map(|_| true),
+        Command::Regress(args) => regress::run(&args),
+        Command::Help => {
+            print!("{}", USAGE);
+            Ok(true)
+        }
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Counts of the input or the result.
+struct Stats {
+    shapes: usize,
+    contours: usize,
+    points: usize,
+}
+
+impl Stats {
+    fn of<'a, P: 'a, I>(shapes: usize, contours: I) -> Self
+    where
+        I: Iterator<Item=&'a Vec<P>> + Clone,
+    {
+        Self {
+            shapes,
+            contours: contours.clone().count(),
+            points: contours.map(|contour| contour.len()).sum(),
+        }
+    }
+
+    fn of_shapes<P>(shapes: &Shapes<P>) -> Self {
+        Self::of(shapes.len(), shapes.iter().flatten())
+    }
+}
+
+/// The written result with the statistics of a run.
+struct Report {
+    text: String,
+    input: Stats,
+    result: Stats,
+    load: Duration,
+    times: Vec<Duration>,
+}
+
+fn overlay(args: &OverlayArgs) -> Result<(), String> {
+    let report = match args.format() {
+        Format::Json => overlay_json(args)?,
+        Format::GeoJson => overlay_geojson(args)?,
+    };
+
+    match &args.output {
+        Some(path) => fs::write(path, &report.text).map_err(|e| format!("{}: {}", path.display(), e))?,
+        None => print!("{}", report.text),
+    }
+
+    let Report { input, result, load, times, .. } = report;
+    eprintln!("input: {} contours, {} points", input.contours, input.points);
+    eprintln!("result: {} shapes, {} contours, {} points", result.shapes, result.contours, result.points);
+    eprintln!("load: {:.3} ms", millis(load));
+
+    let min = times.iter().min().copied().unwrap_or_default();
+    let mean = times.iter().sum::<Duration>() / times.len() as u32;
+    eprintln!("overlay: {:.3} ms (min), {:.3} ms (mean) over {} runs", millis(min), millis(mean), times.len());
+
+    Ok(())
+}
+
+fn overlay_json(args: &OverlayArgs) -> Result<Report, String> {
+    if args.inputs.len() > 1 {
+        return Err("a JSON file holds both subjPaths and clipPaths, expected one input".to_string());
+    }
+
+    let start = Instant::now();
+    let case = BooleanCase::load(&args.inputs[0])?;
+    let fill_rule = match args.fill_rule {
+        Some(fill_rule) => fill_rule,
+        None => case.fill_rule(),
+    };
+    let load = start.elapsed();
+
+    let solver = args.solver();
+    let (shapes, times) = measure(args.repeat, || {
+        Overlay::with_contours(&case.subj_paths, &case.clip_paths)
+            .overlay_with_min_area_and_solver(args.overlay_rule, fill_rule, 0, solver)
+    });
+
+    // the same layout as an expected result of the corpus
+    let text = format!("{{\"{}\":[{}]}}\n", key(args.overlay_rule), shapes_to_json(&shapes));
+    let input = Stats::of(0, case.subj_paths.iter().chain(case.clip_paths.iter()));
+
+    Ok(Report { text, input, result: Stats::of_shapes(&shapes), load, times })
+}
+
+fn overlay_geojson(args: &OverlayArgs) -> Result<Report, String> {
+    let start = Instant::now();
+    let subj = read_geojson(&args.inputs[0])?;
+    let clip = match args.inputs.get(1) {
+        Some(path) => read_geojson(path)?,
+        None => Vec::new(),
+    };
+    let fill_rule = args.fill_rule.unwrap_or(FillRule::NonZero);
+    let load = start.elapsed();
+
+    let solver = args.solver();
+    let (shapes, times) = measure(args.repeat, || {
+        FloatOverlay::with_subj_and_clip(&subj, &clip)
+            .overlay_with_filter_and_solver(args.overlay_rule, fill_rule, ContourFilter::default(), solver)
+    });
+
+    let text = shapes_to_geojson(&shapes) + "\n";
+    let input = Stats::of(subj.len() + clip.len(), subj.iter().chain(clip.iter()).flatten());
+
+    Ok(Report { text, input, result: Stats::of_shapes(&shapes), load, times })
+}
+
+fn read_geojson(path: &Path) -> Result<Shapes<[f64; 2]>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    shapes_from_geojson(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Runs `operation` `repeat` times and returns the last result with the time of every run.
+fn measure<R, F: Fn() -> R>(repeat: usize, operation: F) -> (R, Vec<Duration>) {
+    let mut times = Vec::with_capacity(repeat);
+    let mut result = None;
+    for _ in 0..repeat {
+        let start = Instant::now();
+        result = Some(operation());
+        times.push(start.elapsed());
+    }
+
+    (result.expect("repeat is positive"), times)
+}
+
+#[inline]
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
@@ -0,0 +1,103 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use i_overlay::core::canonical::{Canonical, PointSetEq};
+use i_overlay::core::overlay::Overlay;
+use i_overlay::i_shape::int::shape::IntShapes;
+use crate::args::{solver, RegressArgs};
+use crate::corpus::{key, shapes_to_json, BooleanCase, OVERLAY_RULES};
+
+/// Result of a single check that differs from the corpus.
+enum Mismatch {
+    /// The same area is covered, but the contours differ from every expected variant.
+    Representation,
+    /// The covered area differs.
+    Area,
+}
+
+/// Runs every case of the corpus and returns `true` if all results match.
+pub fn run(args: &RegressArgs) -> Result<bool, String> {
+    let files = case_files(&args.dir)?;
+    if files.is_empty() {
+        return Err(format!("no test_*.json files in {}", args.dir.display()));
+    }
+
+    let start = Instant::now();
+    let mut checks = 0;
+    let mut failed = 0;
+
+    for (path, name) in files.iter() {
+        let case = BooleanCase::load(path)?;
+        let fill_rule = case.fill_rule();
+        for &strategy in args.strategies.iter() {
+            let overlay = Overlay::with_contours(&case.subj_paths, &case.clip_paths);
+            let graph = overlay.into_graph_with_solver(fill_rule, solver(strategy, args.precision));
+            for overlay_rule in OVERLAY_RULES {
+                let expected = case.expected(overlay_rule);
+                if expected.is_empty() {
+                    continue;
+                }
+
+                checks += 1;
+                let result = graph.extract_shapes(overlay_rule);
+                let mismatch = match compare(&result, expected) {
+                    Some(mismatch) => mismatch,
+                    None => continue,
+                };
+
+                failed += 1;
+                let reason = match mismatch {
+                    Mismatch::Representation => "same area, different contours",
+                    Mismatch::Area => "different area",
+                };
+                println!("FAIL {} {} {:?}: {}", name, key(overlay_rule), strategy, reason);
+                if args.verbose {
+                    println!("  actual: {}", shapes_to_json(&result));
+                    println!("  expected: {}", shapes_to_json(&expected[0]));
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} files, {} checks, {} failed in {:.3}s",
+        files.len(), checks, failed, start.elapsed().as_secs_f64()
+    );
+
+    Ok(failed == 0)
+}
+
+fn compare(result: &IntShapes, expected: &[IntShapes]) -> Option<Mismatch> {
+    let canonical = result.to_canonical();
+    if expected.iter().any(|variant| variant.to_canonical() == canonical) {
+        None
+    } else if expected.iter().any(|variant| variant.eq_point_set(result)) {
+        Some(Mismatch::Representation)
+    } else {
+        Some(Mismatch::Area)
+    }
+}
+
+/// Returns `test_<n>.json` files sorted by `n`.
+fn case_files(dir: &Path) -> Result<Vec<(PathBuf, String)>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        let name = match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        let index = name.strip_prefix("test_")
+            .and_then(|rest| rest.strip_suffix(".json"))
+            .and_then(|index| index.parse::<usize>().ok());
+        if let Some(index) = index {
+            files.push((index, path, name));
+        }
+    }
+
+    files.sort_by_key(|(index, _, _)| *index);
+
+    Ok(files.into_iter().map(|(_, path, name)| (path, name)).collect())
+}
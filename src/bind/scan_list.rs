@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use crate::bind::segment::IdSegment;
 use crate::bind::solver::ScanHoleStore;
 use crate::geom::x_segment::XSegment;
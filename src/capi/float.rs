@@ -0,0 +1,133 @@
+//! Float API: coordinates are `double`.
+
+use alloc::vec::Vec;
+use i_shape::base::data::{Path, Shapes};
+use crate::capi::{guard, out_result, read_fill_rule, read_overlay_rule, read_paths, IOverlayFloatPaths, IOverlayFloatPathsResult, IOverlayFloatShapesResult, IOverlayStatus};
+use crate::float::clip::FloatClip;
+use crate::float::single::SingleFloatOverlay;
+use crate::float::slice::FloatSlice;
+use crate::string::clip::ClipRule;
+
+/// Applies a boolean operation to subject and clip contours.
+/// - `subj`, `clip`: The subject and clip contours.
+/// - `overlay_rule`: One of `IOVERLAY_OVERLAY_RULE_*`.
+/// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+/// - `result`: Receives the shapes, release it with `ioverlay_float_shapes_result_free`.
+///
+/// # Safety
+/// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+#[no_mangle]
+pub unsafe extern "C" fn ioverlay_float_overlay(
+    subj: IOverlayFloatPaths,
+    clip: IOverlayFloatPaths,
+    overlay_rule: u32,
+    fill_rule: u32,
+    result: *mut IOverlayFloatShapesResult,
+) -> IOverlayStatus {
+    let result = match out_result(result) {
+        Ok(result) => result,
+        Err(status) => return status,
+    };
+
+    guard(|| {
+        let overlay_rule = read_overlay_rule(overlay_rule)?;
+        let fill_rule = read_fill_rule(fill_rule)?;
+        let subj = read_float_paths(&subj)?;
+        let clip = read_float_paths(&clip)?;
+
+        if is_empty(&subj) && is_empty(&clip) {
+            return Ok(());
+        }
+
+        *result = IOverlayFloatShapesResult::new(&subj.overlay(&clip, overlay_rule, fill_rule));
+        Ok(())
+    })
+}
+
+/// Clips string lines by shape contours.
+/// - `lines`: The open paths to clip.
+/// - `shape`: The contours of the clipping area.
+/// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+/// - `invert`: Keeps the parts outside of the area instead of inside.
+/// - `boundary_included`: Treats the parts on the area boundary as inside.
+/// - `result`: Receives the clipped paths, release it with `ioverlay_float_paths_result_free`.
+///
+/// # Safety
+/// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+#[no_mangle]
+pub unsafe extern "C" fn ioverlay_float_clip(
+    lines: IOverlayFloatPaths,
+    shape: IOverlayFloatPaths,
+    fill_rule: u32,
+    invert: bool,
+    boundary_included: bool,
+    result: *mut IOverlayFloatPathsResult,
+) -> IOverlayStatus {
+    let result = match out_result(result) {
+        Ok(result) => result,
+        Err(status) => return status,
+    };
+
+    guard(|| {
+        let fill_rule = read_fill_rule(fill_rule)?;
+        let lines = read_float_paths(&lines)?;
+        let shape = read_float_paths(&shape)?;
+
+        if is_empty(&lines) {
+            return Ok(());
+        }
+
+        let clip_rule = ClipRule { invert, boundary_included };
+        *result = IOverlayFloatPathsResult::new(&lines.clip_by(&shape, fill_rule, clip_rule));
+        Ok(())
+    })
+}
+
+/// Slices shape contours by string lines.
+/// - `shape`: The contours to slice.
+/// - `lines`: The open paths to slice by.
+/// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+/// - `result`: Receives the shapes, release it with `ioverlay_float_shapes_result_free`.
+///
+/// # Safety
+/// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+#[no_mangle]
+pub unsafe extern "C" fn ioverlay_float_slice(
+    shape: IOverlayFloatPaths,
+    lines: IOverlayFloatPaths,
+    fill_rule: u32,
+    result: *mut IOverlayFloatShapesResult,
+) -> IOverlayStatus {
+    let result = match out_result(result) {
+        Ok(result) => result,
+        Err(status) => return status,
+    };
+
+    guard(|| {
+        let fill_rule = read_fill_rule(fill_rule)?;
+        let shape = read_float_paths(&shape)?;
+        let lines = read_float_paths(&lines)?;
+
+        if is_empty(&shape) {
+            return Ok(());
+        }
+
+        let shapes: Shapes<[f64; 2]> = shape.slice_by(&lines, fill_rule);
+        *result = IOverlayFloatShapesResult::new(&shapes);
+        Ok(())
+    })
+}
+
+unsafe fn read_float_paths(paths: &IOverlayFloatPaths) -> Result<Vec<Path<[f64; 2]>>, IOverlayStatus> {
+    let paths = read_paths(paths.xy, paths.points_count, paths.offsets, paths.paths_count)?;
+    if paths.iter().flatten().flatten().all(|v| v.is_finite()) {
+        Ok(paths)
+    } else {
+        Err(IOverlayStatus::InvalidArgument)
+    }
+}
+
+#[inline]
+fn is_empty(paths: &[Path<[f64; 2]>]) -> bool {
+    paths.iter().all(|path| path.is_empty())
+}
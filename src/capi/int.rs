@@ -0,0 +1,121 @@
+//! Integer API: coordinates are `int32_t`.
+
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use i_shape::int::path::IntPath;
+use crate::capi::{guard, out_result, read_fill_rule, read_overlay_rule, read_paths, IOverlayIntPaths, IOverlayIntPathsResult, IOverlayIntShapesResult, IOverlayStatus};
+use crate::core::overlay::Overlay;
+use crate::string::clip::ClipRule;
+use crate::string::overlay::StringOverlay;
+use crate::string::rule::StringRule;
+
+/// Applies a boolean operation to subject and clip contours.
+/// - `subj`, `clip`: The subject and clip contours.
+/// - `overlay_rule`: One of `IOVERLAY_OVERLAY_RULE_*`.
+/// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+/// - `result`: Receives the shapes, release it with `ioverlay_int_shapes_result_free`.
+///
+/// # Safety
+/// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+#[no_mangle]
+pub unsafe extern "C" fn ioverlay_int_overlay(
+    subj: IOverlayIntPaths,
+    clip: IOverlayIntPaths,
+    overlay_rule: u32,
+    fill_rule: u32,
+    result: *mut IOverlayIntShapesResult,
+) -> IOverlayStatus {
+    let result = match out_result(result) {
+        Ok(result) => result,
+        Err(status) => return status,
+    };
+
+    guard(|| {
+        let overlay_rule = read_overlay_rule(overlay_rule)?;
+        let fill_rule = read_fill_rule(fill_rule)?;
+        let subj = read_int_paths(&subj)?;
+        let clip = read_int_paths(&clip)?;
+
+        let shapes = Overlay::with_contours(&subj, &clip).overlay(overlay_rule, fill_rule);
+        *result = IOverlayIntShapesResult::new(&shapes);
+        Ok(())
+    })
+}
+
+/// Clips string lines by shape contours.
+/// - `lines`: The open paths to clip.
+/// - `shape`: The contours of the clipping area.
+/// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+/// - `invert`: Keeps the parts outside of the area instead of inside.
+/// - `boundary_included`: Treats the parts on the area boundary as inside.
+/// - `result`: Receives the clipped paths, release it with `ioverlay_int_paths_result_free`.
+///
+/// # Safety
+/// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+#[no_mangle]
+pub unsafe extern "C" fn ioverlay_int_clip(
+    lines: IOverlayIntPaths,
+    shape: IOverlayIntPaths,
+    fill_rule: u32,
+    invert: bool,
+    boundary_included: bool,
+    result: *mut IOverlayIntPathsResult,
+) -> IOverlayStatus {
+    let result = match out_result(result) {
+        Ok(result) => result,
+        Err(status) => return status,
+    };
+
+    guard(|| {
+        let fill_rule = read_fill_rule(fill_rule)?;
+        let lines = read_int_paths(&lines)?;
+        let shape = read_int_paths(&shape)?;
+
+        let mut overlay = StringOverlay::with_shape_contours(&shape);
+        overlay.add_string_paths(&lines);
+
+        let clip_rule = ClipRule { invert, boundary_included };
+        *result = IOverlayIntPathsResult::new(&overlay.clip_string_lines(fill_rule, clip_rule));
+        Ok(())
+    })
+}
+
+/// Slices shape contours by string lines.
+/// - `shape`: The contours to slice.
+/// - `lines`: The open paths to slice by.
+/// - `fill_rule`: One of `IOVERLAY_FILL_RULE_*`.
+/// - `result`: Receives the shapes, release it with `ioverlay_int_shapes_result_free`.
+///
+/// # Safety
+/// Every input buffer must hold the number of values given by its counts, `result` must be writable and empty.
+#[no_mangle]
+pub unsafe extern "C" fn ioverlay_int_slice(
+    shape: IOverlayIntPaths,
+    lines: IOverlayIntPaths,
+    fill_rule: u32,
+    result: *mut IOverlayIntShapesResult,
+) -> IOverlayStatus {
+    let result = match out_result(result) {
+        Ok(result) => result,
+        Err(status) => return status,
+    };
+
+    guard(|| {
+        let fill_rule = read_fill_rule(fill_rule)?;
+        let shape = read_int_paths(&shape)?;
+        let lines = read_int_paths(&lines)?;
+
+        let mut overlay = StringOverlay::with_shape_contours(&shape);
+        overlay.add_string_paths(&lines);
+
+        *result = IOverlayIntShapesResult::new(&overlay.into_graph(fill_rule).extract_shapes(StringRule::Slice));
+        Ok(())
+    })
+}
+
+unsafe fn read_int_paths(paths: &IOverlayIntPaths) -> Result<Vec<IntPath>, IOverlayStatus> {
+    let paths = read_paths(paths.xy, paths.points_count, paths.offsets, paths.paths_count)?;
+    Ok(paths.into_iter()
+        .map(|path| path.into_iter().map(|[x, y]| IntPoint::new(x, y)).collect())
+        .collect())
+}
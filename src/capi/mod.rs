@@ -0,0 +1,410 @@
+//! C ABI for the integer and float overlay APIs.
+//!
+//! The declarations are in `include/i_overlay.h`, generated by `cbindgen` from this module
+//! (`cbindgen --config cbindgen.toml --output include/i_overlay.h`).
+//! To get a C library build the crate as a static or dynamic library:
+//!
+//! ```sh
+//! cargo rustc --release --features capi --crate-type staticlib
+//! ```
+//!
+//! ## Buffers
+//! Input paths are passed as a flat buffer of `x, y` pairs and a buffer of path offsets:
+//! `offsets[i]` is the index of the first point of the path `i`, the path ends where the next one starts
+//! (the last one at `points_count`). Shapes add a buffer of shape offsets over the paths in the same way.
+//!
+//! Results are allocated by the library and must be released by the caller with the matching
+//! `ioverlay_*_free` function. Empty buffers are returned as null pointers.
+//! A result passed to a function must be empty (zeroed or released), a filled one is rejected
+//! instead of being overwritten, so its buffers are never leaked.
+//!
+//! ## Errors
+//! Every function returns an `IOverlayStatus`. On failure the output is left empty.
+//! A panic never crosses the boundary, it is reported as `I_OVERLAY_STATUS_PANIC`
+//! (unless the library is built with `panic = "abort"`).
+
+pub mod int;
+pub mod float;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ptr;
+use core::slice;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use i_float::int::point::IntPoint;
+use i_shape::base::data::{Path, Shape};
+use i_shape::int::path::IntPath;
+use i_shape::int::shape::IntShape;
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay_rule::OverlayRule;
+
+/// Result code of every exported function.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IOverlayStatus {
+    /// The operation succeeded.
+    Ok = 0,
+    /// A required pointer is null.
+    NullPointer = 1,
+    /// A rule is unknown, offsets are out of order, a coordinate is not finite or the result is not empty.
+    InvalidArgument = 2,
+    /// The operation panicked, the output is empty.
+    Panic = 3,
+}
+
+pub const IOVERLAY_FILL_RULE_EVEN_ODD: u32 = 0;
+pub const IOVERLAY_FILL_RULE_NON_ZERO: u32 = 1;
+pub const IOVERLAY_FILL_RULE_POSITIVE: u32 = 2;
+pub const IOVERLAY_FILL_RULE_NEGATIVE: u32 = 3;
+
+pub const IOVERLAY_OVERLAY_RULE_SUBJECT: u32 = 0;
+pub const IOVERLAY_OVERLAY_RULE_CLIP: u32 = 1;
+pub const IOVERLAY_OVERLAY_RULE_INTERSECT: u32 = 2;
+pub const IOVERLAY_OVERLAY_RULE_UNION: u32 = 3;
+pub const IOVERLAY_OVERLAY_RULE_DIFFERENCE: u32 = 4;
+pub const IOVERLAY_OVERLAY_RULE_INVERSE_DIFFERENCE: u32 = 5;
+pub const IOVERLAY_OVERLAY_RULE_XOR: u32 = 6;
+
+/// Integer paths, `xy` holds `2 * points_count` values and `offsets` holds `paths_count` values.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct IOverlayIntPaths {
+    pub xy: *const i32,
+    pub points_count: usize,
+    pub offsets: *const usize,
+    pub paths_count: usize,
+}
+
+/// Float paths, `xy` holds `2 * points_count` values and `offsets` holds `paths_count` values.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct IOverlayFloatPaths {
+    pub xy: *const f64,
+    pub points_count: usize,
+    pub offsets: *const usize,
+    pub paths_count: usize,
+}
+
+/// Integer paths returned by the library, release with `ioverlay_int_paths_result_free`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct IOverlayIntPathsResult {
+    pub xy: *mut i32,
+    pub points_count: usize,
+    pub offsets: *mut usize,
+    pub paths_count: usize,
+}
+
+/// Integer shapes returned by the library, release with `ioverlay_int_shapes_result_free`.
+/// The first path of every shape is the outer contour, the rest are holes.
+#[repr(C)]
+#[derive(Debug)]
+pub struct IOverlayIntShapesResult {
+    pub xy: *mut i32,
+    pub points_count: usize,
+    pub path_offsets: *mut usize,
+    pub paths_count: usize,
+    pub shape_offsets: *mut usize,
+    pub shapes_count: usize,
+}
+
+/// Float paths returned by the library, release with `ioverlay_float_paths_result_free`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct IOverlayFloatPathsResult {
+    pub xy: *mut f64,
+    pub points_count: usize,
+    pub offsets: *mut usize,
+    pub paths_count: usize,
+}
+
+/// Float shapes returned by the library, release with `ioverlay_float_shapes_result_free`.
+/// The first path of every shape is the outer contour, the rest are holes.
+#[repr(C)]
+#[derive(Debug)]
+pub struct IOverlayFloatShapesResult {
+    pub xy: *mut f64,
+    pub points_count: usize,
+    pub path_offsets: *mut usize,
+    pub paths_count: usize,
+    pub shape_offsets: *mut usize,
+    pub shapes_count: usize,
+}
+
+/// Releases the buffers of `result` and resets it to empty. Null is ignored.
+///
+/// # Safety
+/// `result` must be null or point to a result filled by this library.
+#[no_mangle]
+pub unsafe extern "C" fn ioverlay_int_paths_result_free(result: *mut IOverlayIntPathsResult) {
+    if let Some(result) = result.as_mut() {
+        free_buffer(result.xy, 2 * result.points_count);
+        free_buffer(result.offsets, result.paths_count);
+        *result = IOverlayIntPathsResult::empty();
+    }
+}
+
+/// Releases the buffers of `result` and resets it to empty. Null is ignored.
+///
+/// # Safety
+/// `result` must be null or point to a result filled by this library.
+#[no_mangle]
+pub unsafe extern "C" fn ioverlay_int_shapes_result_free(result: *mut IOverlayIntShapesResult) {
+    if let Some(result) = result.as_mut() {
+        free_buffer(result.xy, 2 * result.points_count);
+        free_buffer(result.path_offsets, result.paths_count);
+        free_buffer(result.shape_offsets, result.shapes_count);
+        *result = IOverlayIntShapesResult::empty();
+    }
+}
+
+/// Releases the buffers of `result` and resets it to empty. Null is ignored.
+///
+/// # Safety
+/// `result` must be null or point to a result filled by this library.
+#[no_mangle]
+pub unsafe extern "C" fn ioverlay_float_paths_result_free(result: *mut IOverlayFloatPathsResult) {
+    if let Some(result) = result.as_mut() {
+        free_buffer(result.xy, 2 * result.points_count);
+        free_buffer(result.offsets, result.paths_count);
+        *result = IOverlayFloatPathsResult::empty();
+    }
+}
+
+/// Releases the buffers of `result` and resets it to empty. Null is ignored.
+///
+/// # Safety
+/// `result` must be null or point to a result filled by this library.
+#[no_mangle]
+pub unsafe extern "C" fn ioverlay_float_shapes_result_free(result: *mut IOverlayFloatShapesResult) {
+    if let Some(result) = result.as_mut() {
+        free_buffer(result.xy, 2 * result.points_count);
+        free_buffer(result.path_offsets, result.paths_count);
+        free_buffer(result.shape_offsets, result.shapes_count);
+        *result = IOverlayFloatShapesResult::empty();
+    }
+}
+
+impl IOverlayIntPathsResult {
+    fn empty() -> Self {
+        Self { xy: ptr::null_mut(), points_count: 0, offsets: ptr::null_mut(), paths_count: 0 }
+    }
+
+    fn new(paths: &[IntPath]) -> Self {
+        let (xy, offsets) = write_paths(paths);
+        let (xy, points_count) = into_buffer(xy);
+        let (offsets, paths_count) = into_buffer(offsets);
+        Self { xy, points_count: points_count / 2, offsets, paths_count }
+    }
+}
+
+impl IOverlayIntShapesResult {
+    fn empty() -> Self {
+        Self {
+            xy: ptr::null_mut(),
+            points_count: 0,
+            path_offsets: ptr::null_mut(),
+            paths_count: 0,
+            shape_offsets: ptr::null_mut(),
+            shapes_count: 0,
+        }
+    }
+
+    fn new(shapes: &[IntShape]) -> Self {
+        let (xy, path_offsets, shape_offsets) = write_shapes(shapes);
+        let (xy, points_count) = into_buffer(xy);
+        let (path_offsets, paths_count) = into_buffer(path_offsets);
+        let (shape_offsets, shapes_count) = into_buffer(shape_offsets);
+        Self { xy, points_count: points_count / 2, path_offsets, paths_count, shape_offsets, shapes_count }
+    }
+}
+
+impl IOverlayFloatPathsResult {
+    fn empty() -> Self {
+        Self { xy: ptr::null_mut(), points_count: 0, offsets: ptr::null_mut(), paths_count: 0 }
+    }
+
+    fn new(paths: &[Path<[f64; 2]>]) -> Self {
+        let (xy, offsets) = write_paths(paths);
+        let (xy, points_count) = into_buffer(xy);
+        let (offsets, paths_count) = into_buffer(offsets);
+        Self { xy, points_count: points_count / 2, offsets, paths_count }
+    }
+}
+
+impl IOverlayFloatShapesResult {
+    fn empty() -> Self {
+        Self {
+            xy: ptr::null_mut(),
+            points_count: 0,
+            path_offsets: ptr::null_mut(),
+            paths_count: 0,
+            shape_offsets: ptr::null_mut(),
+            shapes_count: 0,
+        }
+    }
+
+    fn new(shapes: &[Shape<[f64; 2]>]) -> Self {
+        let (xy, path_offsets, shape_offsets) = write_shapes(shapes);
+        let (xy, points_count) = into_buffer(xy);
+        let (path_offsets, paths_count) = into_buffer(path_offsets);
+        let (shape_offsets, shapes_count) = into_buffer(shape_offsets);
+        Self { xy, points_count: points_count / 2, path_offsets, paths_count, shape_offsets, shapes_count }
+    }
+}
+
+trait OutResult {
+    fn is_empty(&self) -> bool;
+}
+
+impl OutResult for IOverlayIntPathsResult {
+    fn is_empty(&self) -> bool {
+        self.xy.is_null() && self.offsets.is_null()
+    }
+}
+
+impl OutResult for IOverlayIntShapesResult {
+    fn is_empty(&self) -> bool {
+        self.xy.is_null() && self.path_offsets.is_null() && self.shape_offsets.is_null()
+    }
+}
+
+impl OutResult for IOverlayFloatPathsResult {
+    fn is_empty(&self) -> bool {
+        self.xy.is_null() && self.offsets.is_null()
+    }
+}
+
+impl OutResult for IOverlayFloatShapesResult {
+    fn is_empty(&self) -> bool {
+        self.xy.is_null() && self.path_offsets.is_null() && self.shape_offsets.is_null()
+    }
+}
+
+/// Returns the result to fill, it must not be null and must hold no buffers.
+unsafe fn out_result<'a, R: OutResult>(result: *mut R) -> Result<&'a mut R, IOverlayStatus> {
+    match result.as_mut() {
+        None => Err(IOverlayStatus::NullPointer),
+        Some(result) if !result.is_empty() => Err(IOverlayStatus::InvalidArgument),
+        Some(result) => Ok(result),
+    }
+}
+
+trait PointXY<C> {
+    fn xy(&self) -> [C; 2];
+}
+
+impl PointXY<i32> for IntPoint {
+    #[inline(always)]
+    fn xy(&self) -> [i32; 2] {
+        [self.x, self.y]
+    }
+}
+
+impl PointXY<f64> for [f64; 2] {
+    #[inline(always)]
+    fn xy(&self) -> [f64; 2] {
+        *self
+    }
+}
+
+/// Runs `f` and converts its error or panic into a status.
+fn guard<F: FnOnce() -> Result<(), IOverlayStatus>>(f: F) -> IOverlayStatus {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => IOverlayStatus::Ok,
+        Ok(Err(status)) => status,
+        Err(_) => IOverlayStatus::Panic,
+    }
+}
+
+fn read_fill_rule(value: u32) -> Result<FillRule, IOverlayStatus> {
+    match value {
+        IOVERLAY_FILL_RULE_EVEN_ODD => Ok(FillRule::EvenOdd),
+        IOVERLAY_FILL_RULE_NON_ZERO => Ok(FillRule::NonZero),
+        IOVERLAY_FILL_RULE_POSITIVE => Ok(FillRule::Positive),
+        IOVERLAY_FILL_RULE_NEGATIVE => Ok(FillRule::Negative),
+        _ => Err(IOverlayStatus::InvalidArgument),
+    }
+}
+
+fn read_overlay_rule(value: u32) -> Result<OverlayRule, IOverlayStatus> {
+    match value {
+        IOVERLAY_OVERLAY_RULE_SUBJECT => Ok(OverlayRule::Subject),
+        IOVERLAY_OVERLAY_RULE_CLIP => Ok(OverlayRule::Clip),
+        IOVERLAY_OVERLAY_RULE_INTERSECT => Ok(OverlayRule::Intersect),
+        IOVERLAY_OVERLAY_RULE_UNION => Ok(OverlayRule::Union),
+        IOVERLAY_OVERLAY_RULE_DIFFERENCE => Ok(OverlayRule::Difference),
+        IOVERLAY_OVERLAY_RULE_INVERSE_DIFFERENCE => Ok(OverlayRule::InverseDifference),
+        IOVERLAY_OVERLAY_RULE_XOR => Ok(OverlayRule::Xor),
+        _ => Err(IOverlayStatus::InvalidArgument),
+    }
+}
+
+/// Reads flat paths, null pointers are allowed only for empty buffers.
+unsafe fn read_paths<C: Copy>(xy: *const C, points_count: usize, offsets: *const usize, paths_count: usize) -> Result<Vec<Vec<[C; 2]>>, IOverlayStatus> {
+    let xy_count = points_count.checked_mul(2).ok_or(IOverlayStatus::InvalidArgument)?;
+    let xy = as_slice(xy, xy_count)?;
+    let offsets = as_slice(offsets, paths_count)?;
+
+    let mut paths = Vec::with_capacity(paths_count);
+    for (i, &start) in offsets.iter().enumerate() {
+        let end = offsets.get(i + 1).copied().unwrap_or(points_count);
+        if start > end || end > points_count || (i == 0 && start != 0) {
+            return Err(IOverlayStatus::InvalidArgument);
+        }
+        paths.push(xy[2 * start..2 * end].chunks_exact(2).map(|p| [p[0], p[1]]).collect());
+    }
+
+    Ok(paths)
+}
+
+#[inline]
+unsafe fn as_slice<'a, C>(data: *const C, len: usize) -> Result<&'a [C], IOverlayStatus> {
+    if len == 0 {
+        Ok(&[])
+    } else if data.is_null() {
+        Err(IOverlayStatus::NullPointer)
+    } else {
+        Ok(slice::from_raw_parts(data, len))
+    }
+}
+
+fn write_paths<P: PointXY<C>, C: Copy>(paths: &[Vec<P>]) -> (Vec<C>, Vec<usize>) {
+    let mut xy = Vec::with_capacity(2 * paths.iter().map(Vec::len).sum::<usize>());
+    let mut offsets = Vec::with_capacity(paths.len());
+    for path in paths.iter() {
+        offsets.push(xy.len() / 2);
+        xy.extend(path.iter().flat_map(PointXY::xy));
+    }
+
+    (xy, offsets)
+}
+
+fn write_shapes<P: PointXY<C>, C: Copy>(shapes: &[Vec<Vec<P>>]) -> (Vec<C>, Vec<usize>, Vec<usize>) {
+    let mut xy = Vec::new();
+    let mut path_offsets = Vec::new();
+    let mut shape_offsets = Vec::with_capacity(shapes.len());
+    for shape in shapes.iter() {
+        shape_offsets.push(path_offsets.len());
+        for path in shape.iter() {
+            path_offsets.push(xy.len() / 2);
+            xy.extend(path.iter().flat_map(PointXY::xy));
+        }
+    }
+
+    (xy, path_offsets, shape_offsets)
+}
+
+fn into_buffer<C>(vec: Vec<C>) -> (*mut C, usize) {
+    if vec.is_empty() {
+        return (ptr::null_mut(), 0);
+    }
+    let len = vec.len();
+    (Box::into_raw(vec.into_boxed_slice()) as *mut C, len)
+}
+
+unsafe fn free_buffer<C>(data: *mut C, len: usize) {
+    if !data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(data, len)));
+    }
+}
@@ -0,0 +1,260 @@
+//! This module provides a canonical representation for contours and shapes and an equality check
+//! that compares geometry by the covered point set rather than by representation.
+//!
+//! Overlay results are topologically stable, but the start vertex of a contour, the order of holes
+//! and the order of shapes depend on the solver strategy and on the input order. The canonical form
+//! removes that freedom:
+//! - duplicate points and collinear vertices are removed,
+//! - every contour starts from its minimal point (ordered by `x`, then by `y`),
+//! - the outer contour stays first, holes are sorted,
+//! - shapes are sorted by their outer contour.
+
+use i_float::float::number::FloatNumber;
+use i_float::int::point::IntPoint;
+use i_shape::int::count::PointsCount;
+use i_shape::int::path::{IntPath, PointPathExtension};
+use i_shape::int::shape::{IntShape, IntShapes};
+use i_shape::int::simple::SimpleContour;
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::{Overlay, ShapeType};
+use crate::core::overlay_rule::OverlayRule;
+
+/// Trait `Canonical` converts geometry into a deterministic representation.
+pub trait Canonical {
+    /// Converts `self` into the canonical form in place.
+    fn canonicalize(&mut self);
+
+    /// Returns a canonical copy of `self`.
+    fn to_canonical(&self) -> Self;
+}
+
+impl Canonical for IntPath {
+    fn canonicalize(&mut self) {
+        if !self.is_simple() {
+            if let Some(simple) = self.simplified() {
+                *self = simple;
+            } else {
+                self.clear();
+                return;
+            }
+        }
+
+        if let Some(start) = min_index(self) {
+            self.rotate_left(start);
+        }
+    }
+
+    #[inline]
+    fn to_canonical(&self) -> Self {
+        let mut path = self.clone();
+        path.canonicalize();
+        path
+    }
+}
+
+impl Canonical for IntShape {
+    fn canonicalize(&mut self) {
+        for path in self.iter_mut() {
+            path.canonicalize();
+        }
+
+        if self.first().is_none_or(|outer| outer.is_empty()) {
+            self.clear();
+            return;
+        }
+
+        self.retain(|path| !path.is_empty());
+        self[1..].sort_unstable();
+    }
+
+    #[inline]
+    fn to_canonical(&self) -> Self {
+        let mut shape = self.clone();
+        shape.canonicalize();
+        shape
+    }
+}
+
+impl Canonical for IntShapes {
+    fn canonicalize(&mut self) {
+        for shape in self.iter_mut() {
+            shape.canonicalize();
+        }
+
+        self.retain(|shape| !shape.is_empty());
+        self.sort_unstable();
+    }
+
+    #[inline]
+    fn to_canonical(&self) -> Self {
+        let mut shapes = self.clone();
+        shapes.canonicalize();
+        shapes
+    }
+}
+
+/// Trait `PointSetEq` compares geometry by the area it covers.
+pub trait PointSetEq<Rhs: ?Sized = Self> {
+    /// Returns `true` if both arguments cover exactly the same point set,
+    /// regardless of contour start points, contour order, collinear vertices
+    /// or the way the area is split into shapes.
+    ///
+    /// Both arguments are interpreted with the `NonZero` fill rule.
+    fn eq_point_set(&self, other: &Rhs) -> bool;
+
+    /// Returns `true` if both arguments cover the same point set, regardless of representation.
+    ///
+    /// - `other`: The geometry to compare with.
+    /// - `tolerance`: The maximum allowed width of a difference between the two point sets.
+    ///   Every piece of the symmetric difference must satisfy `area <= tolerance * perimeter / 2`,
+    ///   which for a thin sliver means its width is not larger than `tolerance`.
+    ///
+    /// Both arguments are interpreted with the `NonZero` fill rule.
+    fn eq_point_set_with_tolerance(&self, other: &Rhs, tolerance: usize) -> bool;
+}
+
+impl PointSetEq for [IntShape] {
+    #[inline]
+    fn eq_point_set(&self, other: &[IntShape]) -> bool {
+        xor(self, other).is_empty()
+    }
+
+    fn eq_point_set_with_tolerance(&self, other: &[IntShape], tolerance: usize) -> bool {
+        xor(self, other).iter().all(|shape| {
+            let doubled_area: i64 = shape.iter().map(|contour| contour.unsafe_area()).sum();
+            let perimeter: f64 = shape.iter().map(perimeter).sum();
+            doubled_area.unsigned_abs() as f64 <= tolerance as f64 * perimeter
+        })
+    }
+}
+
+impl PointSetEq for IntShapes {
+    #[inline]
+    fn eq_point_set(&self, other: &IntShapes) -> bool {
+        self.as_slice().eq_point_set(other.as_slice())
+    }
+
+    #[inline]
+    fn eq_point_set_with_tolerance(&self, other: &IntShapes, tolerance: usize) -> bool {
+        self.as_slice().eq_point_set_with_tolerance(other.as_slice(), tolerance)
+    }
+}
+
+impl PointSetEq for IntShape {
+    #[inline]
+    fn eq_point_set(&self, other: &IntShape) -> bool {
+        core::slice::from_ref(self).eq_point_set(core::slice::from_ref(other))
+    }
+
+    #[inline]
+    fn eq_point_set_with_tolerance(&self, other: &IntShape, tolerance: usize) -> bool {
+        core::slice::from_ref(self).eq_point_set_with_tolerance(core::slice::from_ref(other), tolerance)
+    }
+}
+
+fn xor(subj: &[IntShape], clip: &[IntShape]) -> IntShapes {
+    let mut overlay = Overlay::new(subj.points_count() + clip.points_count());
+    overlay.add_shapes(subj, ShapeType::Subject);
+    overlay.add_shapes(clip, ShapeType::Clip);
+
+    overlay.overlay(OverlayRule::Xor, FillRule::NonZero)
+}
+
+fn perimeter(contour: &IntPath) -> f64 {
+    let n = contour.len();
+    (0..n).map(|i| {
+        let (a, b) = (contour[i], contour[(i + 1) % n]);
+        let (dx, dy) = (b.x as f64 - a.x as f64, b.y as f64 - a.y as f64);
+        FloatNumber::sqrt(dx * dx + dy * dy)
+    }).sum()
+}
+
+#[inline]
+fn min_index(path: &[IntPoint]) -> Option<usize> {
+    path.iter().enumerate().min_by_key(|(_, &p)| p).map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::canonical::{Canonical, PointSetEq};
+
+    #[test]
+    fn test_path() {
+        let path = vec![
+            IntPoint::new(10, 10),
+            IntPoint::new(10, 0),
+            IntPoint::new(5, 0),
+            IntPoint::new(0, 0),
+            IntPoint::new(0, 10),
+            IntPoint::new(0, 10),
+        ];
+
+        let canonical = path.to_canonical();
+
+        assert_eq!(canonical, vec![
+            IntPoint::new(0, 0),
+            IntPoint::new(0, 10),
+            IntPoint::new(10, 10),
+            IntPoint::new(10, 0),
+        ]);
+    }
+
+    #[test]
+    fn test_shapes_order() {
+        let a = vec![square(0, 0, 10), square(2, 2, 2).into_iter().rev().collect(), square(6, 6, 2).into_iter().rev().collect()];
+        let b = vec![square(20, 0, 10)];
+
+        let mut shuffled_a = a.clone();
+        shuffled_a.swap(1, 2);
+        shuffled_a[0].rotate_left(2);
+
+        let s0 = vec![a, b.clone()].to_canonical();
+        let s1 = vec![b, shuffled_a].to_canonical();
+
+        assert_eq!(s0, s1);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let shapes = vec![vec![vec![IntPoint::new(0, 0), IntPoint::new(5, 0), IntPoint::new(10, 0)]]];
+        assert!(shapes.to_canonical().is_empty());
+    }
+
+    #[test]
+    fn test_point_set() {
+        // the same rectangle as one shape and as two touching halves
+        let whole = vec![vec![rect(0, 0, 10, 5)]];
+        let halves = vec![vec![rect(0, 0, 5, 5)], vec![rect(5, 0, 10, 5)]];
+        let other = vec![vec![rect(0, 0, 10, 6)]];
+
+        assert!(whole.eq_point_set(&halves));
+        assert!(!whole.eq_point_set(&other));
+    }
+
+    #[test]
+    fn test_point_set_with_tolerance() {
+        // the difference is a 10 x 1 sliver, its perimeter is 22
+        let whole = vec![vec![rect(0, 0, 10, 5)]];
+        let other = vec![vec![rect(0, 0, 10, 6)]];
+
+        assert!(whole.eq_point_set_with_tolerance(&other, 1));
+        assert!(!whole.eq_point_set_with_tolerance(&other, 0));
+        assert!(whole.eq_point_set_with_tolerance(&whole, 0));
+    }
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        rect(x, y, x + size, y + size)
+    }
+
+    fn rect(x0: i32, y0: i32, x1: i32, y1: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x0, y0),
+            IntPoint::new(x0, y1),
+            IntPoint::new(x1, y1),
+            IntPoint::new(x1, y0),
+        ]
+    }
+}
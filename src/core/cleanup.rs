@@ -0,0 +1,457 @@
+//! This module removes small and thin parts of shapes by thresholds.
+//!
+//! The stages run in this order:
+//! - Spikes: vertices with an angle sharper than `max_spike_angle` are removed.
+//! - Width: every vertex that comes closer than `min_width` to another part of its shape is connected
+//!   to it with a cut. The shape is sliced by the cuts and the pieces narrower than `min_width` are
+//!   removed, so narrow spikes and necks disappear. The width of a piece is the smallest distance
+//!   between two parallel lines that enclose it. A shape without cuts is kept as it is.
+//! - Contours: outer contours smaller than `min_outer_area` are removed with their holes, holes smaller
+//!   than `min_hole_area` are filled, contours with the area to perimeter ratio below `min_sliver_ratio`
+//!   are removed or filled as well.
+//!
+//! A shape changed by the first two stages is rebuilt by the union of its own pieces, so the output
+//! is valid overlay output.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::float::number::FloatNumber;
+use i_float::int::point::IntPoint;
+use i_shape::int::path::{IntPath, PointPathExtension};
+use i_shape::int::shape::{IntShape, IntShapes};
+use crate::core::fill_rule::FillRule;
+use crate::core::generalize::{sqr_distance, sqr_length, winding};
+use crate::core::graph::OverlayGraph;
+use crate::core::overlay::Overlay;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::string::slice::IntSlice;
+
+/// The thresholds of the cleanup, a zero threshold turns its stage off.
+/// - `min_outer_area`: Outer contours with a smaller area are removed with their holes.
+/// - `min_hole_area`: Holes with a smaller area are filled.
+/// - `min_sliver_ratio`: Contours with a smaller ratio of area to perimeter are removed, or filled if they are holes.
+///   The ratio is a length: half of the width for a long thin contour, but a quarter of the side for a square,
+///   so a 15 × 15 square has the ratio 3.75 and is removed by `min_sliver_ratio: 5`.
+/// - `min_width`: Spikes and necks narrower than this width are cut off.
+/// - `max_spike_angle`: Vertices with a sharper angle, in degrees, are removed.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cleanup<T = usize> {
+    pub min_outer_area: T,
+    pub min_hole_area: T,
+    pub min_sliver_ratio: T,
+    pub min_width: T,
+    pub max_spike_angle: f64,
+}
+
+/// Trait `Clean` removes small holes, small shapes, slivers, spikes and necks by the thresholds of `Cleanup`.
+pub trait Clean {
+    /// Cleans the shapes with the default solver.
+    /// - `cleanup`: The thresholds.
+    /// # Returns
+    /// The cleaned shapes, outer contours are clockwise and holes are counterclockwise.
+    fn clean(&self, cleanup: &Cleanup) -> IntShapes;
+
+    /// Cleans the shapes.
+    /// - `cleanup`: The thresholds.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The cleaned shapes, outer contours are clockwise and holes are counterclockwise.
+    fn clean_with_solver(&self, cleanup: &Cleanup, solver: Solver) -> IntShapes;
+}
+
+impl Clean for [IntShape] {
+    #[inline]
+    fn clean(&self, cleanup: &Cleanup) -> IntShapes {
+        self.clean_with_solver(cleanup, Default::default())
+    }
+
+    fn clean_with_solver(&self, cleanup: &Cleanup, solver: Solver) -> IntShapes {
+        let min_cos = cos(cleanup.max_spike_angle.min(180.0) * core::f64::consts::PI / 180.0);
+        let width = cleanup.min_width as f64;
+
+        let mut shapes = Vec::with_capacity(self.len());
+        for shape in self.iter() {
+            let mut shape = shape.clone();
+            let mut is_modified = false;
+
+            if cleanup.max_spike_angle > 0.0 {
+                for contour in shape.iter_mut() {
+                    is_modified |= remove_spikes(contour, min_cos);
+                }
+                if shape.first().is_none_or(|outer| outer.len() < 3) {
+                    continue;
+                }
+                shape.retain(|contour| contour.len() >= 3);
+            }
+
+            let mut pieces = vec![shape];
+            if cleanup.min_width > 0 {
+                let cuts = neck_cuts(&pieces[0], width);
+                if !cuts.is_empty() {
+                    is_modified = true;
+                    pieces = pieces[0].slice_by_paths(&cuts, FillRule::NonZero);
+                    pieces.retain(|piece| caliper_width(&piece[0]) >= width);
+                }
+            }
+
+            if is_modified {
+                // only the pieces of this shape are joined back
+                shapes.extend(Overlay::with_shapes(&pieces, &[]).overlay_with_min_area_and_solver(OverlayRule::Subject, FillRule::NonZero, 0, solver));
+            } else {
+                shapes.append(&mut pieces);
+            }
+        }
+
+        let min_ratio = cleanup.min_sliver_ratio as f64;
+        let is_kept = |contour: &IntPath, min_area: usize| {
+            let contour_area = 0.5 * contour.unsafe_area().unsigned_abs() as f64;
+            contour_area >= min_area as f64 && contour_area >= min_ratio * perimeter(contour)
+        };
+
+        shapes.retain(|shape| is_kept(&shape[0], cleanup.min_outer_area));
+        for shape in shapes.iter_mut() {
+            let mut index = 0;
+            shape.retain(|contour| {
+                index += 1;
+                index == 1 || is_kept(contour, cleanup.min_hole_area)
+            });
+        }
+
+        shapes
+    }
+}
+
+impl OverlayGraph {
+    /// Extracts shapes from the overlay graph and cleans them by the thresholds of `Cleanup`.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph.
+    /// - `cleanup`: The thresholds.
+    /// # Returns
+    /// The cleaned shapes, outer contours are clockwise and holes are counterclockwise.
+    #[inline]
+    pub fn extract_shapes_with_cleanup(&self, overlay_rule: OverlayRule, cleanup: &Cleanup) -> IntShapes {
+        self.extract_shapes(overlay_rule).clean_with_solver(cleanup, self.solver)
+    }
+}
+
+impl Overlay {
+    /// Executes a single Boolean operation and cleans the result by the thresholds of `Cleanup`.
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - `cleanup`: The thresholds.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The cleaned shapes, outer contours are clockwise and holes are counterclockwise.
+    #[inline]
+    pub fn overlay_with_cleanup_and_solver(self, overlay_rule: OverlayRule, fill_rule: FillRule, cleanup: &Cleanup, solver: Solver) -> IntShapes {
+        self.overlay_with_min_area_and_solver(overlay_rule, fill_rule, 0, solver)
+            .clean_with_solver(cleanup, solver)
+    }
+}
+
+/// Removes the vertices with the cosine of the angle between the neighbors above `min_cos`.
+fn remove_spikes(contour: &mut IntPath, min_cos: f64) -> bool {
+    let mut is_modified = false;
+    loop {
+        let n = contour.len();
+        if n < 3 {
+            return is_modified;
+        }
+        let spike = (0..n).find(|&i| {
+            let p = contour[(i + n - 1) % n];
+            let v = contour[i];
+            let q = contour[(i + 1) % n];
+            let (ax, ay) = (p.x as f64 - v.x as f64, p.y as f64 - v.y as f64);
+            let (bx, by) = (q.x as f64 - v.x as f64, q.y as f64 - v.y as f64);
+            let len = FloatNumber::sqrt((ax * ax + ay * ay) * (bx * bx + by * by));
+            len == 0.0 || ax * bx + ay * by > min_cos * len
+        });
+        match spike {
+            Some(i) => {
+                contour.remove(i);
+                is_modified = true;
+            }
+            None => return is_modified,
+        }
+    }
+}
+
+/// The cuts from every vertex to the nearest part of the shape closer than `width`.
+/// A part of the same contour is taken only if the way to it along the contour is more than twice
+/// longer than the cut, so the cuts do not cross the bends of the boundary.
+fn neck_cuts(shape: &IntShape, width: f64) -> Vec<IntPath> {
+    let sqr_width = width * width;
+
+    // the edges sorted by their left end
+    let mut edges: Vec<(i32, usize, usize)> = Vec::new();
+    let mut offsets = Vec::with_capacity(shape.len());
+    for (c, contour) in shape.iter().enumerate() {
+        let n = contour.len();
+        let mut lengths = Vec::with_capacity(n + 1);
+        lengths.push(0.0);
+        for i in 0..n {
+            let (a, b) = (contour[i], contour[(i + 1) % n]);
+            edges.push((a.x.min(b.x), c, i));
+            let length = FloatNumber::sqrt(sqr_length(a, b));
+            lengths.push(lengths[i] + length);
+        }
+        offsets.push(lengths);
+    }
+    edges.sort_unstable();
+
+    let reach = width as i64 + 1;
+    let mut cuts = Vec::new();
+    for (cv, contour) in shape.iter().enumerate() {
+        let n = contour.len();
+        for (iv, &v) in contour.iter().enumerate() {
+            let end = edges.partition_point(|e| (e.0 as i64) <= v.x as i64 + reach);
+            let mut best: Option<(f64, IntPoint)> = None;
+            for &(_, c, i) in edges[..end].iter() {
+                let other = &shape[c];
+                let m = other.len();
+                let (a, b) = (other[i], other[(i + 1) % m]);
+                if (a.x.max(b.x) as i64) < v.x as i64 - reach {
+                    continue;
+                }
+                let sqr_d = sqr_distance(v, a, b);
+                if sqr_d >= sqr_width || sqr_d == 0.0 || best.is_some_and(|(d, _)| d <= sqr_d) {
+                    continue;
+                }
+
+                let q = closest(v, a, b);
+                if c == cv {
+                    if i == iv || (i + 1) % n == iv {
+                        continue;
+                    }
+                    let lengths = &offsets[c];
+                    let s_v = lengths[iv];
+                    let s_q = lengths[i] + FloatNumber::sqrt(sqr_length(a, q));
+                    let delta = if s_q > s_v { s_q - s_v } else { s_v - s_q };
+                    let detour = delta.min(lengths[n] - delta);
+                    if detour * detour <= 4.0 * sqr_d {
+                        continue;
+                    }
+                }
+
+                // the cut goes inside the shape
+                let middle = IntPoint::new(
+                    ((v.x as i64 + q.x as i64) / 2) as i32,
+                    ((v.y as i64 + q.y as i64) / 2) as i32,
+                );
+                if q != v && is_inside_shape(middle, shape) {
+                    best = Some((sqr_d, q));
+                }
+            }
+            if let Some((_, q)) = best {
+                cuts.push(vec![v, q]);
+            }
+        }
+    }
+
+    cuts
+}
+
+/// The nearest point of the segment `a -> b` to `p`, rounded to the grid.
+fn closest(p: IntPoint, a: IntPoint, b: IntPoint) -> IntPoint {
+    let (dx, dy) = (b.x as f64 - a.x as f64, b.y as f64 - a.y as f64);
+    let sqr_len = dx * dx + dy * dy;
+    if sqr_len == 0.0 {
+        return a;
+    }
+    let t = (((p.x as f64 - a.x as f64) * dx + (p.y as f64 - a.y as f64) * dy) / sqr_len).clamp(0.0, 1.0);
+    let x = a.x as f64 + t * dx;
+    let y = a.y as f64 + t * dy;
+    IntPoint::new(round(x) as i32, round(y) as i32)
+}
+
+#[inline]
+fn round(x: f64) -> f64 {
+    if x >= 0.0 { (x + 0.5) as i64 as f64 } else { (x - 0.5) as i64 as f64 }
+}
+
+/// Returns `true` if the point is strictly inside the shape.
+fn is_inside_shape(p: IntPoint, shape: &IntShape) -> bool {
+    let mut total = 0;
+    for contour in shape.iter() {
+        match winding(p, contour) {
+            Some(w) => total += w,
+            None => return false,
+        }
+    }
+    total != 0
+}
+
+/// The smallest distance between two parallel lines that enclose the contour, found by rotating
+/// calipers around its convex hull.
+fn caliper_width(contour: &IntPath) -> f64 {
+    let hull = convex_hull(contour);
+    let n = hull.len();
+    if n < 3 {
+        return 0.0;
+    }
+
+    let height = |a: IntPoint, b: IntPoint, p: IntPoint| cross(a, b, p).unsigned_abs() as f64;
+
+    let mut min_width = f64::MAX;
+    let mut j = 1;
+    for i in 0..n {
+        let (a, b) = (hull[i], hull[(i + 1) % n]);
+        // the farthest vertex from the edge only moves forward
+        while height(a, b, hull[(j + 1) % n]) > height(a, b, hull[j]) {
+            j = (j + 1) % n;
+        }
+        let width = height(a, b, hull[j]) / FloatNumber::sqrt(sqr_length(a, b));
+        min_width = min_width.min(width);
+    }
+
+    min_width
+}
+
+/// The convex hull by the monotone chain, counterclockwise without collinear points.
+fn convex_hull(contour: &IntPath) -> IntPath {
+    let mut points = contour.clone();
+    points.sort_unstable();
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut hull: IntPath = Vec::with_capacity(points.len() + 1);
+    for half in [false, true] {
+        let start = hull.len();
+        let mut add = |p: IntPoint| {
+            while hull.len() >= start + 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0 {
+                hull.pop();
+            }
+            hull.push(p);
+        };
+        if half {
+            points.iter().rev().for_each(|&p| add(p));
+        } else {
+            points.iter().for_each(|&p| add(p));
+        }
+        hull.pop();
+    }
+
+    hull
+}
+
+#[inline]
+fn cross(a: IntPoint, b: IntPoint, p: IntPoint) -> i128 {
+    (b.x as i128 - a.x as i128) * (p.y as i128 - a.y as i128) - (b.y as i128 - a.y as i128) * (p.x as i128 - a.x as i128)
+}
+
+fn perimeter(contour: &IntPath) -> f64 {
+    let n = contour.len();
+    (0..n).map(|i| FloatNumber::sqrt(sqr_length(contour[i], contour[(i + 1) % n]))).sum()
+}
+
+/// The cosine by its Taylor series, `x` is in `[0, π]`.
+fn cos(x: f64) -> f64 {
+    let sqr_x = x * x;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for k in 1..12 {
+        term *= -sqr_x / ((2 * k - 1) * (2 * k)) as f64;
+        sum += term;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use i_shape::int::path::PointPathExtension;
+    use crate::core::cleanup::{caliper_width, Clean, Cleanup};
+
+    fn points(coords: &[[i32; 2]]) -> Vec<IntPoint> {
+        coords.iter().map(|&[x, y]| IntPoint::new(x, y)).collect()
+    }
+
+    fn square(x0: i32, y0: i32, x1: i32, y1: i32) -> Vec<IntPoint> {
+        points(&[[x0, y0], [x0, y1], [x1, y1], [x1, y0]])
+    }
+
+    #[test]
+    fn test_holes_and_outers() {
+        let small_hole = square(10, 10, 15, 15).into_iter().rev().collect();
+        let big_hole = square(50, 50, 80, 80).into_iter().rev().collect();
+        let shapes = [vec![square(0, 0, 100, 100), small_hole, big_hole], vec![square(200, 0, 205, 5)]];
+
+        let cleanup = Cleanup { min_outer_area: 30, min_hole_area: 30, ..Default::default() };
+        let result = shapes.clean(&cleanup);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].len(), 2);
+        assert_eq!(result[0][1].unsafe_area(), -2 * 900);
+
+        // nothing is removed by default
+        assert_eq!(shapes.clean(&Cleanup::default()), shapes.to_vec());
+    }
+
+    #[test]
+    fn test_sliver() {
+        let shapes = [vec![square(0, 0, 20, 20)], vec![square(50, 0, 150, 2)]];
+        let cleanup = Cleanup { min_sliver_ratio: 2, ..Default::default() };
+        let result = shapes.clean(&cleanup);
+        assert_eq!(result, vec![vec![square(0, 0, 20, 20)]]);
+    }
+
+    #[test]
+    fn test_spike_angle() {
+        let needle = points(&[[0, 0], [0, 10], [5, 10], [5, 40], [6, 10], [10, 10], [10, 0]]);
+        let cleanup = Cleanup { max_spike_angle: 10.0, ..Default::default() };
+        let result = [vec![needle.clone()]].clean(&cleanup);
+        assert_eq!(result, vec![vec![square(0, 0, 10, 10)]]);
+
+        let cleanup = Cleanup { max_spike_angle: 1.0, ..Default::default() };
+        let result = [vec![needle.clone()]].clean(&cleanup);
+        assert_eq!(result, vec![vec![needle]]);
+    }
+
+    #[test]
+    fn test_width() {
+        // two squares connected by a neck and a square with a spike
+        let dumbbell = points(&[
+            [0, 0], [0, 40], [40, 40], [40, 22], [60, 22], [60, 40], [100, 40], [100, 0], [60, 0], [60, 18], [40, 18], [40, 0]
+        ]);
+        let spike = points(&[[200, 0], [200, 40], [218, 40], [218, 80], [222, 80], [222, 40], [240, 40], [240, 0]]);
+        let shapes = [vec![dumbbell], vec![spike]];
+
+        let cleanup = Cleanup { min_width: 10, ..Default::default() };
+        let mut result = shapes.clean(&cleanup);
+        result.sort_by_key(|shape| shape[0].iter().min().copied());
+        assert_eq!(result, vec![
+            vec![square(0, 0, 40, 40)],
+            vec![square(60, 0, 100, 40)],
+            vec![square(200, 0, 240, 40)],
+        ]);
+
+        // a wider neck stays
+        let cleanup = Cleanup { min_width: 3, ..Default::default() };
+        let result = shapes.clean(&cleanup);
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn test_width_keeps_whole_shapes() {
+        // a shape without necks is not measured, a square is a sliver only by the ratio
+        let shapes = [vec![square(0, 0, 15, 15)]];
+
+        let cleanup = Cleanup { min_width: 10, ..Default::default() };
+        assert_eq!(shapes.clean(&cleanup), shapes.to_vec());
+
+        let cleanup = Cleanup { min_sliver_ratio: 3, ..Default::default() };
+        assert_eq!(shapes.clean(&cleanup), shapes.to_vec());
+    }
+
+    #[test]
+    fn test_caliper_width() {
+        assert_eq!(caliper_width(&square(0, 0, 4, 40)), 4.0);
+        assert_eq!(caliper_width(&points(&[[0, 0], [0, 10], [5, 20], [10, 10], [10, 0]])), 10.0);
+        assert_eq!(caliper_width(&points(&[[0, 0], [10, 0], [20, 0]])), 0.0);
+    }
+}
+
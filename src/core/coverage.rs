@@ -0,0 +1,544 @@
+//! This module simplifies polygon coverages: sets of polygons that share boundaries and do not overlap.
+//!
+//! The contours of all polygons are split against each other by the split phase of the overlay and
+//! equal pieces are merged, so a boundary shared by two polygons is stored once with the polygon on
+//! each side. The pieces are joined into chains between the nodes where the neighbors change, every
+//! chain is simplified once, and the polygons are assembled back from the simplified chains. The
+//! neighbors use the same simplified chain, so their boundaries stay coincident.
+//!
+//! A simplified chain that crosses or touches another one gets back the removed vertex farthest from
+//! it, until the chains are noded. A ring always keeps at least three points, so no polygon disappears.
+
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use i_shape::int::path::{IntPath, PointPathExtension};
+use i_shape::int::shape::{IntShape, IntShapes};
+use crate::core::generalize::{is_inside, sqr_distance, sqr_length, triangle_area, Candidate, Tolerance};
+use crate::core::noding::Noder;
+use crate::core::overlay::ShapeType;
+use crate::core::solver::Solver;
+use crate::segm::segment::Segment;
+use crate::segm::winding_count::WindingCount;
+use crate::split::solver::SplitSegments;
+
+/// Trait `SimplifyCoverage` simplifies polygons that share boundaries without opening gaps or overlaps between them.
+pub trait SimplifyCoverage {
+    /// Simplifies the coverage with the default solver.
+    /// - `tolerance`: The distance or the area tolerance, applied once to every boundary between two neighbors.
+    /// # Returns
+    /// The simplified polygons in the order of the input, every input shape may become several shapes.
+    /// The outer contours are clockwise and the holes are counterclockwise.
+    fn simplify_coverage(&self, tolerance: Tolerance) -> Vec<IntShapes>;
+
+    /// Simplifies the coverage.
+    /// - `tolerance`: The distance or the area tolerance, applied once to every boundary between two neighbors.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The simplified polygons in the order of the input, every input shape may become several shapes.
+    /// The outer contours are clockwise and the holes are counterclockwise.
+    fn simplify_coverage_with_solver(&self, tolerance: Tolerance, solver: Solver) -> Vec<IntShapes>;
+}
+
+impl SimplifyCoverage for [IntShape] {
+    #[inline]
+    fn simplify_coverage(&self, tolerance: Tolerance) -> Vec<IntShapes> {
+        self.simplify_coverage_with_solver(tolerance, Default::default())
+    }
+
+    fn simplify_coverage_with_solver(&self, tolerance: Tolerance, solver: Solver) -> Vec<IntShapes> {
+        let capacity = self.iter().flatten().map(|contour| contour.len()).sum();
+        let mut segments = Vec::with_capacity(capacity);
+        for (owner, shape) in self.iter().enumerate() {
+            for (index, contour) in shape.iter().enumerate() {
+                // outer contours are clockwise and holes are counterclockwise, the polygon is on the right
+                let is_clockwise = contour.unsafe_area() > 0;
+                let count = if (index == 0) == is_clockwise {
+                    Sides { left: 0, right: owner + 1 }
+                } else {
+                    Sides { left: owner + 1, right: 0 }
+                };
+                let mut p = if let Some(&p) = contour.last() { p } else { continue; };
+                for &q in contour.iter() {
+                    if p != q {
+                        segments.push(Segment::create_and_validate(p, q, count));
+                    }
+                    p = q;
+                }
+            }
+        }
+
+        if segments.is_empty() {
+            return vec![Vec::new(); self.len()];
+        }
+
+        let edges: Vec<([IntPoint; 2], Sides)> = segments
+            .split_segments(solver)
+            .into_iter()
+            .map(|s| ([s.x_segment.a, s.x_segment.b], s.count))
+            .collect();
+
+        let mut chains = chains(&edges);
+        for chain in chains.iter_mut() {
+            chain.simplify(tolerance);
+        }
+        repair(&mut chains, solver);
+
+        assemble(&chains, self.len())
+    }
+}
+
+/// The polygons on both sides of a piece: 0 is no polygon, `i + 1` is the polygon `i`.
+/// The sides are taken looking from `a` to `b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Sides {
+    left: usize,
+    right: usize,
+}
+
+impl WindingCount for Sides {
+    #[inline(always)]
+    fn is_not_empty(&self) -> bool {
+        // a piece with the same polygon on both sides is not a boundary
+        self.left != self.right
+    }
+
+    #[inline(always)]
+    fn new(_subj: i32, _clip: i32) -> Self {
+        Self { left: 0, right: 0 }
+    }
+
+    #[inline(always)]
+    fn with_shape_type(_shape_type: ShapeType) -> (Self, Self) {
+        let empty = <Self as WindingCount>::new(0, 0);
+        (empty, empty)
+    }
+
+    #[inline(always)]
+    fn add(self, count: Self) -> Self {
+        let left = if self.left != 0 { self.left } else { count.left };
+        let right = if self.right != 0 { self.right } else { count.right };
+        Self { left, right }
+    }
+
+    #[inline(always)]
+    fn apply(&mut self, count: Self) {
+        *self = self.add(count);
+    }
+
+    #[inline(always)]
+    fn invert(self) -> Self {
+        Self { left: self.right, right: self.left }
+    }
+}
+
+/// A boundary between two nodes, a closed chain ends with its first point.
+struct Chain {
+    points: Vec<IntPoint>,
+    indices: Vec<usize>,
+    sides: Sides,
+}
+
+impl Chain {
+    #[inline]
+    fn is_closed(&self) -> bool {
+        self.points.first() == self.points.last()
+    }
+
+    fn simplify(&mut self, tolerance: Tolerance) {
+        let n = self.points.len();
+        if n < 3 {
+            return;
+        }
+
+        let mut keep = vec![false; n];
+        keep[0] = true;
+        keep[n - 1] = true;
+
+        match tolerance {
+            Tolerance::Distance(distance) => {
+                let sqr_tolerance = (distance as f64) * (distance as f64);
+                if self.is_closed() {
+                    let first = self.points[0];
+                    let far = (1..n - 1)
+                        .max_by(|&i, &j| sqr_length(first, self.points[i]).total_cmp(&sqr_length(first, self.points[j])))
+                        .unwrap_or(1);
+                    keep[far] = true;
+                    douglas_peucker(&self.points, 0, far, sqr_tolerance, &mut keep);
+                    douglas_peucker(&self.points, far, n - 1, sqr_tolerance, &mut keep);
+                } else {
+                    douglas_peucker(&self.points, 0, n - 1, sqr_tolerance, &mut keep);
+                }
+            }
+            Tolerance::Area(area) => {
+                let min_count = if self.is_closed() { 4 } else { 2 };
+                visvalingam(&self.points, area as f64, min_count, &mut keep);
+            }
+        }
+
+        self.indices = (0..n).filter(|&i| keep[i]).collect();
+
+        // a ring keeps three distinct points
+        while self.is_closed() && self.indices.len() < 4 {
+            let k = (0..self.indices.len() - 1)
+                .max_by_key(|&k| self.indices[k + 1] - self.indices[k])
+                .unwrap_or(0);
+            if !self.refine(self.indices[k]) {
+                break;
+            }
+        }
+    }
+
+    /// Keeps the removed point farthest from the edge starting at the point `start`, returns `false` if there is none.
+    fn refine(&mut self, start: usize) -> bool {
+        let k = match self.indices.binary_search(&start) {
+            Ok(k) if k + 1 < self.indices.len() => k,
+            _ => return false,
+        };
+        let end = self.indices[k + 1];
+        if end - start < 2 {
+            return false;
+        }
+
+        let (a, b) = (self.points[start], self.points[end]);
+        let best = (start + 1..end)
+            .max_by(|&i, &j| sqr_distance(self.points[i], a, b).total_cmp(&sqr_distance(self.points[j], a, b)))
+            .unwrap_or(start + 1);
+
+        self.indices.insert(k + 1, best);
+        true
+    }
+
+    #[inline]
+    fn path(&self, is_reversed: bool) -> IntPath {
+        let path: IntPath = self.indices.iter().map(|&i| self.points[i]).collect();
+        if is_reversed { path.into_reversed() } else { path }
+    }
+}
+
+/// Joins the edges into chains that stop at nodes of degree other than 2 and where the neighbors change.
+/// Closed chains without such nodes start at their smallest point.
+fn chains(edges: &[([IntPoint; 2], Sides)]) -> Vec<Chain> {
+    let mut nodes: Vec<IntPoint> = edges.iter().flat_map(|e| e.0).collect();
+    nodes.sort_unstable();
+    nodes.dedup();
+
+    let ends: Vec<[usize; 2]> = edges.iter()
+        .map(|e| e.0.map(|p| nodes.binary_search(&p).unwrap()))
+        .collect();
+
+    // adjacency lists of the nodes in one buffer
+    let mut offsets = vec![0; nodes.len() + 1];
+    for &[a, b] in ends.iter() {
+        offsets[a + 1] += 1;
+        offsets[b + 1] += 1;
+    }
+    for i in 1..offsets.len() {
+        offsets[i] += offsets[i - 1];
+    }
+    let mut fill = offsets.clone();
+    let mut adjacency = vec![0; 2 * ends.len()];
+    for (e, &[a, b]) in ends.iter().enumerate() {
+        adjacency[fill[a]] = e;
+        fill[a] += 1;
+        adjacency[fill[b]] = e;
+        fill[b] += 1;
+    }
+
+    let other = |e: usize, n: usize| if ends[e][0] == n { ends[e][1] } else { ends[e][0] };
+    // the sides of the edge looking from the node
+    let sides_from = |e: usize, n: usize| if ends[e][0] == n { edges[e].1 } else { edges[e].1.invert() };
+
+    let is_break: Vec<bool> = (0..nodes.len()).map(|n| {
+        let around = &adjacency[offsets[n]..offsets[n + 1]];
+        around.len() != 2 || sides_from(around[0], n) != sides_from(around[1], n).invert()
+    }).collect();
+
+    let mut visited = vec![false; edges.len()];
+    let mut chains = Vec::new();
+
+    let walk = |start: usize, first_edge: usize, visited: &mut [bool]| {
+        let sides = sides_from(first_edge, start);
+        let mut points = vec![nodes[start]];
+        let mut node = start;
+        let mut e = first_edge;
+        loop {
+            visited[e] = true;
+            node = other(e, node);
+            points.push(nodes[node]);
+            if is_break[node] || node == start {
+                break;
+            }
+            let next = adjacency[offsets[node]..offsets[node + 1]].iter().copied().find(|&i| !visited[i]);
+            match next {
+                Some(i) => e = i,
+                None => break,
+            }
+        }
+        let indices = (0..points.len()).collect();
+        Chain { points, indices, sides }
+    };
+
+    for n in 0..nodes.len() {
+        if !is_break[n] {
+            continue;
+        }
+        for &e in adjacency[offsets[n]..offsets[n + 1]].iter() {
+            if !visited[e] {
+                chains.push(walk(n, e, &mut visited));
+            }
+        }
+    }
+
+    for e in 0..edges.len() {
+        if !visited[e] {
+            chains.push(walk(ends[e][0], e, &mut visited));
+        }
+    }
+
+    chains
+}
+
+/// Refines the simplified chains until no edge is split by another one and no edge is repeated.
+fn repair(chains: &mut [Chain], solver: Solver) {
+    loop {
+        let mut edges: Vec<([IntPoint; 2], usize, usize)> = Vec::new();
+        for (c, chain) in chains.iter().enumerate() {
+            for pair in chain.indices.windows(2) {
+                let (a, b) = (chain.points[pair[0]], chain.points[pair[1]]);
+                let segment = if a < b { [a, b] } else { [b, a] };
+                edges.push((segment, c, pair[0]));
+            }
+        }
+        edges.sort_unstable();
+
+        let mut noder = Noder::new(edges.len());
+        for &([a, b], _, _) in edges.iter() {
+            noder.add_segment(a, b);
+        }
+        let noded = noder.node_with_solver(solver);
+
+        let mut conflicts = Vec::new();
+        for (i, &(segment, c, start)) in edges.iter().enumerate() {
+            let is_repeated = i > 0 && edges[i - 1].0 == segment || i + 1 < edges.len() && edges[i + 1].0 == segment;
+            if is_repeated || noded.segments.binary_search(&segment).is_err() {
+                conflicts.push((c, start));
+            }
+        }
+
+        let mut is_refined = false;
+        for &(c, start) in conflicts.iter() {
+            is_refined |= chains[c].refine(start);
+        }
+
+        if !is_refined {
+            return;
+        }
+    }
+}
+
+/// Builds the polygons from the chains on their boundaries.
+fn assemble(chains: &[Chain], count: usize) -> Vec<IntShapes> {
+    // every polygon walks its chains with its interior on the right
+    let mut directed: Vec<(usize, IntPoint, usize, bool)> = Vec::with_capacity(2 * chains.len());
+    for (c, chain) in chains.iter().enumerate() {
+        if chain.sides.right != 0 {
+            directed.push((chain.sides.right - 1, chain.points[0], c, false));
+        }
+        if chain.sides.left != 0 {
+            directed.push((chain.sides.left - 1, *chain.points.last().unwrap(), c, true));
+        }
+    }
+    directed.sort_unstable();
+
+    let mut rings: Vec<Vec<IntPath>> = vec![Vec::new(); count];
+    let mut used = vec![false; directed.len()];
+    for i in 0..directed.len() {
+        if used[i] {
+            continue;
+        }
+        used[i] = true;
+        let (owner, _, c, is_reversed) = directed[i];
+        let mut ring = chains[c].path(is_reversed);
+
+        while ring.first() != ring.last() {
+            let end = *ring.last().unwrap();
+            let from = directed.partition_point(|d| (d.0, d.1) < (owner, end));
+            let next = (from..directed.len())
+                .take_while(|&j| directed[j].0 == owner && directed[j].1 == end)
+                .find(|&j| !used[j]);
+            let j = if let Some(j) = next { j } else { break; };
+            used[j] = true;
+            let path = chains[directed[j].2].path(directed[j].3);
+            ring.extend_from_slice(&path[1..]);
+        }
+
+        if ring.len() > 1 && ring.first() == ring.last() {
+            ring.pop();
+        }
+        rings[owner].push(ring);
+    }
+
+    rings.into_iter().map(|owner_rings| {
+        let (outers, holes): (Vec<IntPath>, Vec<IntPath>) = owner_rings.into_iter()
+            .partition(|ring| ring.unsafe_area() > 0);
+
+        let areas: Vec<i64> = outers.iter().map(|outer| outer.unsafe_area()).collect();
+        let mut shapes: IntShapes = outers.into_iter().map(|outer| vec![outer]).collect();
+        for hole in holes.into_iter() {
+            // the smallest outer contour around the hole
+            let best = (0..shapes.len())
+                .filter(|&s| is_inside(&hole, &shapes[s][0]))
+                .min_by_key(|&s| areas[s]);
+            if let Some(s) = best {
+                shapes[s].push(hole);
+            }
+        }
+        shapes
+    }).collect()
+}
+
+/// Keeps the points between `start` and `end` farther than the tolerance from the simplified chain.
+fn douglas_peucker(points: &[IntPoint], start: usize, end: usize, sqr_tolerance: f64, keep: &mut [bool]) {
+    let mut stack = vec![(start, end)];
+    while let Some((start, end)) = stack.pop() {
+        let (a, b) = (points[start], points[end]);
+        let mut best = start;
+        let mut best_distance = sqr_tolerance;
+        for (i, &p) in points.iter().enumerate().take(end).skip(start + 1) {
+            let d = sqr_distance(p, a, b);
+            if d > best_distance {
+                best_distance = d;
+                best = i;
+            }
+        }
+        if best != start {
+            keep[best] = true;
+            stack.push((start, best));
+            stack.push((best, end));
+        }
+    }
+}
+
+/// Removes the inner points of the chain while their triangle is smaller than the tolerance.
+fn visvalingam(points: &[IntPoint], area: f64, min_count: usize, keep: &mut [bool]) {
+    let n = points.len();
+    let mut prev: Vec<usize> = (0..n).map(|i| i.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1).min(n - 1)).collect();
+    let mut versions = vec![0; n];
+    let mut removed = vec![false; n];
+    let mut count = n;
+
+    let triangle = |prev: &[usize], next: &[usize], i: usize| {
+        triangle_area(points[prev[i]], points[i], points[next[i]])
+    };
+
+    let mut heap: BinaryHeap<Candidate> = (1..n - 1)
+        .map(|index| Candidate { area: triangle(&prev, &next, index), index, version: 0 })
+        .collect();
+
+    while let Some(candidate) = heap.pop() {
+        if count <= min_count || candidate.area >= area {
+            break;
+        }
+        let i = candidate.index;
+        if removed[i] || versions[i] != candidate.version {
+            continue;
+        }
+
+        removed[i] = true;
+        count -= 1;
+        let (p, q) = (prev[i], next[i]);
+        next[p] = q;
+        prev[q] = p;
+
+        for j in [p, q] {
+            if j == 0 || j == n - 1 {
+                continue;
+            }
+            versions[j] += 1;
+            let area = triangle(&prev, &next, j);
+            heap.push(Candidate { area, index: j, version: versions[j] });
+        }
+    }
+
+    for (k, &is_removed) in keep.iter_mut().zip(removed.iter()) {
+        *k = !is_removed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::coverage::SimplifyCoverage;
+    use crate::core::generalize::Tolerance;
+    use crate::core::validation::Validate;
+
+    fn points(coords: &[[i32; 2]]) -> Vec<IntPoint> {
+        coords.iter().map(|&[x, y]| IntPoint::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_shared_boundary() {
+        // two squares with a noisy shared boundary, the right square is not split at the shared vertices
+        let left = points(&[[0, 0], [0, 100], [50, 100], [52, 75], [49, 50], [51, 25], [50, 0]]);
+        let right = points(&[[50, 0], [51, 25], [49, 50], [52, 75], [50, 100], [100, 100], [100, 0]]);
+        let coverage = [vec![left], vec![right]];
+
+        for tolerance in [Tolerance::Distance(5), Tolerance::Area(200)] {
+            let result = coverage.simplify_coverage(tolerance);
+            assert_eq!(result.len(), 2);
+            let left = &result[0][0][0];
+            let right = &result[1][0][0];
+            assert_eq!(left.len(), 4);
+            assert_eq!(right.len(), 4);
+            assert!(left.contains(&IntPoint::new(50, 0)) && left.contains(&IntPoint::new(50, 100)));
+            assert!(right.contains(&IntPoint::new(50, 0)) && right.contains(&IntPoint::new(50, 100)));
+        }
+    }
+
+    #[test]
+    fn test_island() {
+        // a noisy island inside a square keeps three points and stays a hole of the square
+        let outer = points(&[[0, 0], [0, 100], [100, 100], [100, 0]]);
+        let island = points(&[[40, 40], [40, 60], [50, 61], [60, 60], [60, 40], [50, 39]]);
+        let hole: Vec<IntPoint> = island.iter().rev().copied().collect();
+        let coverage = [vec![outer.clone(), hole], vec![island]];
+
+        let result = coverage.simplify_coverage(Tolerance::Distance(50));
+        assert_eq!(result[0].len(), 1);
+        assert_eq!(result[0][0].len(), 2);
+        assert_eq!(result[0][0][0].len(), 4);
+        assert_eq!(result[1].len(), 1);
+        assert_eq!(result[1][0][0].len(), 3);
+
+        // the hole is the island reversed
+        let mut hole = result[0][0][1].clone();
+        hole.reverse();
+        let mut island = result[1][0][0].clone();
+        hole.sort_unstable();
+        island.sort_unstable();
+        assert_eq!(hole, island);
+    }
+
+    #[test]
+    fn test_no_duplicate() {
+        // both boundaries of the middle polygon become the same edge, so they are restored
+        let a = points(&[[0, 0], [0, 20], [40, 20], [50, 14], [60, 20], [100, 20], [100, 0]]);
+        let b = points(&[[0, 20], [0, 40], [100, 40], [100, 20], [60, 20], [50, 30], [40, 20]]);
+        let c = points(&[[40, 20], [50, 30], [60, 20], [50, 14]]);
+        let coverage = [vec![a], vec![b], vec![c.clone()]];
+
+        let result = coverage.simplify_coverage(Tolerance::Distance(10));
+        assert_eq!(result.iter().map(|shapes| shapes.len()).collect::<Vec<_>>(), vec![1, 1, 1]);
+        assert_eq!(result[0][0][0].len(), 7);
+        assert_eq!(result[1][0][0].len(), 7);
+        assert_eq!(result[2][0][0].len(), 4);
+
+        let shapes: Vec<_> = result.into_iter().flatten().collect();
+        assert!(shapes.iter().all(|shape| core::slice::from_ref(shape).validate().is_valid()));
+    }
+}
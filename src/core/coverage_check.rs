@@ -0,0 +1,207 @@
+//! This module checks that polygons tile the plane without gaps and overlaps, as cadastral or
+//! zoning layers must.
+//!
+//! The polygons are split once as the groups of a [`Partition`], every polygon is a group, so every
+//! region of the arrangement knows how many times every polygon winds around it. A region covered
+//! more than once is an overlap, even if it is covered twice by the same polygon. A frame around
+//! all polygons is one more group, so a gap is a region covered only by the frame that is not
+//! connected to the frame itself.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use i_shape::base::data::Shape;
+use i_shape::int::path::PointPathExtension;
+use i_shape::int::shape::{IntContour, IntShape, IntShapes};
+use crate::core::partition::Partition;
+use crate::core::solver::Solver;
+
+/// A region covered by more than one polygon.
+/// - `shape`: The region, the first contour is the outer boundary and the rest are holes.
+/// - `polygons`: The sorted indices of the polygons that cover the region.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Overlap<P = IntPoint> {
+    pub shape: Shape<P>,
+    pub polygons: Vec<usize>,
+}
+
+/// The result of a coverage check.
+/// - `overlaps`: The regions covered by more than one polygon, sorted by the polygons.
+/// - `gaps`: The regions inside the union of the polygons that no polygon covers.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoverageReport<P = IntPoint> {
+    pub overlaps: Vec<Overlap<P>>,
+    pub gaps: Vec<Shape<P>>,
+}
+
+impl<P> CoverageReport<P> {
+    /// Returns `true` if the polygons have no overlaps and no reported gaps.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.overlaps.is_empty() && self.gaps.is_empty()
+    }
+}
+
+/// Trait `CheckCoverage` reports the overlaps and the gaps between polygons.
+pub trait CheckCoverage {
+    /// Checks the coverage with the default solver.
+    /// - `max_gap_area`: If set, only gaps with an area not larger than it are reported, so intended holes
+    ///   such as lakes can be told apart from slivers.
+    /// # Returns
+    /// The overlaps with the indices of the polygons involved and the gaps. A region is an overlap if the
+    /// winding numbers of all polygons around it sum to more than one in absolute value, so a polygon
+    /// that overlaps itself is reported too.
+    fn check_coverage(&self, max_gap_area: Option<usize>) -> CoverageReport;
+
+    /// Checks the coverage.
+    /// - `max_gap_area`: If set, only gaps with an area not larger than it are reported.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The overlaps with the indices of the polygons involved and the gaps.
+    fn check_coverage_with_solver(&self, max_gap_area: Option<usize>, solver: Solver) -> CoverageReport;
+}
+
+impl CheckCoverage for [IntShape] {
+    #[inline]
+    fn check_coverage(&self, max_gap_area: Option<usize>) -> CoverageReport {
+        self.check_coverage_with_solver(max_gap_area, Default::default())
+    }
+
+    fn check_coverage_with_solver(&self, max_gap_area: Option<usize>, solver: Solver) -> CoverageReport {
+        let frame = if let Some(frame) = frame(self) { frame } else {
+            return CoverageReport { overlaps: Vec::new(), gaps: Vec::new() };
+        };
+
+        // the frame is the last group, the regions covered only by it are the gaps and the outside
+        let frame_group = self.len();
+        let capacity = self.iter().flatten().map(|contour| contour.len()).sum::<usize>() + frame.len();
+        let mut partition = Partition::new(capacity);
+        for (index, shape) in self.iter().enumerate() {
+            partition.add_shape(shape, index);
+        }
+        partition.add_contour(&frame, frame_group);
+        let arrangement = partition.into_arrangement(solver);
+
+        let mut overlaps = Vec::new();
+        let classify = |winding: &[(usize, i32)]| {
+            let (_, polygons) = winding.split_last()?;
+            let count: i32 = polygons.iter().map(|&(_, winding)| winding.abs()).sum();
+            (count > 1).then(|| polygons.iter().map(|&(polygon, _)| polygon).collect::<Vec<_>>())
+        };
+        for (polygons, shapes) in arrangement.extract(classify) {
+            for shape in shapes.into_iter() {
+                overlaps.push(Overlap { shape, polygons: polygons.clone() });
+            }
+        }
+
+        let mut gaps: IntShapes = arrangement.extract(|winding| (winding.len() == 1).then_some(()))
+            .into_iter()
+            .flat_map(|(_, shapes)| shapes)
+            // the shape bounded by the frame is the outside of the union
+            .filter(|shape| shape[0].iter().all(|&p| p != frame[0]))
+            .collect();
+
+        if let Some(max_area) = max_gap_area {
+            // a gap area is the sum of the signed areas of its contours, holes are subtracted
+            gaps.retain(|gap| gap.iter().map(|contour| contour.unsafe_area()).sum::<i64>().unsigned_abs() / 2 <= max_area as u64);
+        }
+
+        CoverageReport { overlaps, gaps }
+    }
+}
+
+/// A clockwise rectangle around all shapes that touches none of them.
+fn frame(shapes: &[IntShape]) -> Option<IntContour> {
+    let mut points = shapes.iter().flatten().flatten();
+    let first = *points.next()?;
+    let (mut min, mut max) = (first, first);
+    for p in points {
+        min = IntPoint::new(min.x.min(p.x), min.y.min(p.y));
+        max = IntPoint::new(max.x.max(p.x), max.y.max(p.y));
+    }
+    let (x0, y0, x1, y1) = (min.x - 1, min.y - 1, max.x + 1, max.y + 1);
+
+    Some(vec![IntPoint::new(x0, y0), IntPoint::new(x0, y1), IntPoint::new(x1, y1), IntPoint::new(x1, y0)])
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::coverage_check::{CheckCoverage, Overlap};
+
+    fn rect(x0: i32, y0: i32, x1: i32, y1: i32) -> Vec<IntPoint> {
+        vec![IntPoint::new(x0, y0), IntPoint::new(x0, y1), IntPoint::new(x1, y1), IntPoint::new(x1, y0)]
+    }
+
+    #[test]
+    fn test_tiling() {
+        let shapes = [
+            vec![rect(0, 0, 10, 10)],
+            vec![rect(10, 0, 20, 10)],
+            vec![rect(0, 10, 20, 20)],
+        ];
+
+        let report = shapes.check_coverage(None);
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn test_overlap() {
+        let shapes = [
+            vec![rect(0, 0, 10, 10)],
+            vec![rect(8, 0, 20, 10)],
+            vec![rect(0, 9, 20, 20)],
+        ];
+
+        let report = shapes.check_coverage(None);
+        assert!(report.gaps.is_empty());
+
+        let polygons: Vec<Vec<usize>> = report.overlaps.iter().map(|overlap| overlap.polygons.clone()).collect();
+        assert_eq!(polygons, vec![vec![0, 1], vec![0, 1, 2], vec![0, 2], vec![1, 2]]);
+        assert_eq!(report.overlaps[0].shape, vec![rect(8, 0, 10, 9)]);
+        assert_eq!(report.overlaps[1].shape, vec![rect(8, 9, 10, 10)]);
+    }
+
+    #[test]
+    fn test_gaps() {
+        // a ring of four rectangles around a hole with an island
+        let shapes = [
+            vec![rect(0, 0, 30, 10)],
+            vec![rect(0, 20, 30, 30)],
+            vec![rect(0, 10, 10, 20)],
+            vec![rect(20, 10, 30, 20)],
+            vec![rect(14, 14, 16, 16)],
+            vec![rect(40, 0, 41, 30)],
+            vec![rect(42, 0, 43, 30)],
+            vec![rect(40, 30, 43, 31)],
+            vec![rect(40, -1, 43, 0)],
+        ];
+
+        let report = shapes.check_coverage(None);
+        assert!(report.overlaps.is_empty());
+        assert_eq!(report.gaps.len(), 2);
+
+        let ring = report.gaps.iter().find(|gap| gap.len() == 2).unwrap();
+        assert_eq!(ring[0], rect(10, 10, 20, 20));
+
+        let small = shapes.check_coverage(Some(30));
+        assert_eq!(small.gaps, vec![vec![rect(41, 0, 42, 30)]]);
+    }
+
+    #[test]
+    fn test_self_overlap() {
+        // the contours of the second polygon overlap each other
+        let shapes = [
+            vec![rect(0, 0, 10, 10)],
+            vec![rect(10, 0, 20, 10), rect(15, 0, 25, 10)],
+        ];
+
+        let report = shapes.check_coverage(None);
+        assert!(report.gaps.is_empty());
+        assert_eq!(report.overlaps, vec![Overlap { shape: vec![rect(15, 0, 20, 10)], polygons: vec![1] }]);
+    }
+}
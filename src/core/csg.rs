@@ -0,0 +1,177 @@
+//! This module evaluates constructive solid geometry expressions such as `(A ∪ B) − (C ∩ D) ⊕ E`.
+//!
+//! Chaining `overlay` calls rebuilds the segments from scratch at every step and snaps the result
+//! of every step again. Here all leaves are split once as the groups of a [`Partition`], every
+//! leaf is a group. The fill rule turns the windings of a region into the set of leaves that cover
+//! it, the expression is evaluated on this set and the result is extracted once.
+
+use alloc::boxed::Box;
+use i_shape::int::shape::{IntContour, IntShape, IntShapes};
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::partition::{Arrangement, Partition};
+use crate::core::solver::Solver;
+
+/// A boolean expression over the leaves of a [`Csg`].
+/// - `Leaf`: The region covered by the leaf with the given index.
+/// - `Operation`: The `OverlayRule` applied to two expressions, the first one is the subject and the second one is the clip.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CsgExpression {
+    Leaf(usize),
+    Operation(OverlayRule, Box<CsgExpression>, Box<CsgExpression>),
+}
+
+impl CsgExpression {
+    /// Combines two expressions with an `OverlayRule`.
+    /// - `overlay_rule`: The boolean operation, `self` is the subject.
+    /// - `clip`: The expression used as the clip.
+    #[inline]
+    pub fn apply(self, overlay_rule: OverlayRule, clip: CsgExpression) -> Self {
+        Self::Operation(overlay_rule, Box::new(self), Box::new(clip))
+    }
+
+    /// Returns `true` if a region covered by the given sorted leaves is inside the expression.
+    fn contains(&self, leaves: &[usize]) -> bool {
+        match self {
+            Self::Leaf(index) => leaves.binary_search(index).is_ok(),
+            Self::Operation(overlay_rule, subj, clip) => {
+                let (subj, clip) = (subj.contains(leaves), clip.contains(leaves));
+                match overlay_rule {
+                    OverlayRule::Subject => subj,
+                    OverlayRule::Clip => clip,
+                    OverlayRule::Intersect => subj && clip,
+                    OverlayRule::Union => subj || clip,
+                    OverlayRule::Difference => subj && !clip,
+                    OverlayRule::InverseDifference => !subj && clip,
+                    OverlayRule::Xor => subj != clip,
+                }
+            }
+        }
+    }
+}
+
+/// Collects the leaves of constructive solid geometry expressions to evaluate them in one arrangement.
+///
+/// Outer contours are expected clockwise and holes counterclockwise, it matters only for
+/// `FillRule::Positive` and `FillRule::Negative`.
+#[derive(Clone)]
+pub struct Csg {
+    partition: Partition,
+    leaves: usize,
+}
+
+impl Csg {
+    /// Constructs a new `Csg`.
+    /// - `capacity`: The expected number of edges.
+    pub fn new(capacity: usize) -> Self {
+        Self { partition: Partition::new(capacity), leaves: 0 }
+    }
+
+    /// Adds a leaf made of closed paths.
+    /// - `contours`: An array of `IntContour` instances.
+    /// # Returns
+    /// The expression of the new leaf.
+    pub fn add_leaf(&mut self, contours: &[IntContour]) -> CsgExpression {
+        self.partition.add_contours(contours, self.leaves);
+        self.next_leaf()
+    }
+
+    /// Adds a leaf made of shapes.
+    /// - `shapes`: An array of `IntShape` instances.
+    /// # Returns
+    /// The expression of the new leaf.
+    pub fn add_shapes_leaf(&mut self, shapes: &[IntShape]) -> CsgExpression {
+        self.partition.add_shapes(shapes, self.leaves);
+        self.next_leaf()
+    }
+
+    #[inline]
+    fn next_leaf(&mut self) -> CsgExpression {
+        self.leaves += 1;
+        CsgExpression::Leaf(self.leaves - 1)
+    }
+
+    /// Evaluates an expression with the default solver.
+    /// - `expression`: The expression over the leaves of this `Csg`.
+    /// - `fill_rule`: The fill rule applied to the winding number of every leaf separately.
+    /// # Returns
+    /// The shapes of the expression. The outer contours are clockwise and the holes are counterclockwise.
+    #[inline]
+    pub fn evaluate(self, expression: &CsgExpression, fill_rule: FillRule) -> IntShapes {
+        self.evaluate_with_solver(expression, fill_rule, Default::default())
+    }
+
+    /// Evaluates an expression.
+    /// - `expression`: The expression over the leaves of this `Csg`.
+    /// - `fill_rule`: The fill rule applied to the winding number of every leaf separately.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The shapes of the expression. The outer contours are clockwise and the holes are counterclockwise.
+    pub fn evaluate_with_solver(self, expression: &CsgExpression, fill_rule: FillRule, solver: Solver) -> IntShapes {
+        let arrangement = self.partition.into_arrangement(solver);
+        let classify = |winding: &[(usize, i32)]| {
+            let leaves = Arrangement::membership(winding, fill_rule).unwrap_or_default();
+            expression.contains(&leaves).then_some(())
+        };
+
+        arrangement.extract(classify)
+            .into_iter()
+            .flat_map(|(_, shapes)| shapes)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::csg::Csg;
+    use crate::core::fill_rule::FillRule;
+    use crate::core::overlay::Overlay;
+    use crate::core::overlay_rule::OverlayRule;
+
+    fn rect(x0: i32, y0: i32, x1: i32, y1: i32) -> Vec<IntPoint> {
+        vec![IntPoint::new(x0, y0), IntPoint::new(x0, y1), IntPoint::new(x1, y1), IntPoint::new(x1, y0)]
+    }
+
+    #[test]
+    fn test_expression() {
+        // (A ∪ B) − (C ∩ D) ⊕ E
+        let contours = [rect(0, 0, 10, 10), rect(5, 0, 20, 10), rect(2, 2, 12, 8), rect(8, 4, 18, 6), rect(15, 5, 25, 15)];
+
+        let mut csg = Csg::new(20);
+        let [a, b, c, d, e] = contours.each_ref().map(|contour| csg.add_leaf(core::slice::from_ref(contour)));
+        let expression = a.apply(OverlayRule::Union, b)
+            .apply(OverlayRule::Difference, c.apply(OverlayRule::Intersect, d))
+            .apply(OverlayRule::Xor, e);
+
+        let result = csg.evaluate(&expression, FillRule::NonZero);
+
+        let ab = Overlay::with_contours(&[contours[0].clone()], &[contours[1].clone()]).overlay(OverlayRule::Union, FillRule::NonZero);
+        let cd = Overlay::with_contours(&[contours[2].clone()], &[contours[3].clone()]).overlay(OverlayRule::Intersect, FillRule::NonZero);
+        let abcd = Overlay::with_shapes(&ab, &cd).overlay(OverlayRule::Difference, FillRule::NonZero);
+        let expected = Overlay::with_shapes(&abcd, &[vec![contours[4].clone()]]).overlay(OverlayRule::Xor, FillRule::NonZero);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_single_leaf() {
+        let mut csg = Csg::new(8);
+        let a = csg.add_leaf(&[rect(0, 0, 10, 10)]);
+        let b = csg.add_leaf(&[rect(20, 0, 30, 10)]);
+
+        let result = csg.evaluate(&b.apply(OverlayRule::Subject, a), FillRule::NonZero);
+        assert_eq!(result, vec![vec![rect(20, 0, 30, 10)]]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut csg = Csg::new(4);
+        let a = csg.add_leaf(&[rect(0, 0, 10, 10)]);
+        let result = csg.evaluate(&a.clone().apply(OverlayRule::Difference, a), FillRule::NonZero);
+        assert!(result.is_empty());
+    }
+}
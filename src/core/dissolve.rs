@@ -0,0 +1,191 @@
+//! This module merges polygons that share an attribute key, as the GIS dissolve does.
+//!
+//! The contours of all keys are split once as the groups of a [`Partition`], every key is a group.
+//! The union of a key is extracted from the pieces where the key covers only one side, and the
+//! boundaries between keys are the pieces covered on both sides by different sets of keys.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use i_shape::base::data::{Path, Shapes};
+use i_shape::int::path::IntPath;
+use i_shape::int::shape::{IntContour, IntShape};
+use crate::core::fill_rule::FillRule;
+use crate::core::partition::{Arrangement, Partition};
+use crate::core::solver::Solver;
+use crate::string::network::join;
+
+/// The result of a dissolve.
+/// - `shapes`: The union of every key, sorted by key. The outer contours are clockwise and the holes are counterclockwise.
+/// - `boundaries`: The open paths between regions covered by different keys. Empty if the boundaries were not requested.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dissolved<K, P = IntPoint> {
+    pub shapes: Vec<(K, Shapes<P>)>,
+    pub boundaries: Vec<Path<P>>,
+}
+
+/// Collects contours labeled with an attribute key to merge all contours of the same key.
+#[derive(Clone)]
+pub struct Dissolve<K> {
+    partition: Partition,
+    keys: BTreeMap<K, usize>,
+}
+
+impl<K: Ord + Clone> Dissolve<K> {
+    /// Constructs a new `Dissolve`.
+    /// - `capacity`: The expected number of edges.
+    pub fn new(capacity: usize) -> Self {
+        Self { partition: Partition::new(capacity), keys: BTreeMap::new() }
+    }
+
+    /// Creates a new `Dissolve` from `(key, contour)` pairs.
+    /// - `pairs`: The closed paths with the key every path belongs to.
+    pub fn with_pairs(pairs: &[(K, IntContour)]) -> Self {
+        let capacity = pairs.iter().map(|(_, contour)| contour.len()).sum();
+        let mut dissolve = Self::new(capacity);
+        for (key, contour) in pairs.iter() {
+            dissolve.add_contour(key.clone(), contour);
+        }
+        dissolve
+    }
+
+    /// Adds a closed path.
+    /// - `key`: The key the contour belongs to.
+    /// - `contour`: An array of points that form a closed path.
+    pub fn add_contour(&mut self, key: K, contour: &[IntPoint]) {
+        let next = self.keys.len();
+        let group = *self.keys.entry(key).or_insert(next);
+        self.partition.add_contour(contour, group);
+    }
+
+    /// Adds a shape.
+    /// - `key`: The key the shape belongs to.
+    /// - `shape`: A reference to a `IntShape` instance.
+    pub fn add_shape(&mut self, key: K, shape: &IntShape) {
+        for contour in shape.iter() {
+            self.add_contour(key.clone(), contour);
+        }
+    }
+
+    /// Merges the contours of every key with the default solver.
+    /// - `fill_rule`: The fill rule applied to the winding number of every key separately.
+    /// - `boundaries`: If `true`, the boundaries between different keys are returned too.
+    /// # Returns
+    /// The union of every key and the requested boundaries.
+    #[inline]
+    pub fn into_dissolved(self, fill_rule: FillRule, boundaries: bool) -> Dissolved<K> {
+        self.into_dissolved_with_solver(fill_rule, boundaries, Default::default())
+    }
+
+    /// Merges the contours of every key.
+    /// - `fill_rule`: The fill rule applied to the winding number of every key separately.
+    /// - `boundaries`: If `true`, the boundaries between different keys are returned too.
+    ///   A boundary separates two regions that are both covered, by different sets of keys.
+    ///   Pieces meeting at a node of degree 2 are joined into one path.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The union of every key and the requested boundaries. A key that covers nothing is omitted.
+    pub fn into_dissolved_with_solver(self, fill_rule: FillRule, boundaries: bool, solver: Solver) -> Dissolved<K> {
+        let mut keys: Vec<Option<K>> = vec![None; self.keys.len()];
+        for (key, group) in self.keys.into_iter() {
+            keys[group] = Some(key);
+        }
+
+        let arrangement = self.partition.into_arrangement(solver);
+
+        let mut shapes: Vec<(K, Shapes<IntPoint>)> = arrangement.extract_groups(fill_rule).into_iter()
+            .filter_map(|(group, shapes)| keys[group].take().map(|key| (key, shapes)))
+            .collect();
+        shapes.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let boundaries = if boundaries { Self::boundaries(&arrangement, fill_rule) } else { Vec::new() };
+
+        Dissolved { shapes, boundaries }
+    }
+
+    fn boundaries(arrangement: &Arrangement, fill_rule: FillRule) -> Vec<IntPath> {
+        let memberships: Vec<Option<Vec<usize>>> = arrangement.windings.iter()
+            .map(|winding| Arrangement::membership(winding, fill_rule))
+            .collect();
+
+        let edges: Vec<[IntPoint; 2]> = arrangement.segments.iter()
+            .zip(arrangement.sides.iter())
+            .filter(|(_, &[bottom, top])| {
+                match (&memberships[bottom as usize], &memberships[top as usize]) {
+                    (Some(bottom), Some(top)) => bottom != top,
+                    _ => false,
+                }
+            })
+            .map(|(s, _)| [s.a, s.b])
+            .collect();
+
+        join(&edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::dissolve::Dissolve;
+    use crate::core::fill_rule::FillRule;
+
+    fn rect(x0: i32, y0: i32, x1: i32, y1: i32) -> Vec<IntPoint> {
+        vec![IntPoint::new(x0, y0), IntPoint::new(x0, y1), IntPoint::new(x1, y1), IntPoint::new(x1, y0)]
+    }
+
+    #[test]
+    fn test_counties() {
+        let pairs = [
+            ("a", rect(0, 0, 10, 10)),
+            ("a", rect(10, 0, 20, 10)),
+            ("b", rect(0, 10, 20, 20)),
+            ("a", rect(30, 0, 40, 10)),
+        ];
+
+        let dissolved = Dissolve::with_pairs(&pairs).into_dissolved(FillRule::NonZero, true);
+        assert_eq!(dissolved.shapes.len(), 2);
+
+        let (key, a) = &dissolved.shapes[0];
+        assert_eq!(*key, "a");
+        assert_eq!(a.len(), 2);
+        assert_eq!(a[0], vec![rect(0, 0, 20, 10)]);
+        assert_eq!(a[1], vec![rect(30, 0, 40, 10)]);
+
+        let (key, b) = &dissolved.shapes[1];
+        assert_eq!(*key, "b");
+        assert_eq!(b, &vec![vec![rect(0, 10, 20, 20)]]);
+
+        assert_eq!(dissolved.boundaries, vec![vec![IntPoint::new(0, 10), IntPoint::new(10, 10), IntPoint::new(20, 10)]]);
+    }
+
+    #[test]
+    fn test_no_boundaries() {
+        let pairs = [
+            (1, rect(0, 0, 10, 10)),
+            (0, rect(5, 0, 15, 10)),
+        ];
+
+        let dissolved = Dissolve::with_pairs(&pairs).into_dissolved(FillRule::NonZero, false);
+        assert!(dissolved.boundaries.is_empty());
+        assert_eq!(dissolved.shapes, vec![
+            (0, vec![vec![rect(5, 0, 15, 10)]]),
+            (1, vec![vec![rect(0, 0, 10, 10)]]),
+        ]);
+    }
+
+    #[test]
+    fn test_overlapping_keys() {
+        // the boundary runs through the overlap too, it separates {0} from {0, 1} and {0, 1} from {1}
+        let pairs = [
+            (0, rect(0, 0, 10, 10)),
+            (1, rect(5, 0, 15, 10)),
+        ];
+
+        let dissolved = Dissolve::with_pairs(&pairs).into_dissolved(FillRule::NonZero, true);
+        assert_eq!(dissolved.boundaries.len(), 2);
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 /// Represents the rule used to determine the "bind" of a shape, affecting how shapes are filled. For a visual description, see [Fill Rules](https://ishape-rust.github.io/iShape-js/overlay/filling_rules/filling_rules.html).
 /// - `EvenOdd`: Only odd-numbered sub-regions are filled.
@@ -6,6 +6,7 @@ use std::fmt;
 /// - `Positive`: Fills regions where the winding number is positive.
 /// - `Negative`: Fills regions where the winding number is negative.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FillRule {
     EvenOdd,
     NonZero,
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use crate::{core::link::OverlayLink};
 use crate::segm::segment::{SegmentFill, ALL, BOTH_BOTTOM, BOTH_TOP, CLIP_BOTH, CLIP_BOTTOM, CLIP_TOP, NONE, SUBJ_BOTH, SUBJ_BOTTOM, SUBJ_TOP};
 use super::overlay_rule::OverlayRule;
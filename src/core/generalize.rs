@@ -0,0 +1,497 @@
+//! This module simplifies shapes with a tolerance while keeping them valid.
+//!
+//! Every contour is simplified on its own with Douglas-Peucker (`Tolerance::Distance`) or
+//! Visvalingam-Whyatt (`Tolerance::Area`). The result is checked with `Validate`, which runs the
+//! intersection tests of the split phase. Every simplified edge that crosses or overlaps another edge,
+//! or lets a hole escape its outer contour, gets back the removed vertex farthest from it, and only the
+//! refined contours are checked again. The original contours are the worst case, so the result is as
+//! valid as the input.
+//!
+//! A contour that collapses below the tolerance is removed, a shape is removed with its outer contour.
+
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use i_float::int::point::IntPoint;
+use i_shape::int::path::PointPathExtension;
+use i_shape::int::shape::{IntContour, IntShape};
+use crate::core::validation::{validate_changed, ContourId, Issue, Validate};
+
+/// The tolerance of the simplification.
+/// - `Distance`: Douglas-Peucker, a removed vertex is not farther than this distance from the new edge.
+/// - `Area`: Visvalingam-Whyatt, vertices are removed while the triangle they form with their neighbors is smaller than this area.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tolerance<T = usize> {
+    Distance(T),
+    Area(T),
+}
+
+/// Trait `Generalize` simplifies shapes with a tolerance without creating crossings between their contours.
+pub trait Generalize {
+    /// Simplifies the shapes.
+    /// - `tolerance`: The distance or the area tolerance.
+    /// # Returns
+    /// The simplified shapes. A vertex is kept if removing it breaks the validity of the input.
+    fn generalize(&self, tolerance: Tolerance) -> Vec<IntShape>;
+}
+
+impl Generalize for [IntShape] {
+    fn generalize(&self, tolerance: Tolerance) -> Vec<IntShape> {
+        let mut rings: Vec<Vec<Ring>> = self.iter()
+            .map(|shape| shape.iter().map(|contour| Ring::new(contour, tolerance)).collect())
+            .collect();
+
+        // the touches of the input are not a result of the simplification
+        let mut touches: Vec<IntPoint> = self.validate().issues.iter()
+            .filter_map(|issue| if let Issue::Touching { point, .. } = issue { Some(*point) } else { None })
+            .collect();
+        touches.sort_unstable();
+
+        // only the rings refined in the last round can have new problems
+        let mut changed: Vec<Vec<bool>> = rings.iter().map(|shape| vec![true; shape.len()]).collect();
+
+        loop {
+            let (shapes, ids) = collect(self, &rings);
+            let is_changed = |id: ContourId| {
+                let (s, c) = ids[id.shape][id.contour];
+                changed[s][c]
+            };
+            let mut spans = Vec::new();
+
+            for issue in validate_changed(&shapes, is_changed).issues.iter() {
+                match *issue {
+                    Issue::Crossing { edges, .. }
+                    | Issue::DuplicateEdge { edges, .. }
+                    | Issue::OverlappingEdges { edges, .. } => {
+                        for edge in edges {
+                            spans.push(span(&rings, &ids, edge.shape, edge.contour, edge.index));
+                        }
+                    }
+                    Issue::Touching { point, edges } if touches.binary_search(&point).is_err() => {
+                        for edge in edges {
+                            spans.push(span(&rings, &ids, edge.shape, edge.contour, edge.index));
+                        }
+                    }
+                    Issue::Spike { contour, vertex, .. } => {
+                        let n = shapes[contour.shape][contour.contour].len();
+                        for index in [(vertex + n - 1) % n, vertex] {
+                            spans.push(span(&rings, &ids, contour.shape, contour.contour, index));
+                        }
+                    }
+                    Issue::WrongOrientation { contour } => {
+                        let n = shapes[contour.shape][contour.contour].len();
+                        for index in 0..n {
+                            spans.push(span(&rings, &ids, contour.shape, contour.contour, index));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            for (i, shape) in shapes.iter().enumerate() {
+                let is_outer_changed = is_changed(ContourId { shape: i, contour: 0 });
+                for (j, hole) in shape.iter().enumerate().skip(1) {
+                    if !is_outer_changed && !is_changed(ContourId { shape: i, contour: j }) || is_inside(hole, &shape[0]) {
+                        continue;
+                    }
+                    let (min, max) = bounds(hole);
+                    let (s, c) = ids[i][0];
+                    let ring = &rings[s][c];
+                    for (index, edge_span) in ring.spans().enumerate() {
+                        let (a, b) = ring.span_bounds(edge_span);
+                        if a.x <= max.x && min.x <= b.x && a.y <= max.y && min.y <= b.y {
+                            spans.push(span(&rings, &ids, i, 0, index));
+                        }
+                    }
+                }
+            }
+
+            spans.sort_unstable();
+            spans.dedup();
+
+            for shape in changed.iter_mut() {
+                shape.fill(false);
+            }
+
+            let mut is_refined = false;
+            for &(s, c, start, end) in spans.iter() {
+                if rings[s][c].refine(start, end) {
+                    changed[s][c] = true;
+                    is_refined = true;
+                }
+            }
+
+            if !is_refined {
+                return shapes;
+            }
+        }
+    }
+}
+
+impl Generalize for IntShape {
+    #[inline]
+    fn generalize(&self, tolerance: Tolerance) -> Vec<IntShape> {
+        core::slice::from_ref(self).generalize(tolerance)
+    }
+}
+
+/// Builds the current shapes, and for every contour the index of its ring.
+fn collect(shapes: &[IntShape], rings: &[Vec<Ring>]) -> (Vec<IntShape>, Vec<Vec<(usize, usize)>>) {
+    let mut result = Vec::with_capacity(shapes.len());
+    let mut ids = Vec::with_capacity(shapes.len());
+    for (s, (shape, shape_rings)) in shapes.iter().zip(rings.iter()).enumerate() {
+        if shape_rings.first().is_none_or(|outer| outer.is_collapsed) {
+            continue;
+        }
+        let mut contours = Vec::with_capacity(shape.len());
+        let mut contour_ids = Vec::with_capacity(shape.len());
+        for (c, ring) in shape_rings.iter().enumerate() {
+            if ring.is_collapsed {
+                continue;
+            }
+            contours.push(ring.indices.iter().map(|&i| ring.points[i]).collect());
+            contour_ids.push((s, c));
+        }
+        result.push(contours);
+        ids.push(contour_ids);
+    }
+    (result, ids)
+}
+
+/// The original indices of the ends of a current edge.
+#[inline]
+fn span(rings: &[Vec<Ring>], ids: &[Vec<(usize, usize)>], shape: usize, contour: usize, index: usize) -> (usize, usize, usize, usize) {
+    let (s, c) = ids[shape][contour];
+    let indices = &rings[s][c].indices;
+    let n = indices.len();
+    (s, c, indices[index], indices[(index + 1) % n])
+}
+
+/// A contour with the indices of the kept points.
+struct Ring {
+    points: Vec<IntPoint>,
+    indices: Vec<usize>,
+    is_collapsed: bool,
+}
+
+impl Ring {
+    fn new(contour: &IntContour, tolerance: Tolerance) -> Self {
+        let mut points = contour.clone();
+        points.dedup();
+        while points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+
+        if points.len() < 3 || points.unsafe_area() == 0 {
+            // nothing to simplify
+            let indices = (0..points.len()).collect();
+            return Self { points, indices, is_collapsed: false };
+        }
+
+        let indices = match tolerance {
+            Tolerance::Distance(distance) => douglas_peucker(&points, distance as f64),
+            Tolerance::Area(area) => visvalingam(&points, area as f64),
+        };
+
+        let is_collapsed = indices.len() < 3 || {
+            let kept: Vec<IntPoint> = indices.iter().map(|&i| points[i]).collect();
+            let kept_area = kept.unsafe_area();
+            let is_small = match tolerance {
+                // the last triangle is removed too if it is below the tolerance
+                Tolerance::Area(area) => kept.len() == 3 && (kept_area.unsigned_abs() as f64) < 2.0 * area as f64,
+                Tolerance::Distance(_) => false,
+            };
+            is_small || kept_area.signum() != points.unsafe_area().signum()
+        };
+
+        Self { points, indices, is_collapsed }
+    }
+
+    /// The original index ranges of the current edges.
+    #[inline]
+    fn spans(&self) -> impl Iterator<Item=(usize, usize)> + '_ {
+        let n = self.indices.len();
+        (0..n).map(move |k| (self.indices[k], self.indices[(k + 1) % n]))
+    }
+
+    /// The bounding box of the original points of the span.
+    fn span_bounds(&self, (start, end): (usize, usize)) -> (IntPoint, IntPoint) {
+        let n = self.points.len();
+        let len = (end + n - start) % n;
+        let len = if len == 0 { n } else { len };
+        let mut min = self.points[start];
+        let mut max = min;
+        for k in 1..=len {
+            let p = self.points[(start + k) % n];
+            min = IntPoint::new(min.x.min(p.x), min.y.min(p.y));
+            max = IntPoint::new(max.x.max(p.x), max.y.max(p.y));
+        }
+        (min, max)
+    }
+
+    /// Keeps the removed point of the span farthest from its edge, returns `false` if there is none.
+    fn refine(&mut self, start: usize, end: usize) -> bool {
+        let n = self.points.len();
+        let len = (end + n - start) % n;
+        let len = if len == 0 { n } else { len };
+        if len < 2 {
+            return false;
+        }
+
+        let (a, b) = (self.points[start], self.points[end]);
+        let mut best = (start + 1) % n;
+        let mut best_distance = -1.0;
+        for k in 1..len {
+            let i = (start + k) % n;
+            let distance = sqr_distance(self.points[i], a, b);
+            if distance > best_distance {
+                best_distance = distance;
+                best = i;
+            }
+        }
+
+        let pos = self.indices.iter().position(|&i| i == start).unwrap_or(0);
+        self.indices.insert(pos + 1, best);
+        true
+    }
+}
+
+fn douglas_peucker(points: &[IntPoint], distance: f64) -> Vec<usize> {
+    let n = points.len();
+    let sqr_tolerance = distance * distance;
+
+    // the first point and the farthest one from it split the contour into two chains
+    let far = (1..n)
+        .max_by(|&i, &j| sqr_length(points[0], points[i]).total_cmp(&sqr_length(points[0], points[j])))
+        .unwrap_or(1);
+
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[far] = true;
+
+    let mut stack = vec![(0, far), (far, n)];
+    while let Some((start, end)) = stack.pop() {
+        let (a, b) = (points[start], points[end % n]);
+        let mut best = start;
+        let mut best_distance = sqr_tolerance;
+        for (i, &p) in points.iter().enumerate().take(end).skip(start + 1) {
+            let d = sqr_distance(p, a, b);
+            if d > best_distance {
+                best_distance = d;
+                best = i;
+            }
+        }
+        if best != start {
+            keep[best] = true;
+            stack.push((start, best));
+            stack.push((best, end));
+        }
+    }
+
+    (0..n).filter(|&i| keep[i]).collect()
+}
+
+#[derive(PartialEq)]
+pub(super) struct Candidate {
+    pub(super) area: f64,
+    pub(super) index: usize,
+    pub(super) version: usize,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    // the smallest area is the greatest for the max heap
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.area.total_cmp(&self.area).then(other.index.cmp(&self.index))
+    }
+}
+
+fn visvalingam(points: &[IntPoint], area: f64) -> Vec<usize> {
+    let n = points.len();
+    let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let mut versions = vec![0; n];
+    let mut removed = vec![false; n];
+    let mut count = n;
+
+    let triangle = |prev: &[usize], next: &[usize], i: usize| {
+        triangle_area(points[prev[i]], points[i], points[next[i]])
+    };
+
+    let mut heap: BinaryHeap<Candidate> = (0..n)
+        .map(|index| Candidate { area: triangle(&prev, &next, index), index, version: 0 })
+        .collect();
+
+    while let Some(candidate) = heap.pop() {
+        if count <= 3 || candidate.area >= area {
+            break;
+        }
+        let i = candidate.index;
+        if removed[i] || versions[i] != candidate.version {
+            continue;
+        }
+
+        removed[i] = true;
+        count -= 1;
+        let (p, q) = (prev[i], next[i]);
+        next[p] = q;
+        prev[q] = p;
+
+        for j in [p, q] {
+            versions[j] += 1;
+            let area = triangle(&prev, &next, j);
+            heap.push(Candidate { area, index: j, version: versions[j] });
+        }
+    }
+
+    (0..n).filter(|&i| !removed[i]).collect()
+}
+
+#[inline]
+pub(super) fn sqr_length(a: IntPoint, b: IntPoint) -> f64 {
+    let dx = b.x as f64 - a.x as f64;
+    let dy = b.y as f64 - a.y as f64;
+    dx * dx + dy * dy
+}
+
+/// The squared distance from `p` to the segment `a -> b`.
+pub(super) fn sqr_distance(p: IntPoint, a: IntPoint, b: IntPoint) -> f64 {
+    let (dx, dy) = (b.x as f64 - a.x as f64, b.y as f64 - a.y as f64);
+    let (vx, vy) = (p.x as f64 - a.x as f64, p.y as f64 - a.y as f64);
+    let sqr_len = dx * dx + dy * dy;
+    if sqr_len == 0.0 {
+        return vx * vx + vy * vy;
+    }
+    let t = ((vx * dx + vy * dy) / sqr_len).clamp(0.0, 1.0);
+    let (ex, ey) = (vx - t * dx, vy - t * dy);
+    ex * ex + ey * ey
+}
+
+#[inline]
+pub(super) fn triangle_area(a: IntPoint, b: IntPoint, c: IntPoint) -> f64 {
+    let cross = (b.x as i128 - a.x as i128) * (c.y as i128 - a.y as i128)
+        - (b.y as i128 - a.y as i128) * (c.x as i128 - a.x as i128);
+    0.5 * cross.unsigned_abs() as f64
+}
+
+fn bounds(contour: &[IntPoint]) -> (IntPoint, IntPoint) {
+    let mut min = contour[0];
+    let mut max = min;
+    for p in contour.iter() {
+        min = IntPoint::new(min.x.min(p.x), min.y.min(p.y));
+        max = IntPoint::new(max.x.max(p.x), max.y.max(p.y));
+    }
+    (min, max)
+}
+
+/// Returns `true` if a vertex of the hole that is not on the outer boundary is inside the outer contour.
+pub(super) fn is_inside(hole: &[IntPoint], outer: &[IntPoint]) -> bool {
+    for &p in hole.iter() {
+        match winding(p, outer) {
+            Some(w) => return w != 0,
+            None => continue,
+        }
+    }
+    // the hole lies on the boundary
+    true
+}
+
+/// The winding number of the contour around the point, `None` if the point is on the contour.
+pub(super) fn winding(p: IntPoint, contour: &[IntPoint]) -> Option<i32> {
+    let n = contour.len();
+    let mut w = 0;
+    for i in 0..n {
+        let a = contour[i];
+        let b = contour[(i + 1) % n];
+        let cross = (b.x as i64 - a.x as i64) * (p.y as i64 - a.y as i64)
+            - (b.y as i64 - a.y as i64) * (p.x as i64 - a.x as i64);
+        let is_between = a.x.min(b.x) <= p.x && p.x <= a.x.max(b.x) && a.y.min(b.y) <= p.y && p.y <= a.y.max(b.y);
+        if cross == 0 && is_between {
+            return None;
+        }
+        if a.y <= p.y {
+            if b.y > p.y && cross > 0 {
+                w += 1;
+            }
+        } else if b.y <= p.y && cross < 0 {
+            w -= 1;
+        }
+    }
+    Some(w)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::generalize::{Generalize, Tolerance};
+    use crate::core::validation::Validate;
+
+    fn points(coords: &[[i32; 2]]) -> Vec<IntPoint> {
+        coords.iter().map(|&[x, y]| IntPoint::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_noise() {
+        let shape = vec![points(&[[0, 0], [0, 50], [1, 100], [50, 101], [100, 100], [100, 0], [50, -1]])];
+
+        for tolerance in [Tolerance::Distance(2), Tolerance::Area(100)] {
+            let result = shape.generalize(tolerance);
+            assert_eq!(result, vec![vec![points(&[[0, 0], [1, 100], [100, 100], [100, 0]])]]);
+        }
+    }
+
+    #[test]
+    fn test_collapsed() {
+        let outer = points(&[[0, 0], [0, 100], [100, 100], [100, 0]]);
+        let hole = points(&[[10, 10], [12, 10], [11, 11]]);
+        let shapes = [vec![outer.clone(), hole], vec![points(&[[200, 0], [201, 100], [202, 0]])]];
+        assert!(shapes.validate().is_valid());
+
+        for tolerance in [Tolerance::Distance(5), Tolerance::Area(150)] {
+            let result = shapes.generalize(tolerance);
+            assert_eq!(result, vec![vec![outer.clone()]]);
+        }
+    }
+
+    #[test]
+    fn test_hole() {
+        // the bump of the outer contour is removed but the new edge would cross the hole
+        let outer = points(&[[0, 0], [0, 100], [100, 100], [100, 0], [50, -6]]);
+        let hole = points(&[[30, -2], [70, -2], [70, 10], [30, 10]]);
+        let shapes = [vec![outer.clone(), hole.clone()]];
+        assert!(shapes.validate().is_valid());
+
+        let result = shapes.generalize(Tolerance::Distance(7));
+        assert_eq!(result, vec![vec![outer.clone(), hole]]);
+
+        let result = vec![outer].generalize(Tolerance::Distance(7));
+        assert_eq!(result[0][0].len(), 4);
+    }
+
+    #[test]
+    fn test_no_crossing() {
+        // the notch of the first shape is removed but the new edge would cross the second shape
+        let a = points(&[[0, 0], [0, 20], [50, 14], [100, 20], [100, 0]]);
+        let b = points(&[[45, 16], [45, 30], [55, 30], [55, 16]]);
+        let shapes = [vec![a.clone()], vec![b.clone()]];
+        assert!(shapes.validate().is_valid());
+
+        let result = shapes.generalize(Tolerance::Distance(7));
+        assert!(result.validate().is_valid());
+        assert_eq!(result, vec![vec![a.clone()], vec![b]]);
+
+        let result = [vec![a]].generalize(Tolerance::Distance(7));
+        assert_eq!(result[0][0].len(), 4);
+    }
+}
@@ -2,6 +2,8 @@
 //! subject and clip polygons after boolean operations. The graph helps in extracting final shapes
 //! based on the overlay rule applied.
 
+use alloc::vec;
+use alloc::vec::Vec;
 use i_float::int::point::IntPoint;
 use crate::core::solver::Solver;
 use crate::geom::end::End;
@@ -1,3 +1,5 @@
+use alloc::vec;
+use alloc::vec::Vec;
 use i_float::int::point::IntPoint;
 use crate::core::fill_rule::FillRule;
 use crate::core::filter::{ClipFilter, DifferenceFilter, StringClipInsideBoundaryExcludedFilter, StringClipInsideBoundaryIncludedFilter, FillerFilter, IntersectFilter, InverseDifferenceFilter, StringClipOutsideBoundaryExcludedFilter, StringClipOutsideBoundaryIncludedFilter, SubjectFilter, UnionFilter, XorFilter, InclusionFilterStrategy};
@@ -64,9 +66,14 @@ impl OverlayLinkBuilder {
         if segments.is_empty() { return vec![]; }
         let segments = segments.split_segments(solver);
         if segments.is_empty() { return vec![]; }
-        let fills = Self::fill_string(&segments, fill_rule, solver);
 
-        Self::build_all_links(&segments, &fills)
+        Self::build_string_all_split(&segments, fill_rule, solver)
+    }
+
+    /// Builds the links of segments that are already split.
+    pub(crate) fn build_string_all_split(segments: &[Segment<ShapeCountString>], fill_rule: FillRule, solver: Solver) -> Vec<OverlayLink> {
+        let fills = Self::fill_string(segments, fill_rule, solver);
+        Self::build_all_links(segments, &fills)
     }
 
     pub(crate) fn build_string_with_clip_rule(segments: Vec<Segment<ShapeCountString>>, fill_rule: FillRule, clip_rule: ClipRule, solver: Solver) -> Vec<OverlayLink> {
@@ -83,7 +90,7 @@ impl OverlayLinkBuilder {
         }
     }
 
-    fn fill_string(segments: &[Segment<ShapeCountString>], fill_rule: FillRule, solver: Solver) -> Vec<SegmentFill> {
+    pub(crate) fn fill_string(segments: &[Segment<ShapeCountString>], fill_rule: FillRule, solver: Solver) -> Vec<SegmentFill> {
         let is_list = solver.is_list_fill(segments);
         match fill_rule {
             FillRule::EvenOdd => FillSolver::fill::<EvenOddStrategyString, ShapeCountString>(is_list, segments),
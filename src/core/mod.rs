@@ -5,6 +5,18 @@ pub mod overlay_rule;
 pub mod extract;
 pub mod fill_rule;
 pub mod simplify;
+pub mod generalize;
+pub mod coverage;
+pub mod cleanup;
+pub mod partition;
+pub mod coverage_check;
+pub mod topology;
+pub mod dissolve;
+pub mod csg;
+pub mod canonical;
+pub mod validation;
+pub mod noding;
+pub mod svg;
 pub(crate) mod link;
 pub(crate) mod node;
 pub(crate) mod filter;
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 #[derive(Debug)]
 pub(crate) enum OverlayNode {
     Bridge([usize; 2]),
@@ -0,0 +1,252 @@
+//! This module exposes the split phase of the overlay as a noder for linework.
+//!
+//! `Noder` collects segments and splits them at every intersection, so the result has no crossings
+//! and no overlaps: two segments either share an end point or do not touch at all.
+//! The split uses the same solvers as the overlay (`List`, `Tree` and `Frag`), the strategy is
+//! chosen by `Solver` in the same way. Intersection points are rounded to the integer grid.
+
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use crate::core::overlay::ShapeType;
+use crate::core::solver::Solver;
+use crate::segm::segment::Segment;
+use crate::segm::winding_count::WindingCount;
+use crate::split::solver::SplitSegments;
+
+/// The result of noding.
+/// - `segments`: The split segments, sorted, every segment is stored with `a < b`. Equal segments are merged.
+/// - `intersections`: Sorted points where at least one input segment was split, i.e. points that lie
+///   inside an input segment and on another one.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodedSegments<P = IntPoint> {
+    pub segments: Vec<[P; 2]>,
+    pub intersections: Vec<P>,
+}
+
+/// Collects segments to split them at their intersections.
+#[derive(Clone)]
+pub struct Noder {
+    segments: Vec<Segment<Origin>>,
+}
+
+impl Noder {
+    /// Constructs a new `Noder`.
+    /// - `capacity`: The expected number of segments.
+    pub fn new(capacity: usize) -> Self {
+        Self { segments: Vec::with_capacity(capacity) }
+    }
+
+    /// Creates a new `Noder` from separate segments.
+    pub fn with_segments(segments: &[[IntPoint; 2]]) -> Self {
+        let mut noder = Self::new(segments.len());
+        for &[a, b] in segments.iter() {
+            noder.add_segment(a, b);
+        }
+        noder
+    }
+
+    /// Creates a new `Noder` from paths.
+    /// - `paths`: The paths, every pair of consecutive points is a segment.
+    /// - `is_closed`: If `true`, the last point of every path is also connected to the first one.
+    pub fn with_paths(paths: &[Vec<IntPoint>], is_closed: bool) -> Self {
+        let capacity = paths.iter().map(|path| path.len()).sum();
+        let mut noder = Self::new(capacity);
+        for path in paths.iter() {
+            noder.add_path(path, is_closed);
+        }
+        noder
+    }
+
+    /// Adds a segment, a zero length segment is ignored.
+    #[inline]
+    pub fn add_segment(&mut self, a: IntPoint, b: IntPoint) {
+        if a != b {
+            let origin = Origin::new(a, b);
+            self.segments.push(Segment::create_and_validate(a, b, origin));
+        }
+    }
+
+    /// Adds every pair of consecutive points of the path as a segment.
+    /// - `is_closed`: If `true`, the last point is also connected to the first one.
+    pub fn add_path(&mut self, path: &[IntPoint], is_closed: bool) {
+        for pair in path.windows(2) {
+            self.add_segment(pair[0], pair[1]);
+        }
+        if is_closed && path.len() > 2 {
+            self.add_segment(path[path.len() - 1], path[0]);
+        }
+    }
+
+    /// Splits the segments with the default solver.
+    #[inline]
+    pub fn node(self) -> NodedSegments {
+        self.node_with_solver(Default::default())
+    }
+
+    /// Splits the segments.
+    /// - `solver`: Type of solver to use.
+    pub fn node_with_solver(self, solver: Solver) -> NodedSegments {
+        if self.segments.is_empty() {
+            return NodedSegments { segments: Vec::new(), intersections: Vec::new() };
+        }
+
+        let split = self.segments.split_segments(solver);
+
+        let mut intersections = Vec::new();
+        let mut segments = Vec::with_capacity(split.len());
+        for s in split.iter() {
+            let (a, b) = (s.x_segment.a, s.x_segment.b);
+            if s.count.is_inner(a) {
+                intersections.push(a);
+            }
+            if s.count.is_inner(b) {
+                intersections.push(b);
+            }
+            segments.push([a, b]);
+        }
+
+        intersections.sort_unstable();
+        intersections.dedup();
+
+        NodedSegments { segments, intersections }
+    }
+}
+
+/// The extent of the input segments a split segment comes from.
+///
+/// A split part inherits the extent of its segment. Equal parts of collinear segments are merged
+/// and their extents are united, so an end of a part is inside some input segment if and only if
+/// it is inside the united extent.
+#[derive(Debug, Clone, Copy)]
+struct Origin {
+    a: IntPoint,
+    b: IntPoint,
+}
+
+impl Origin {
+    #[inline(always)]
+    fn new(a: IntPoint, b: IntPoint) -> Self {
+        if a < b { Self { a, b } } else { Self { a: b, b: a } }
+    }
+
+    #[inline(always)]
+    fn is_inner(&self, p: IntPoint) -> bool {
+        p != self.a && p != self.b
+    }
+}
+
+impl WindingCount for Origin {
+    #[inline(always)]
+    fn is_not_empty(&self) -> bool {
+        // segments are never removed
+        true
+    }
+
+    #[inline(always)]
+    fn new(_subj: i32, _clip: i32) -> Self {
+        Self { a: IntPoint::ZERO, b: IntPoint::ZERO }
+    }
+
+    #[inline(always)]
+    fn with_shape_type(_shape_type: ShapeType) -> (Self, Self) {
+        let empty = <Self as WindingCount>::new(0, 0);
+        (empty, empty)
+    }
+
+    #[inline(always)]
+    fn add(self, count: Self) -> Self {
+        Self { a: self.a.min(count.a), b: self.b.max(count.b) }
+    }
+
+    #[inline(always)]
+    fn apply(&mut self, count: Self) {
+        *self = self.add(count);
+    }
+
+    #[inline(always)]
+    fn invert(self) -> Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::noding::Noder;
+    use crate::core::solver::Solver;
+
+    #[test]
+    fn test_cross() {
+        let segments = [
+            [IntPoint::new(0, 0), IntPoint::new(10, 10)],
+            [IntPoint::new(0, 10), IntPoint::new(10, 0)],
+        ];
+
+        for solver in [Solver::LIST, Solver::TREE, Solver::FRAG] {
+            let result = Noder::with_segments(&segments).node_with_solver(solver);
+            assert_eq!(result.intersections, vec![IntPoint::new(5, 5)]);
+            assert_eq!(result.segments, vec![
+                [IntPoint::new(0, 0), IntPoint::new(5, 5)],
+                [IntPoint::new(0, 10), IntPoint::new(5, 5)],
+                [IntPoint::new(5, 5), IntPoint::new(10, 0)],
+                [IntPoint::new(5, 5), IntPoint::new(10, 10)],
+            ]);
+        }
+    }
+
+    #[test]
+    fn test_touch() {
+        // the end of the second segment lies inside the first one
+        let segments = [
+            [IntPoint::new(0, 0), IntPoint::new(10, 0)],
+            [IntPoint::new(5, 0), IntPoint::new(5, 10)],
+        ];
+
+        let result = Noder::with_segments(&segments).node();
+        assert_eq!(result.intersections, vec![IntPoint::new(5, 0)]);
+        assert_eq!(result.segments.len(), 3);
+    }
+
+    #[test]
+    fn test_shared_end() {
+        // a path is not split at its own vertices
+        let path = vec![IntPoint::new(0, 0), IntPoint::new(10, 0), IntPoint::new(20, 0), IntPoint::new(20, 10)];
+
+        let result = Noder::with_paths(&[path], false).node();
+        assert!(result.intersections.is_empty());
+        assert_eq!(result.segments.len(), 3);
+    }
+
+    #[test]
+    fn test_overlap() {
+        let segments = [
+            [IntPoint::new(0, 0), IntPoint::new(10, 0)],
+            [IntPoint::new(0, 0), IntPoint::new(5, 0)],
+            [IntPoint::new(5, 0), IntPoint::new(10, 0)],
+            [IntPoint::new(10, 0), IntPoint::new(0, 0)],
+        ];
+
+        let result = Noder::with_segments(&segments).node();
+        assert_eq!(result.intersections, vec![IntPoint::new(5, 0)]);
+        assert_eq!(result.segments, vec![
+            [IntPoint::new(0, 0), IntPoint::new(5, 0)],
+            [IntPoint::new(5, 0), IntPoint::new(10, 0)],
+        ]);
+    }
+
+    #[test]
+    fn test_closed() {
+        let square = vec![IntPoint::new(0, 0), IntPoint::new(0, 10), IntPoint::new(10, 10), IntPoint::new(10, 0)];
+        let line = vec![IntPoint::new(-5, 5), IntPoint::new(15, 5)];
+
+        let mut noder = Noder::new(6);
+        noder.add_path(&square, true);
+        noder.add_path(&line, false);
+        let result = noder.node();
+
+        assert_eq!(result.intersections, vec![IntPoint::new(0, 5), IntPoint::new(10, 5)]);
+        assert_eq!(result.segments.len(), 9);
+    }
+}
@@ -0,0 +1,542 @@
+//! This module builds the full planar partition of several groups of contours.
+//!
+//! All edges are split once by the split phase of the overlay. Every split piece gets the winding
+//! delta of every group whose edges run along it, and one sweep gives the winding numbers of all
+//! groups on both sides of every piece. The fill rule turns the windings into a membership set:
+//! the groups that cover the region. The faces of every membership set are extracted from the
+//! pieces that separate it from a different one, so a single split replaces a chain of boolean
+//! operations such as Intersect, Difference and InverseDifference.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use i_shape::base::data::Shape;
+use i_shape::int::shape::{IntContour, IntShape, IntShapes};
+use crate::core::fill_rule::FillRule;
+use crate::core::graph::OverlayGraph;
+use crate::core::link::OverlayLink;
+use crate::core::overlay::ShapeType;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::fill::solver::FillSolver;
+use crate::geom::id_point::IdPoint;
+use crate::geom::x_segment::XSegment;
+use crate::segm::build::BuildSegments;
+use crate::segm::segment::{Segment, CLIP_BOTTOM, CLIP_TOP, SUBJ_BOTTOM, SUBJ_TOP};
+use crate::segm::winding_count::WindingCount;
+use crate::split::solver::SplitSegments;
+
+/// A face of the partition.
+/// - `shape`: The face, the first contour is the outer boundary and the rest are holes.
+/// - `membership`: The sorted ids of the groups that cover the face.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Face<P = IntPoint> {
+    pub shape: Shape<P>,
+    pub membership: Vec<usize>,
+}
+
+/// Collects contours labeled with a group id to split the plane into faces.
+///
+/// A group can be anything: a single contour, a shape, a layer or the subject and the clip of an overlay.
+/// Outer contours are expected clockwise and holes counterclockwise, it matters only for
+/// `FillRule::Positive` and `FillRule::Negative`.
+#[derive(Clone)]
+pub struct Partition {
+    segments: Vec<Segment<Delta>>,
+}
+
+impl Partition {
+    /// Constructs a new `Partition`.
+    /// - `capacity`: The expected number of edges.
+    pub fn new(capacity: usize) -> Self {
+        Self { segments: Vec::with_capacity(capacity) }
+    }
+
+    /// Adds a closed path.
+    /// - `contour`: An array of points that form a closed path.
+    /// - `group`: The id of the group the contour belongs to.
+    pub fn add_contour(&mut self, contour: &[IntPoint], group: usize) {
+        // the same degenerate points and contours are dropped as in the overlay
+        let start = self.segments.len();
+        self.segments.append_path_iter(contour.iter().copied(), ShapeType::Subject);
+        for segment in self.segments[start..].iter_mut() {
+            segment.count.group = group;
+        }
+    }
+
+    /// Adds multiple closed paths to the same group.
+    /// - `contours`: An array of `IntContour` instances.
+    /// - `group`: The id of the group the contours belong to.
+    #[inline]
+    pub fn add_contours(&mut self, contours: &[IntContour], group: usize) {
+        for contour in contours.iter() {
+            self.add_contour(contour, group);
+        }
+    }
+
+    /// Adds a shape.
+    /// - `shape`: A reference to a `IntShape` instance.
+    /// - `group`: The id of the group the shape belongs to.
+    #[inline]
+    pub fn add_shape(&mut self, shape: &IntShape, group: usize) {
+        self.add_contours(shape, group);
+    }
+
+    /// Adds multiple shapes to the same group.
+    /// - `shapes`: An array of `IntShape` instances.
+    /// - `group`: The id of the group the shapes belong to.
+    #[inline]
+    pub fn add_shapes(&mut self, shapes: &[IntShape], group: usize) {
+        for shape in shapes.iter() {
+            self.add_contours(shape, group);
+        }
+    }
+
+    /// Splits the plane into faces with the default solver.
+    /// - `fill_rule`: The fill rule applied to the winding number of every group separately.
+    /// # Returns
+    /// Every face covered by at least one group, sorted by membership. Two faces with the same membership
+    /// never share an edge. The outer contours are clockwise and the holes are counterclockwise.
+    #[inline]
+    pub fn into_faces(self, fill_rule: FillRule) -> Vec<Face> {
+        self.into_faces_with_solver(fill_rule, Default::default())
+    }
+
+    /// Splits the plane into faces.
+    /// - `fill_rule`: The fill rule applied to the winding number of every group separately.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// Every face covered by at least one group, sorted by membership. Two faces with the same membership
+    /// never share an edge. The outer contours are clockwise and the holes are counterclockwise.
+    pub fn into_faces_with_solver(self, fill_rule: FillRule, solver: Solver) -> Vec<Face> {
+        let arrangement = self.into_arrangement(solver);
+        let mut faces = Vec::new();
+        for (membership, shapes) in arrangement.extract(|winding| Arrangement::membership(winding, fill_rule)) {
+            for shape in shapes.into_iter() {
+                faces.push(Face { shape, membership: membership.clone() });
+            }
+        }
+        faces
+    }
+
+    pub(crate) fn into_arrangement(self, solver: Solver) -> Arrangement {
+        let segments = self.segments;
+        let pieces = if segments.is_empty() { segments } else { segments.split_segments(solver) };
+
+        let deltas = Deltas::new(&pieces);
+        let is_list = solver.is_list_fill(&deltas.segments);
+        let segments = deltas.segments.iter().map(|s| s.x_segment).collect::<Vec<_>>();
+
+        let mut windings: Vec<Vec<(usize, i32)>> = vec![Vec::new()];
+        let mut ids: BTreeMap<Vec<(usize, i32)>, u32> = BTreeMap::new();
+        ids.insert(Vec::new(), 0);
+
+        let sides = FillSolver::scan(is_list, &segments, |index, bottom| {
+            let winding = add_winding(&windings[bottom as usize], deltas.get(index));
+            *ids.entry(winding).or_insert_with_key(|winding| {
+                windings.push(winding.clone());
+                (windings.len() - 1) as u32
+            })
+        });
+
+        Arrangement { segments, sides, windings, solver }
+    }
+}
+
+/// The split pieces of all edges with the windings of the regions on both sides.
+pub(crate) struct Arrangement {
+    /// The split pieces, sorted, every piece is stored with `a < b`.
+    pub(crate) segments: Vec<XSegment>,
+    /// The region ids below and above every piece, for a vertical piece: on its right and on its left.
+    pub(crate) sides: Vec<[u32; 2]>,
+    /// The sparse winding numbers `(group, winding)` of every region, sorted by group. The region `0` is outside of everything.
+    pub(crate) windings: Vec<Vec<(usize, i32)>>,
+    pub(crate) solver: Solver,
+}
+
+impl Arrangement {
+    /// Returns the sorted groups that cover a region with the given windings.
+    pub(crate) fn membership(winding: &[(usize, i32)], fill_rule: FillRule) -> Option<Vec<usize>> {
+        let groups: Vec<usize> = winding.iter()
+            .filter(|&&(_, w)| is_filled(w, fill_rule))
+            .map(|&(group, _)| group)
+            .collect();
+
+        if groups.is_empty() { None } else { Some(groups) }
+    }
+
+    /// Extracts the shapes of the regions sorted by their class.
+    /// - `classify`: Returns the class of a region from its windings, `None` if the region is not filled.
+    ///
+    /// Adjacent regions of the same class are merged into one shape.
+    pub(crate) fn extract<K: Ord, F: FnMut(&[(usize, i32)]) -> Option<K>>(&self, mut classify: F) -> Vec<(K, IntShapes)> {
+        let mut keys: BTreeMap<K, usize> = BTreeMap::new();
+        let classes: Vec<Option<usize>> = self.windings.iter()
+            .map(|winding| classify(winding).map(|key| {
+                let next = keys.len();
+                *keys.entry(key).or_insert(next)
+            }))
+            .collect();
+
+        let mut links: Vec<Vec<OverlayLink>> = vec![Vec::new(); keys.len()];
+        for (s, &[bottom, top]) in self.segments.iter().zip(self.sides.iter()) {
+            let bottom = classes[bottom as usize];
+            let top = classes[top as usize];
+            if bottom == top {
+                continue;
+            }
+            let (a, b) = (IdPoint::new(0, s.a), IdPoint::new(0, s.b));
+            if let Some(class) = top {
+                links[class].push(OverlayLink::new(a, b, SUBJ_TOP));
+            }
+            if let Some(class) = bottom {
+                links[class].push(OverlayLink::new(a, b, SUBJ_BOTTOM));
+            }
+        }
+
+        keys.into_iter()
+            .filter_map(|(key, class)| {
+                let links = core::mem::take(&mut links[class]);
+                if links.is_empty() {
+                    return None;
+                }
+                let shapes = OverlayGraph::new(self.solver, links).extract_shapes(OverlayRule::Subject);
+                Some((key, shapes))
+            })
+            .collect()
+    }
+
+    /// Extracts the shapes of every group separately, the shapes of different groups can overlap.
+    /// - `fill_rule`: The fill rule applied to the winding number of every group separately.
+    /// # Returns
+    /// The shapes of every group that covers something, sorted by group.
+    pub(crate) fn extract_groups(&self, fill_rule: FillRule) -> Vec<(usize, IntShapes)> {
+        let memberships: Vec<Vec<usize>> = self.windings.iter()
+            .map(|winding| Self::membership(winding, fill_rule).unwrap_or_default())
+            .collect();
+
+        let mut links: BTreeMap<usize, Vec<OverlayLink>> = BTreeMap::new();
+        for (s, &[bottom, top]) in self.segments.iter().zip(self.sides.iter()) {
+            let bottom = &memberships[bottom as usize];
+            let top = &memberships[top as usize];
+            if bottom == top {
+                continue;
+            }
+            let (a, b) = (IdPoint::new(0, s.a), IdPoint::new(0, s.b));
+            for &group in top.iter().filter(|group| bottom.binary_search(group).is_err()) {
+                links.entry(group).or_default().push(OverlayLink::new(a, b, SUBJ_TOP));
+            }
+            for &group in bottom.iter().filter(|group| top.binary_search(group).is_err()) {
+                links.entry(group).or_default().push(OverlayLink::new(a, b, SUBJ_BOTTOM));
+            }
+        }
+
+        links.into_iter()
+            .map(|(group, links)| (group, OverlayGraph::new(self.solver, links).extract_shapes(OverlayRule::Subject)))
+            .collect()
+    }
+}
+
+impl OverlayGraph {
+    /// Extracts every face covered by the subject or the clip, labeled with its membership:
+    /// `[0]` is covered only by the subject, `[1]` only by the clip and `[0, 1]` by both.
+    ///
+    /// The links are classified in a single pass, every link bounds the faces on both of its sides,
+    /// so this replaces the separate `Difference`, `Intersect` and `InverseDifference` extractions.
+    /// # Returns
+    /// The faces sorted by membership. The outer contours are clockwise and the holes are counterclockwise.
+    pub fn extract_faces(&self) -> Vec<Face> {
+        // the class of a region is `subj | clip << 1`: 1 is only the subject, 3 is both and 2 is only the clip
+        const CLASSES: [(usize, OverlayRule); 3] = [
+            (1, OverlayRule::Difference),
+            (3, OverlayRule::Intersect),
+            (2, OverlayRule::InverseDifference),
+        ];
+
+        // a link is a part of the faces on its both sides, a skipped link is marked as visited
+        let mut filters = vec![vec![true; self.links.len()]; 4];
+        for (index, link) in self.links.iter().enumerate() {
+            let top = (link.fill & SUBJ_TOP != 0) as usize | ((link.fill & CLIP_TOP != 0) as usize) << 1;
+            let bottom = (link.fill & SUBJ_BOTTOM != 0) as usize | ((link.fill & CLIP_BOTTOM != 0) as usize) << 1;
+            if top != bottom {
+                filters[top][index] = false;
+                filters[bottom][index] = false;
+            }
+        }
+
+        let mut faces = Vec::new();
+        for (class, overlay_rule) in CLASSES.into_iter() {
+            let membership: Vec<usize> = (0..2).filter(|bit| class & (1 << bit) != 0).collect();
+            let filter = core::mem::take(&mut filters[class]);
+            for shape in self.extract(filter, overlay_rule, 0).into_iter() {
+                faces.push(Face { shape, membership: membership.clone() });
+            }
+        }
+        faces
+    }
+}
+
+#[inline]
+fn is_filled(winding: i32, fill_rule: FillRule) -> bool {
+    match fill_rule {
+        FillRule::EvenOdd => winding & 1 == 1,
+        FillRule::NonZero => winding != 0,
+        FillRule::Positive => winding > 0,
+        FillRule::Negative => winding < 0,
+    }
+}
+
+fn add_winding(winding: &[(usize, i32)], delta: &[(usize, i32)]) -> Vec<(usize, i32)> {
+    let mut result = Vec::with_capacity(winding.len() + delta.len());
+    let (mut i, mut j) = (0, 0);
+    while i < winding.len() || j < delta.len() {
+        let item = if j == delta.len() || i < winding.len() && winding[i].0 < delta[j].0 {
+            i += 1;
+            winding[i - 1]
+        } else if i == winding.len() || delta[j].0 < winding[i].0 {
+            j += 1;
+            delta[j - 1]
+        } else {
+            i += 1;
+            j += 1;
+            (winding[i - 1].0, winding[i - 1].1 + delta[j - 1].1)
+        };
+        if item.1 != 0 {
+            result.push(item);
+        }
+    }
+    result
+}
+
+/// The winding deltas of the groups for every split piece.
+///
+/// Crossing a piece from below to above changes the winding of a group by its delta: `-1` for
+/// every edge of the group that runs along the piece from `a` to `b` and `+1` for every edge in the
+/// opposite direction. So the inside of a clockwise contour has the winding `+1`.
+struct Deltas {
+    /// The distinct pieces with a non-zero delta, sorted.
+    segments: Vec<Segment<Delta>>,
+    offsets: Vec<usize>,
+    values: Vec<(usize, i32)>,
+}
+
+impl Deltas {
+    /// Sums the deltas of the equal pieces, the split keeps them apart.
+    fn new(pieces: &[Segment<Delta>]) -> Self {
+        let mut segments = Vec::with_capacity(pieces.len());
+        let mut offsets = Vec::with_capacity(pieces.len() + 1);
+        offsets.push(0);
+        let mut values: Vec<(usize, i32)> = Vec::with_capacity(pieces.len());
+        let mut buffer: Vec<(usize, i32)> = Vec::new();
+
+        let mut i = 0;
+        while i < pieces.len() {
+            let s = pieces[i];
+            while i < pieces.len() && pieces[i].x_segment == s.x_segment {
+                buffer.push((pieces[i].count.group, pieces[i].count.value));
+                i += 1;
+            }
+            buffer.sort_unstable_by_key(|&(group, _)| group);
+            buffer.dedup_by(|next, prev| next.0 == prev.0 && {
+                prev.1 += next.1;
+                true
+            });
+
+            let len = values.len();
+            values.extend(buffer.drain(..).filter(|&(_, value)| value != 0));
+            if values.len() > len {
+                segments.push(s);
+                offsets.push(values.len());
+            }
+        }
+
+        Self { segments, offsets, values }
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> &[(usize, i32)] {
+        &self.values[self.offsets[index]..self.offsets[index + 1]]
+    }
+}
+
+/// The winding delta of the group of a single input edge.
+///
+/// The split keeps equal pieces of different edges apart, so every piece carries the delta of
+/// its own edge and the deltas are summed exactly by [`Deltas`].
+#[derive(Debug, Clone, Copy)]
+struct Delta {
+    group: usize,
+    value: i32,
+}
+
+impl WindingCount for Delta {
+    const IS_MERGEABLE: bool = false;
+
+    #[inline(always)]
+    fn is_not_empty(&self) -> bool {
+        self.value != 0
+    }
+
+    #[inline(always)]
+    fn new(_subj: i32, _clip: i32) -> Self {
+        Self { group: 0, value: 0 }
+    }
+
+    #[inline(always)]
+    fn with_shape_type(_shape_type: ShapeType) -> (Self, Self) {
+        // the group is set by the partition after the contour is added
+        (Self { group: 0, value: -1 }, Self { group: 0, value: 1 })
+    }
+
+    fn is_empty_sum(counts: &[Self]) -> bool {
+        let mut values: Vec<(usize, i32)> = counts.iter().map(|count| (count.group, count.value)).collect();
+        values.sort_unstable_by_key(|&(group, _)| group);
+        values.chunk_by(|a, b| a.0 == b.0).all(|chunk| chunk.iter().map(|&(_, value)| value).sum::<i32>() == 0)
+    }
+
+    #[inline(always)]
+    fn add(self, count: Self) -> Self {
+        debug_assert!(false, "equal pieces are never merged");
+        Self { group: self.group, value: self.value + count.value }
+    }
+
+    #[inline(always)]
+    fn apply(&mut self, count: Self) {
+        *self = self.add(count);
+    }
+
+    #[inline(always)]
+    fn invert(self) -> Self {
+        Self { group: self.group, value: -self.value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::fill_rule::FillRule;
+    use crate::core::overlay::{Overlay, ShapeType};
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::core::partition::Partition;
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + size),
+            IntPoint::new(x + size, y + size),
+            IntPoint::new(x + size, y),
+        ]
+    }
+
+    #[test]
+    fn test_two_squares() {
+        let subj = square(0, 0, 10);
+        let clip = square(5, 5, 10);
+
+        let mut partition = Partition::new(8);
+        partition.add_contour(&subj, 0);
+        partition.add_contour(&clip, 1);
+        let faces = partition.into_faces(FillRule::NonZero);
+
+        let membership: Vec<Vec<usize>> = faces.iter().map(|face| face.membership.clone()).collect();
+        assert_eq!(membership, vec![vec![0], vec![0, 1], vec![1]]);
+        assert_eq!(faces[1].shape, vec![square(5, 5, 5)]);
+
+        let graph = Overlay::with_contour(&subj, &clip).into_graph(FillRule::NonZero);
+        assert_eq!(graph.extract_faces(), faces);
+    }
+
+    #[test]
+    fn test_many_groups() {
+        let mut partition = Partition::new(16);
+        partition.add_contour(&square(0, 0, 10), 0);
+        partition.add_contour(&square(10, 0, 10), 1);
+        partition.add_contour(&square(5, 0, 10), 2);
+        partition.add_contour(&square(0, 0, 20), 3);
+        let faces = partition.into_faces(FillRule::NonZero);
+
+        let membership: Vec<Vec<usize>> = faces.iter().map(|face| face.membership.clone()).collect();
+        assert_eq!(membership, vec![vec![0, 2, 3], vec![0, 3], vec![1, 2, 3], vec![1, 3], vec![3]]);
+        assert_eq!(faces[4].shape.len(), 1);
+    }
+
+    #[test]
+    fn test_hole() {
+        let mut hole = square(5, 5, 10);
+        hole.reverse();
+
+        let mut partition = Partition::new(8);
+        partition.add_contours(&[square(0, 0, 20), hole], 0);
+        partition.add_contour(&square(10, 10, 20), 1);
+        let faces = partition.into_faces(FillRule::NonZero);
+
+        let subj = vec![vec![square(0, 0, 20), square(5, 5, 10).into_iter().rev().collect()]];
+        let clip = vec![vec![square(10, 10, 20)]];
+        let graph = Overlay::with_shapes(&subj, &clip).into_graph(FillRule::NonZero);
+        assert_eq!(graph.extract_faces(), faces);
+    }
+
+    #[test]
+    fn test_orientation() {
+        let clockwise = square(0, 0, 10);
+        let mut counter_clockwise = square(20, 0, 10);
+        counter_clockwise.reverse();
+
+        for fill_rule in [FillRule::Positive, FillRule::Negative] {
+            let mut partition = Partition::new(8);
+            partition.add_contour(&clockwise, 0);
+            partition.add_contour(&counter_clockwise, 0);
+            let faces = partition.into_faces(fill_rule);
+
+            let mut overlay = Overlay::new(8);
+            overlay.add_contour(&clockwise, ShapeType::Subject);
+            overlay.add_contour(&counter_clockwise, ShapeType::Subject);
+            let shapes = overlay.overlay(OverlayRule::Subject, fill_rule);
+
+            assert_eq!(faces.len(), 1);
+            assert_eq!(faces[0].shape, shapes[0]);
+        }
+    }
+
+    fn path(points: &[[i32; 2]]) -> Vec<IntPoint> {
+        points.iter().map(|p| IntPoint::new(p[0], p[1])).collect()
+    }
+
+    fn check_with_overlay(a: &[IntPoint], b: &[IntPoint]) {
+        let mut partition = Partition::new(8);
+        partition.add_contour(a, 0);
+        partition.add_contour(b, 1);
+        let faces = partition.into_faces(FillRule::NonZero);
+
+        let graph = Overlay::with_contour(a, b).into_graph(FillRule::NonZero);
+        for (rule, membership) in [
+            (OverlayRule::Difference, vec![0]),
+            (OverlayRule::Intersect, vec![0, 1]),
+            (OverlayRule::InverseDifference, vec![1]),
+        ] {
+            let shapes: Vec<_> = faces.iter()
+                .filter(|face| face.membership == membership)
+                .map(|face| face.shape.clone())
+                .collect();
+            assert_eq!(shapes, graph.extract_shapes(rule));
+        }
+    }
+
+    #[test]
+    fn test_overlapping_edges() {
+        let a = path(&[[0, -2], [0, -3], [-1, 4]]);
+        let b = path(&[[1, 0], [-5, -1], [-1, -1]]);
+        check_with_overlay(&a, &b);
+    }
+
+    #[test]
+    fn test_degenerate_contour() {
+        let a = path(&[[4, -3], [-3, -3], [1, -3]]);
+        let b = path(&[[0, 2], [-2, -5], [-1, -4]]);
+        check_with_overlay(&a, &b);
+    }
+}
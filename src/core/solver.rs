@@ -10,6 +10,7 @@ use crate::segm::segment::Segment;
 /// - `Tree`: Implements a tree-based data structure (e.g., a binary search tree or a spatial partitioning tree) to manage geometric data. This method is generally more efficient for larger datasets or scenarios requiring complex spatial queries, as it can significantly reduce the number of comparisons needed for operations. However, its performance advantage becomes more apparent as the dataset size exceeds a certain threshold (roughly estimated at 10,000 edges).
 /// - `Auto`: Delegates the choice of solver to the system, which determines the most suitable approach based on the size and complexity of the dataset. This option is designed to dynamically select between `list` and `tree` strategies, aiming to optimize performance without requiring a priori knowledge of the data's characteristics. It's the recommended choice for users looking for a balance between performance and ease of use, as it adapts to the specific requirements of each operation.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Strategy {
     List,
     Tree,
@@ -17,14 +18,24 @@ pub enum Strategy {
     Auto,
 }
 
+/// Represents the way intersection points are rounded to the integer grid during the split phase.
+///
+/// Cases:
+/// - `Absolute`: Intersection points are rounded to the nearest grid point without any snapping to nearby ends.
+/// - `Average`: A rounded intersection point is snapped to a nearby segment end within a small fixed radius.
+/// - `Auto`: Same as `Average`, but the snap radius grows with every split iteration until the result is stable.
+/// - `SnapRounding`: Iterated snap rounding with hot pixels. Intersection points are snapped to nearby ends as in `Average`, then every vertex owns a unit pixel, and any segment passing through it is rerouted through the vertex. The result never has crossing segments. The process is capped at a fixed number of rounds, for degenerate input that hits the cap a vertex may stay closer than half a unit to a foreign segment. It is slower than the other modes and is intended for cases where provably valid output is required.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Precision {
     Absolute,
     Average,
     Auto,
+    SnapRounding,
 }
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MultithreadOptions {
     pub par_sort_min_size: usize,
 }
@@ -36,6 +47,8 @@ impl Default for MultithreadOptions {
 }
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Solver {
     pub strategy: Strategy,
     pub precision: Precision,
@@ -85,7 +98,7 @@ impl Solver {
     pub(crate) fn radius(&self, iteration: usize) -> i64 {
         match self.precision {
             Precision::Absolute => { 0 }
-            Precision::Average => { 2 }
+            Precision::Average | Precision::SnapRounding => { 2 }
             Precision::Auto => {
                 1 << iteration.min(10)
             }
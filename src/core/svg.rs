@@ -0,0 +1,335 @@
+//! This module provides a debug writer that renders overlay inputs, the `OverlayGraph` and the
+//! extracted shapes into a single self-contained SVG document.
+//!
+//! Layers are emitted as separate groups (`subject`, `clip`, `links`, `nodes`, `result`), so they can
+//! be toggled in any SVG viewer. Every link carries a `<title>` with its id and fill bits, which most
+//! viewers show as a tooltip. The y axis points up, as in the overlay coordinate system.
+//!
+//! Float overlays can be rendered the same way through `FloatOverlayGraph::graph`.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use i_float::int::point::IntPoint;
+use i_shape::int::path::IntPath;
+use i_shape::int::shape::IntShape;
+use crate::core::graph::OverlayGraph;
+use crate::core::overlay::{Overlay, ShapeType};
+use crate::segm::segment::{SegmentFill, CLIP_BOTH, CLIP_BOTTOM, CLIP_TOP, SUBJ_BOTH, SUBJ_BOTTOM, SUBJ_TOP};
+
+const SUBJ_COLOR: &str = "#e5484d";
+const CLIP_COLOR: &str = "#3e63dd";
+const BOTH_COLOR: &str = "#8e4ec6";
+const NONE_COLOR: &str = "#8b8d98";
+const RESULT_COLOR: &str = "#30a46c";
+
+struct Line {
+    a: IntPoint,
+    b: IntPoint,
+    color: &'static str,
+    title: String,
+}
+
+struct Node {
+    id: usize,
+    point: IntPoint,
+}
+
+/// Collects geometry layers and writes them as one SVG document.
+///
+/// ```rust
+/// use i_float::int::point::IntPoint;
+/// use i_overlay::core::fill_rule::FillRule;
+/// use i_overlay::core::overlay::Overlay;
+/// use i_overlay::core::overlay_rule::OverlayRule;
+/// use i_overlay::core::svg::SvgWriter;
+///
+/// let subj = [IntPoint::new(0, 0), IntPoint::new(0, 10), IntPoint::new(10, 10), IntPoint::new(10, 0)];
+/// let clip = [IntPoint::new(5, 5), IntPoint::new(5, 15), IntPoint::new(15, 15), IntPoint::new(15, 5)];
+///
+/// let overlay = Overlay::with_contour(&subj, &clip);
+/// let graph = overlay.clone().into_graph(FillRule::NonZero);
+/// let shapes = graph.extract_shapes(OverlayRule::Union);
+///
+/// let svg = SvgWriter::new()
+///     .add_overlay(&overlay)
+///     .add_graph(&graph)
+///     .add_shapes(&shapes)
+///     .to_svg();
+///
+/// assert!(svg.starts_with("<svg"));
+/// ```
+pub struct SvgWriter {
+    size: f64,
+    subject: Vec<Line>,
+    clip: Vec<Line>,
+    links: Vec<Line>,
+    nodes: Vec<Node>,
+    shapes: Vec<IntShape>,
+}
+
+impl SvgWriter {
+    /// Creates an empty writer. The longer side of the picture is 800 px.
+    pub fn new() -> Self {
+        Self::with_size(800.0)
+    }
+
+    /// Creates an empty writer.
+    /// - `size`: The length of the longer side of the picture in pixels.
+    pub fn with_size(size: f64) -> Self {
+        Self {
+            size,
+            subject: Vec::new(),
+            clip: Vec::new(),
+            links: Vec::new(),
+            nodes: Vec::new(),
+            shapes: Vec::new(),
+        }
+    }
+
+    /// Adds the subject and clip input edges of an overlay.
+    pub fn add_overlay(&mut self, overlay: &Overlay) -> &mut Self {
+        for s in overlay.segments.iter() {
+            let (a, b) = (s.x_segment.a, s.x_segment.b);
+            if s.count.subj != 0 {
+                self.subject.push(Line { a, b, color: SUBJ_COLOR, title: format!("subj {}", s.count.subj) });
+            }
+            if s.count.clip != 0 {
+                self.clip.push(Line { a, b, color: CLIP_COLOR, title: format!("clip {}", s.count.clip) });
+            }
+        }
+        self
+    }
+
+    /// Adds input paths.
+    /// - `paths`: Closed paths to draw.
+    /// - `shape_type`: Selects the layer and the color.
+    pub fn add_paths(&mut self, paths: &[IntPath], shape_type: ShapeType) -> &mut Self {
+        let (layer, color, name) = match shape_type {
+            ShapeType::Subject => (&mut self.subject, SUBJ_COLOR, "subj"),
+            ShapeType::Clip => (&mut self.clip, CLIP_COLOR, "clip"),
+        };
+
+        for (path_index, path) in paths.iter().enumerate() {
+            let mut a = match path.last() {
+                Some(&p) => p,
+                None => continue,
+            };
+            for &b in path.iter() {
+                layer.push(Line { a, b, color, title: format!("{} path {}", name, path_index) });
+                a = b;
+            }
+        }
+        self
+    }
+
+    /// Adds the split links of a graph, colored by their fill, and its nodes with ids.
+    ///
+    /// Colors: red is subject only, blue is clip only, purple is both, gray is none.
+    pub fn add_graph(&mut self, graph: &OverlayGraph) -> &mut Self {
+        let mut node_points = vec![None; graph.nodes.len()];
+        for (index, link) in graph.links.iter().enumerate() {
+            self.links.push(Line {
+                a: link.a.point,
+                b: link.b.point,
+                color: fill_color(link.fill),
+                title: format!("link {}: {} -> {}, fill {:04b} ({})", index, link.a.id, link.b.id, link.fill, fill_description(link.fill)),
+            });
+            node_points[link.a.id] = Some(link.a.point);
+            node_points[link.b.id] = Some(link.b.point);
+        }
+
+        for (id, point) in node_points.into_iter().enumerate() {
+            if let Some(point) = point {
+                self.nodes.push(Node { id, point });
+            }
+        }
+        self
+    }
+
+    /// Adds extracted shapes.
+    pub fn add_shapes(&mut self, shapes: &[IntShape]) -> &mut Self {
+        self.shapes.extend_from_slice(shapes);
+        self
+    }
+
+    /// Writes all added layers into an SVG document.
+    pub fn to_svg(&self) -> String {
+        let view = View::new(self, self.size);
+        let mut svg = String::new();
+
+        _ = writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="0 0 {:.0} {:.0}">"#, view.width, view.height, view.width, view.height);
+        _ = writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#);
+
+        Self::write_lines(&mut svg, &view, "subject", &self.subject, 3.0, 0.35);
+        Self::write_lines(&mut svg, &view, "clip", &self.clip, 3.0, 0.35);
+
+        if !self.shapes.is_empty() {
+            _ = writeln!(svg, r#"<g id="result" fill="{}" fill-opacity="0.25" fill-rule="evenodd" stroke="{}" stroke-width="1">"#, RESULT_COLOR, RESULT_COLOR);
+            for (index, shape) in self.shapes.iter().enumerate() {
+                let mut d = String::new();
+                for contour in shape.iter() {
+                    for (i, &p) in contour.iter().enumerate() {
+                        let (x, y) = view.map(p);
+                        _ = write!(d, "{}{:.2} {:.2} ", if i == 0 { 'M' } else { 'L' }, x, y);
+                    }
+                    d.push_str("Z ");
+                }
+                _ = writeln!(svg, r#"<path d="{}"><title>shape {}</title></path>"#, d.trim_end(), index);
+            }
+            _ = writeln!(svg, "</g>");
+        }
+
+        Self::write_lines(&mut svg, &view, "links", &self.links, 1.5, 1.0);
+
+        if !self.nodes.is_empty() {
+            _ = writeln!(svg, r#"<g id="nodes" font-family="monospace" font-size="10">"#);
+            for node in self.nodes.iter() {
+                let (x, y) = view.map(node.point);
+                _ = writeln!(svg, r#"<circle cx="{:.2}" cy="{:.2}" r="2.5" fill="black"><title>node {}: ({}, {})</title></circle>"#, x, y, node.id, node.point.x, node.point.y);
+                _ = writeln!(svg, r#"<text x="{:.2}" y="{:.2}">{}</text>"#, x + 4.0, y - 4.0, node.id);
+            }
+            _ = writeln!(svg, "</g>");
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn write_lines(svg: &mut String, view: &View, id: &str, lines: &[Line], width: f64, opacity: f64) {
+        if lines.is_empty() {
+            return;
+        }
+
+        _ = writeln!(svg, r#"<g id="{}" stroke-width="{}" stroke-opacity="{}" stroke-linecap="round">"#, id, width, opacity);
+        for line in lines.iter() {
+            let (x0, y0) = view.map(line.a);
+            let (x1, y1) = view.map(line.b);
+            _ = writeln!(svg, r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}"><title>{}</title></line>"#, x0, y0, x1, y1, line.color, line.title);
+        }
+        _ = writeln!(svg, "</g>");
+    }
+}
+
+impl Default for SvgWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct View {
+    min_x: f64,
+    max_y: f64,
+    scale: f64,
+    margin: f64,
+    width: f64,
+    height: f64,
+}
+
+impl View {
+    fn new(writer: &SvgWriter, size: f64) -> Self {
+        let lines = writer.subject.iter().chain(writer.clip.iter()).chain(writer.links.iter());
+        let points = lines.flat_map(|l| [l.a, l.b])
+            .chain(writer.shapes.iter().flatten().flatten().copied());
+
+        let mut min = IntPoint::new(i32::MAX, i32::MAX);
+        let mut max = IntPoint::new(i32::MIN, i32::MIN);
+        for p in points {
+            min = IntPoint::new(min.x.min(p.x), min.y.min(p.y));
+            max = IntPoint::new(max.x.max(p.x), max.y.max(p.y));
+        }
+
+        if min.x > max.x {
+            min = IntPoint::ZERO;
+            max = IntPoint::ZERO;
+        }
+
+        let dx = (max.x as f64 - min.x as f64).max(1.0);
+        let dy = (max.y as f64 - min.y as f64).max(1.0);
+        let margin = 20.0;
+        let scale = (size - 2.0 * margin) / dx.max(dy);
+
+        Self {
+            min_x: min.x as f64,
+            max_y: max.y as f64,
+            scale,
+            margin,
+            width: dx * scale + 2.0 * margin,
+            height: dy * scale + 2.0 * margin,
+        }
+    }
+
+    #[inline]
+    fn map(&self, p: IntPoint) -> (f64, f64) {
+        let x = (p.x as f64 - self.min_x) * self.scale + self.margin;
+        let y = (self.max_y - p.y as f64) * self.scale + self.margin;
+        (x, y)
+    }
+}
+
+#[inline]
+fn fill_color(fill: SegmentFill) -> &'static str {
+    match (fill & SUBJ_BOTH != 0, fill & CLIP_BOTH != 0) {
+        (true, true) => BOTH_COLOR,
+        (true, false) => SUBJ_COLOR,
+        (false, true) => CLIP_COLOR,
+        (false, false) => NONE_COLOR,
+    }
+}
+
+fn fill_description(fill: SegmentFill) -> String {
+    let names = [(SUBJ_TOP, "subj top"), (SUBJ_BOTTOM, "subj bottom"), (CLIP_TOP, "clip top"), (CLIP_BOTTOM, "clip bottom")];
+    let parts: Vec<&str> = names.iter().filter(|(bit, _)| fill & bit != 0).map(|(_, name)| *name).collect();
+    if parts.is_empty() {
+        "none".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::fill_rule::FillRule;
+    use crate::core::overlay::{Overlay, ShapeType};
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::core::svg::SvgWriter;
+
+    #[test]
+    fn test_layers() {
+        let subj = vec![IntPoint::new(0, 0), IntPoint::new(0, 10), IntPoint::new(10, 10), IntPoint::new(10, 0)];
+        let clip = vec![IntPoint::new(5, 5), IntPoint::new(5, 15), IntPoint::new(15, 15), IntPoint::new(15, 5)];
+
+        let overlay = Overlay::with_contour(&subj, &clip);
+        let graph = overlay.clone().into_graph(FillRule::NonZero);
+        let shapes = graph.extract_shapes(OverlayRule::Intersect);
+
+        let svg = SvgWriter::new()
+            .add_overlay(&overlay)
+            .add_graph(&graph)
+            .add_shapes(&shapes)
+            .to_svg();
+
+        for id in ["subject", "clip", "links", "nodes", "result"] {
+            assert!(svg.contains(&format!(r#"<g id="{}""#, id)));
+        }
+
+        assert_eq!(svg.matches("<circle").count(), graph.nodes.len());
+        assert_eq!(svg.matches("<title>link").count(), graph.links.len());
+        assert!(svg.contains("fill 0001 (subj top)"));
+        assert!(svg.contains("fill 1000 (clip bottom)"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_empty() {
+        let svg = SvgWriter::new()
+            .add_paths(&[], ShapeType::Subject)
+            .to_svg();
+        assert!(svg.starts_with("<svg"));
+    }
+}
@@ -0,0 +1,314 @@
+//! This module controls how the rings of extracted shapes may touch.
+//!
+//! The default extraction can return a hole that touches its outer contour at a vertex as a part
+//! of the outer contour, and an outer contour that passes twice through the same vertex. It is valid
+//! for the overlay, but OGC Simple Features consumers (PostGIS, JTS, GEOS) require simple rings and
+//! a connected interior for every polygon.
+//!
+//! `TopologyPolicy::OgcValid` traces the boundary again, turning at every vertex to the nearest edge
+//! counterclockwise, so every traced walk bounds a single connected part of the interior. Every walk
+//! is split at its repeated vertices into simple rings: a clockwise ring is an outer contour, a
+//! counterclockwise ring is a hole of the smallest outer contour around it.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use i_shape::int::path::{IntPath, PointPathExtension};
+use i_shape::int::shape::{IntShape, IntShapes};
+use crate::core::fill_rule::FillRule;
+use crate::core::generalize::is_inside;
+use crate::core::graph::OverlayGraph;
+use crate::core::overlay::Overlay;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::core::vector_rotation::NearestCCWVector;
+
+/// The policy for the rings of extracted shapes.
+/// - `MinimalRings`: The default extraction. A hole can touch its outer contour or be a part of it, and a contour can pass twice through the same vertex.
+/// - `OgcValid`: OGC Simple Features validity. Every ring is simple, rings touch each other only at single points,
+///   and the interior of every shape is connected, so touching holes are split off and shapes connected only at points are separated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TopologyPolicy {
+    #[default]
+    MinimalRings,
+    OgcValid,
+}
+
+impl OverlayGraph {
+    /// Extracts shapes from the overlay graph with the rings required by the policy.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph.
+    /// - `policy`: The policy for the rings.
+    /// # Returns
+    /// The shapes, outer contours are clockwise and holes are counterclockwise.
+    #[inline]
+    pub fn extract_shapes_with_policy(&self, overlay_rule: OverlayRule, policy: TopologyPolicy) -> IntShapes {
+        policy.apply(self.extract_shapes(overlay_rule))
+    }
+}
+
+impl Overlay {
+    /// Executes a single Boolean operation and returns the shapes with the rings required by the policy.
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - `policy`: The policy for the rings.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The shapes, outer contours are clockwise and holes are counterclockwise.
+    #[inline]
+    pub fn overlay_with_policy_and_solver(self, overlay_rule: OverlayRule, fill_rule: FillRule, policy: TopologyPolicy, solver: Solver) -> IntShapes {
+        policy.apply(self.overlay_with_min_area_and_solver(overlay_rule, fill_rule, 0, solver))
+    }
+}
+
+impl TopologyPolicy {
+    pub(crate) fn apply(self, shapes: IntShapes) -> IntShapes {
+        match self {
+            TopologyPolicy::MinimalRings => shapes,
+            TopologyPolicy::OgcValid => ogc_valid(&shapes),
+        }
+    }
+}
+
+fn ogc_valid(shapes: &[IntShape]) -> IntShapes {
+    let is_simple = shapes.iter().flatten().all(|contour| {
+        let mut points = contour.clone();
+        points.sort_unstable();
+        points.windows(2).all(|w| w[0] != w[1])
+    });
+
+    if is_simple && shapes.iter().all(|shape| shape.len() == 1) {
+        return shapes.to_vec();
+    }
+
+    let mut outers: Vec<(i64, IntPath)> = Vec::new();
+    let mut holes: Vec<(i64, IntPath)> = Vec::new();
+    for walk in walks(shapes) {
+        for ring in split_at_repeated(walk) {
+            let area = ring.unsafe_area();
+            if area > 0 {
+                outers.push((area, ring));
+            } else if area < 0 {
+                holes.push((-area, ring));
+            }
+        }
+    }
+
+    // the smallest outer contour around a hole is its shape
+    let mut order: Vec<usize> = (0..outers.len()).collect();
+    order.sort_by_key(|&i| outers[i].0);
+
+    let mut result: IntShapes = outers.iter().map(|(_, outer)| vec![outer.clone()]).collect();
+    for (hole_area, hole) in holes.into_iter() {
+        let owner = order.iter()
+            .copied()
+            .find(|&i| outers[i].0 > hole_area && is_inside(&hole, &outers[i].1));
+        if let Some(i) = owner {
+            result[i].push(hole);
+        }
+    }
+
+    result
+}
+
+/// Traces the contours of all shapes into closed walks. Every walk turns at a vertex to the
+/// nearest outgoing edge counterclockwise from the edge it came by, so it keeps the interior of a
+/// single connected part on its right.
+fn walks(shapes: &[IntShape]) -> Vec<IntPath> {
+    let mut edges: Vec<[IntPoint; 2]> = Vec::new();
+    for contour in shapes.iter().flatten() {
+        let n = contour.len();
+        for i in 0..n {
+            let (a, b) = (contour[i], contour[(i + 1) % n]);
+            if a != b {
+                edges.push([a, b]);
+            }
+        }
+    }
+    edges.sort_unstable();
+
+    let mut visited = vec![false; edges.len()];
+    let mut walks = Vec::new();
+    for start in 0..edges.len() {
+        if visited[start] {
+            continue;
+        }
+        let mut walk = Vec::new();
+        let mut index = start;
+        loop {
+            visited[index] = true;
+            let [a, b] = edges[index];
+            walk.push(a);
+
+            let first = edges.partition_point(|e| e[0] < b);
+            let last = edges.partition_point(|e| e[0] <= b);
+            let mut candidates = (first..last).filter(|&j| edges[j][1] != a);
+            let next = match candidates.next() {
+                Some(j) => {
+                    let mut nearest = NearestCCWVector::new(b, a, edges[j][1], j);
+                    for j in candidates {
+                        nearest.add(edges[j][1], j);
+                    }
+                    nearest.best_id
+                }
+                None if first < last => first,
+                None => break,
+            };
+
+            if next == start || visited[next] {
+                break;
+            }
+            index = next;
+        }
+        walks.push(walk);
+    }
+
+    walks
+}
+
+/// Splits a closed walk into rings that do not pass twice through the same vertex.
+fn split_at_repeated(walk: IntPath) -> Vec<IntPath> {
+    let mut rings = Vec::new();
+    let mut stack: IntPath = Vec::with_capacity(walk.len());
+    let mut positions: BTreeMap<IntPoint, usize> = BTreeMap::new();
+    for p in walk.into_iter() {
+        if let Some(&position) = positions.get(&p) {
+            // the walk came back to `p`, the part after it is a closed ring
+            let mut ring = stack.split_off(position + 1);
+            for q in ring.iter() {
+                positions.remove(q);
+            }
+            ring.insert(0, p);
+            rings.push(ring);
+        } else {
+            positions.insert(p, stack.len());
+            stack.push(p);
+        }
+    }
+    rings.push(stack);
+    rings.retain(|ring| ring.len() > 2);
+    rings
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use i_shape::int::path::{IntPath, PointPathExtension};
+    use i_shape::int::shape::IntShape;
+    use crate::core::fill_rule::FillRule;
+    use crate::core::overlay::Overlay;
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::core::topology::TopologyPolicy;
+
+    fn path(points: &[[i32; 2]]) -> IntPath {
+        points.iter().map(|p| IntPoint::new(p[0], p[1])).collect()
+    }
+
+    /// Every ring is simple and the rings of a shape touching at points do not enclose a part of the interior.
+    fn is_ogc_valid(shape: &IntShape) -> bool {
+        let mut parents: Vec<usize> = (0..shape.len()).collect();
+        fn root(parents: &mut [usize], i: usize) -> usize {
+            let mut i = i;
+            while parents[i] != i {
+                i = parents[i];
+            }
+            i
+        }
+
+        let mut touches: BTreeMap<IntPoint, usize> = BTreeMap::new();
+        for (i, ring) in shape.iter().enumerate() {
+            if (i == 0) != (ring.unsafe_area() > 0) {
+                return false;
+            }
+            let mut points = ring.clone();
+            points.sort_unstable();
+            if points.windows(2).any(|w| w[0] == w[1]) {
+                return false;
+            }
+            for p in points.into_iter() {
+                if let Some(&j) = touches.get(&p) {
+                    let (ri, rj) = (root(&mut parents, i), root(&mut parents, j));
+                    if ri == rj {
+                        return false;
+                    }
+                    parents[ri] = rj;
+                } else {
+                    touches.insert(p, i);
+                }
+            }
+        }
+        true
+    }
+
+    fn area(shapes: &[IntShape]) -> i64 {
+        shapes.iter().flatten().map(|contour| contour.unsafe_area()).sum()
+    }
+
+    fn check(subj: &[IntPath], clip: &[IntPath], rule: OverlayRule) -> Vec<IntShape> {
+        let overlay = Overlay::with_contours(subj, clip);
+        let minimal = overlay.clone().overlay_with_policy_and_solver(rule, FillRule::NonZero, TopologyPolicy::MinimalRings, Default::default());
+        let ogc = overlay.overlay_with_policy_and_solver(rule, FillRule::NonZero, TopologyPolicy::OgcValid, Default::default());
+
+        assert_eq!(minimal, Overlay::with_contours(subj, clip).overlay(rule, FillRule::NonZero));
+        assert_eq!(area(&minimal), area(&ogc));
+        assert!(ogc.iter().all(is_ogc_valid));
+        ogc
+    }
+
+    #[test]
+    fn test_hole_touching_outer() {
+        let square = path(&[[0, 0], [0, 10], [10, 10], [10, 0]]);
+        let triangle = path(&[[5, 10], [7, 5], [3, 5]]);
+
+        let result = check(&[square], &[triangle], OverlayRule::Difference);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].len(), 2);
+    }
+
+    #[test]
+    fn test_self_touching_outer() {
+        // the contour passes twice through (5, 0) around a triangle
+        let contour = path(&[[0, 0], [0, 10], [10, 10], [10, 0], [5, 0], [7, 3], [3, 3], [5, 0]]);
+
+        let result = check(&[contour], &[], OverlayRule::Subject);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].len(), 2);
+        assert_eq!(result[0][1].len(), 3);
+    }
+
+    #[test]
+    fn test_chain_of_holes() {
+        // two holes touch each other and the outer, they cut the square into two parts
+        let square = path(&[[0, 0], [0, 10], [10, 10], [10, 0]]);
+        let top = path(&[[5, 10], [6, 5], [4, 5]]);
+        let bottom = path(&[[5, 5], [6, 1], [5, 0], [4, 1]]);
+
+        let result = check(&[square], &[top, bottom], OverlayRule::Difference);
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|shape| shape.len() == 1));
+    }
+
+    #[test]
+    fn test_touching_squares() {
+        let a = path(&[[0, 0], [0, 10], [10, 10], [10, 0]]);
+        let b = path(&[[10, 10], [10, 20], [20, 20], [20, 10]]);
+
+        let result = check(&[a], &[b], OverlayRule::Union);
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn test_self_touching_hole() {
+        // two holes touching at a vertex stay two holes of the same shape
+        let square = path(&[[0, 0], [0, 20], [20, 20], [20, 0]]);
+        let left = path(&[[5, 5], [5, 10], [10, 10], [10, 5]]);
+        let right = path(&[[10, 10], [10, 15], [15, 15], [15, 10]]);
+
+        let result = check(&[square], &[left, right], OverlayRule::Difference);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].len(), 3);
+    }
+}
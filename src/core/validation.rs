@@ -0,0 +1,435 @@
+//! This module reports problems of input geometry instead of silently fixing them.
+//!
+//! The overlay accepts any contours: self-intersections are split, overlapping edges are merged
+//! and degenerate parts disappear. `Validate` runs the same broad phase and intersection tests as
+//! the split phase but keeps the contour and edge indices, so every problem is reported with its location.
+//!
+//! An edge `index` of a contour goes from `contour[index]` to `contour[(index + 1) % len]`.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use i_float::triangle::Triangle;
+use i_shape::int::path::PointPathExtension;
+use i_shape::int::shape::{IntContour, IntShape};
+use crate::geom::x_segment::XSegment;
+use crate::core::solver::Solver;
+use crate::segm::segment::Segment;
+use crate::split::contact::{Contact, FindContacts};
+
+/// Identifies a contour: `shape` is the index of the shape and `contour` is the index inside it,
+/// `0` is the outer contour. Independent contours are reported as shapes with a single contour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContourId {
+    pub shape: usize,
+    pub contour: usize,
+}
+
+/// Identifies an edge: the edge `index` of a contour goes from its point `index` to the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeId {
+    pub shape: usize,
+    pub contour: usize,
+    pub index: usize,
+}
+
+/// A problem found in the input geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Issue<P = IntPoint> {
+    /// The contour has fewer than three distinct points, it has no area.
+    TooFewPoints { contour: ContourId },
+    /// Two consecutive points of the contour are the same.
+    ZeroLengthEdge { point: P, edge: EdgeId },
+    /// The contour goes to `point` and returns back along the same line.
+    Spike { point: P, contour: ContourId, vertex: usize },
+    /// Two edges cross each other at a point inside both of them.
+    Crossing { point: P, edges: [EdgeId; 2] },
+    /// A vertex lies inside another edge, or a contour passes twice through the same vertex.
+    Touching { point: P, edges: [EdgeId; 2] },
+    /// Two edges have the same end points.
+    DuplicateEdge { segment: [P; 2], edges: [EdgeId; 2] },
+    /// Two collinear edges share a part of their length, `segment` is the shared part.
+    OverlappingEdges { segment: [P; 2], edges: [EdgeId; 2] },
+    /// A hole has the same orientation as the outer contour of its shape.
+    WrongOrientation { contour: ContourId },
+}
+
+/// The result of a validation, an empty list of issues means the geometry is valid.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationReport<P = IntPoint> {
+    pub issues: Vec<Issue<P>>,
+}
+
+impl<P> ValidationReport<P> {
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Issue {
+    pub(crate) fn map_points<P, F: Fn(IntPoint) -> P>(self, f: F) -> Issue<P> {
+        match self {
+            Issue::TooFewPoints { contour } => Issue::TooFewPoints { contour },
+            Issue::ZeroLengthEdge { point, edge } => Issue::ZeroLengthEdge { point: f(point), edge },
+            Issue::Spike { point, contour, vertex } => Issue::Spike { point: f(point), contour, vertex },
+            Issue::Crossing { point, edges } => Issue::Crossing { point: f(point), edges },
+            Issue::Touching { point, edges } => Issue::Touching { point: f(point), edges },
+            Issue::DuplicateEdge { segment, edges } => Issue::DuplicateEdge { segment: segment.map(&f), edges },
+            Issue::OverlappingEdges { segment, edges } => Issue::OverlappingEdges { segment: segment.map(&f), edges },
+            Issue::WrongOrientation { contour } => Issue::WrongOrientation { contour },
+        }
+    }
+}
+
+/// Trait `Validate` reports problems of the geometry without changing it.
+///
+/// - `[IntPoint]` is validated as a single contour.
+/// - `[IntContour]` is validated as independent contours, like `Overlay::with_contours` reads them.
+/// - `[IntShape]` is validated as shapes, holes are also checked for the orientation opposite to their outer contour.
+pub trait Validate {
+    fn validate(&self) -> ValidationReport;
+}
+
+impl Validate for [IntPoint] {
+    #[inline]
+    fn validate(&self) -> ValidationReport {
+        validate(&[(ContourId { shape: 0, contour: 0 }, self)], &[true])
+    }
+}
+
+impl Validate for [IntContour] {
+    fn validate(&self) -> ValidationReport {
+        let contours: Vec<_> = self.iter().enumerate()
+            .map(|(i, contour)| (ContourId { shape: i, contour: 0 }, contour.as_slice()))
+            .collect();
+
+        validate(&contours, &vec![true; contours.len()])
+    }
+}
+
+impl Validate for [IntShape] {
+    #[inline]
+    fn validate(&self) -> ValidationReport {
+        validate_changed(self, |_| true)
+    }
+}
+
+/// Validates only the contours for which `is_changed` returns `true`: their own problems and their
+/// problems with any other contour. Problems between two unchanged contours are not reported.
+pub(crate) fn validate_changed<F: Fn(ContourId) -> bool>(shapes: &[IntShape], is_changed: F) -> ValidationReport {
+    let contours: Vec<_> = shapes.iter().enumerate()
+        .flat_map(|(i, shape)| shape.iter().enumerate()
+            .map(move |(j, contour)| (ContourId { shape: i, contour: j }, contour.as_slice())))
+        .collect();
+    let changed: Vec<bool> = contours.iter().map(|&(id, _)| is_changed(id)).collect();
+
+    let mut report = validate(&contours, &changed);
+
+    for (i, shape) in shapes.iter().enumerate() {
+        let outer = match shape.first() {
+            Some(outer) if has_area(outer) => outer.unsafe_area().signum(),
+            _ => continue,
+        };
+        let is_outer_changed = is_changed(ContourId { shape: i, contour: 0 });
+
+        for (j, hole) in shape.iter().enumerate().skip(1) {
+            let id = ContourId { shape: i, contour: j };
+            if (is_outer_changed || is_changed(id)) && has_area(hole) && hole.unsafe_area().signum() == outer {
+                report.issues.push(Issue::WrongOrientation { contour: id });
+            }
+        }
+    }
+
+    report
+}
+
+#[inline]
+fn has_area(contour: &IntContour) -> bool {
+    contour.len() >= 3 && contour.unsafe_area() != 0
+}
+
+struct Edge {
+    x_segment: XSegment,
+    id: EdgeId,
+    contour: usize,
+    order: usize,
+    count: usize,
+}
+
+impl Edge {
+    #[inline]
+    fn is_neighbor(&self, other: &Edge) -> bool {
+        if self.contour != other.contour {
+            return false;
+        }
+        let diff = self.order.abs_diff(other.order);
+        diff == 1 || diff == self.count - 1
+    }
+}
+
+fn validate(contours: &[(ContourId, &[IntPoint])], changed: &[bool]) -> ValidationReport {
+    let mut issues = Vec::new();
+    let mut edges = Vec::new();
+    let mut vertices = Vec::new();
+
+    // an unchanged edge is needed only if it can touch a changed one
+    let bounds = contours.iter().zip(changed.iter())
+        .filter(|&(_, &is_changed)| is_changed)
+        .flat_map(|(&(_, points), _)| points.iter())
+        .fold(None, |bounds: Option<(IntPoint, IntPoint)>, &p| match bounds {
+            Some((min, max)) => Some((IntPoint::new(min.x.min(p.x), min.y.min(p.y)), IntPoint::new(max.x.max(p.x), max.y.max(p.y)))),
+            None => Some((p, p)),
+        });
+    let (min, max) = if let Some(bounds) = bounds { bounds } else { return ValidationReport { issues }; };
+
+    for (c, &(id, points)) in contours.iter().enumerate() {
+        let is_changed = changed[c];
+        if distinct_count(points) < 3 {
+            if is_changed {
+                issues.push(Issue::TooFewPoints { contour: id });
+            }
+            continue;
+        }
+
+        let n = points.len();
+        for i in 0..n {
+            if is_changed && points[i] == points[(i + 1) % n] {
+                let edge = EdgeId { shape: id.shape, contour: id.contour, index: i };
+                issues.push(Issue::ZeroLengthEdge { point: points[i], edge });
+            }
+        }
+
+        // the first index of every run of equal points
+        let starts: Vec<usize> = (0..n).filter(|&i| points[i] != points[(i + n - 1) % n]).collect();
+        let m = starts.len();
+
+        for k in 0..m {
+            let p0 = points[starts[(k + m - 1) % m]];
+            let p1 = points[starts[k]];
+            let p2 = points[starts[(k + 1) % m]];
+            if is_changed && is_spike(p0, p1, p2) {
+                issues.push(Issue::Spike { point: p1, contour: id, vertex: starts[k] });
+            }
+        }
+
+        for k in 0..m {
+            let a = points[starts[k]];
+            let end = starts[(k + 1) % m];
+            let b = points[end];
+            // the last edge of the run, the other ones have zero length
+            let index = (end + n - 1) % n;
+            let x_segment = if a < b { XSegment { a, b } } else { XSegment { a: b, b: a } };
+            let is_near = x_segment.a.x <= max.x && min.x <= x_segment.b.x
+                && a.y.min(b.y) <= max.y && min.y <= a.y.max(b.y);
+            if !is_changed && !is_near {
+                continue;
+            }
+            let edge = Edge {
+                x_segment,
+                id: EdgeId { shape: id.shape, contour: id.contour, index },
+                contour: c,
+                order: k,
+                count: m,
+            };
+            if is_changed {
+                vertices.push((c, a, edge.id));
+            }
+            edges.push(edge);
+        }
+    }
+
+    // a contour passes twice through the same vertex
+    vertices.sort_unstable_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+    for pair in vertices.windows(2) {
+        let (c0, p0, e0) = pair[0];
+        let (c1, p1, e1) = pair[1];
+        if c0 == c1 && p0 == p1 {
+            issues.push(Issue::Touching { point: p0, edges: [e0, e1] });
+        }
+    }
+
+    edges.sort_unstable_by_key(|edge| edge.x_segment);
+    let segments: Vec<Segment<()>> = edges.iter().map(|edge| Segment { x_segment: edge.x_segment, count: () }).collect();
+
+    let contacts = segments.find_contacts(Solver::default(), |i, j| {
+        let (ei, ej) = (&edges[i], &edges[j]);
+        (changed[ei.contour] || changed[ej.contour]) && !ei.is_neighbor(ej)
+    });
+
+    // a vertex inside an edge is found by both edges of the vertex, keep one
+    let mut touches = Vec::new();
+
+    for contact in contacts.into_iter() {
+        let (ei, ej) = (&edges[contact.i], &edges[contact.j]);
+        let edges = pair(ei.id, ej.id);
+        match contact.contact {
+            Contact::Cross(point) => issues.push(Issue::Crossing { point, edges }),
+            Contact::Touch { point, inner } => {
+                let (inner, end) = if inner == contact.i { (ei.id, ej.id) } else { (ej.id, ei.id) };
+                touches.push((point, inner, end));
+            }
+            Contact::Same => {
+                let segment = [ei.x_segment.a, ei.x_segment.b];
+                issues.push(Issue::DuplicateEdge { segment, edges });
+            }
+            Contact::Overlap(segment) => issues.push(Issue::OverlappingEdges { segment, edges }),
+        }
+    }
+
+    // (point, edge with the point inside, edge ending at the point)
+    touches.sort_unstable();
+    touches.dedup_by_key(|(point, inner, _)| (*point, *inner));
+    for (point, inner, end) in touches {
+        issues.push(Issue::Touching { point, edges: pair(inner, end) });
+    }
+
+    ValidationReport { issues }
+}
+
+#[inline]
+fn pair(a: EdgeId, b: EdgeId) -> [EdgeId; 2] {
+    if a < b { [a, b] } else { [b, a] }
+}
+
+#[inline]
+fn distinct_count(points: &[IntPoint]) -> usize {
+    let mut sorted = points.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    sorted.len()
+}
+
+/// `p1` is a spike if the contour comes from `p0` and returns back towards it.
+#[inline]
+fn is_spike(p0: IntPoint, p1: IntPoint, p2: IntPoint) -> bool {
+    let v0 = p0.subtract(p1);
+    let v1 = p2.subtract(p1);
+    Triangle::is_line_point(p0, p1, p2) && v0.dot_product(v1) > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::validation::{validate_changed, ContourId, EdgeId, Issue, Validate};
+
+    fn edge(contour: usize, index: usize) -> EdgeId {
+        EdgeId { shape: contour, contour: 0, index }
+    }
+
+    fn path(points: &[[i32; 2]]) -> Vec<IntPoint> {
+        points.iter().map(|p| IntPoint::new(p[0], p[1])).collect()
+    }
+
+    #[test]
+    fn test_valid() {
+        let square = path(&[[0, 0], [0, 10], [10, 10], [10, 0]]);
+        let hole = path(&[[2, 2], [8, 2], [8, 8], [2, 8]]);
+        let report = [vec![square, hole]].validate();
+        assert!(report.is_valid(), "{:?}", report);
+    }
+
+    #[test]
+    fn test_crossing() {
+        let bow_tie = path(&[[0, 0], [10, 10], [10, 0], [0, 10]]);
+        let report = bow_tie.validate();
+        assert_eq!(report.issues, vec![Issue::Crossing { point: IntPoint::new(5, 5), edges: [edge(0, 0), edge(0, 2)] }]);
+    }
+
+    #[test]
+    fn test_touching() {
+        // the vertex (5, 0) lies on the edge of the second contour
+        let a = path(&[[0, -5], [5, 0], [10, -5]]);
+        let b = path(&[[0, 0], [0, 10], [10, 10], [10, 0]]);
+        let report = [a, b].validate();
+        assert_eq!(report.issues, vec![Issue::Touching { point: IntPoint::new(5, 0), edges: [edge(0, 0), edge(1, 3)] }]);
+    }
+
+    #[test]
+    fn test_repeated_vertex() {
+        // two triangles connected by the vertex (5, 5)
+        let figure_eight = path(&[[0, 0], [5, 5], [10, 0], [10, 10], [5, 5], [0, 10]]);
+        let report = figure_eight.validate();
+        assert_eq!(report.issues, vec![Issue::Touching { point: IntPoint::new(5, 5), edges: [edge(0, 1), edge(0, 4)] }]);
+    }
+
+    #[test]
+    fn test_duplicate_and_overlapping() {
+        let a = path(&[[0, 0], [0, 10], [10, 10], [10, 0]]);
+        let b = path(&[[10, 0], [10, 10], [20, 10], [20, 0]]);
+        let c = path(&[[0, 10], [0, 20], [5, 20], [5, 10]]);
+        let report = [a, b, c].validate();
+
+        assert!(report.issues.contains(&Issue::DuplicateEdge {
+            segment: [IntPoint::new(10, 0), IntPoint::new(10, 10)],
+            edges: [edge(0, 2), edge(1, 0)],
+        }));
+        assert!(report.issues.contains(&Issue::OverlappingEdges {
+            segment: [IntPoint::new(0, 10), IntPoint::new(5, 10)],
+            edges: [edge(0, 1), edge(2, 3)],
+        }));
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let contours = [
+            path(&[[0, 0], [0, 10], [0, 10], [10, 10], [10, 0]]),
+            path(&[[0, 0], [10, 0], [0, 0]]),
+            path(&[[100, 0], [100, 10], [110, 10], [110, 0], [120, 0]]),
+        ];
+        let report = contours.validate();
+
+        assert_eq!(report.issues, vec![
+            Issue::ZeroLengthEdge { point: IntPoint::new(0, 10), edge: edge(0, 1) },
+            Issue::TooFewPoints { contour: ContourId { shape: 1, contour: 0 } },
+            Issue::Spike { point: IntPoint::new(120, 0), contour: ContourId { shape: 2, contour: 0 }, vertex: 4 },
+            // the spike returns over the vertex (110, 0)
+            Issue::Touching { point: IntPoint::new(110, 0), edges: [edge(2, 2), edge(2, 4)] },
+        ]);
+    }
+
+    #[test]
+    fn test_changed() {
+        // a crosses b, b crosses c, only c is changed
+        let a = path(&[[0, 0], [0, 10], [10, 10], [10, 0]]);
+        let b = path(&[[5, 5], [5, 15], [15, 15], [15, 5]]);
+        let c = path(&[[12, 12], [12, 20], [20, 20], [20, 12]]);
+        let far = path(&[[100, 100], [100, 110], [110, 110], [110, 100]]);
+        let shapes = [vec![a], vec![b], vec![c], vec![far]];
+
+        let report = validate_changed(&shapes, |id| id.shape == 2);
+        assert_eq!(report.issues, vec![
+            Issue::Crossing { point: IntPoint::new(12, 15), edges: [edge(1, 1), edge(2, 0)] },
+            Issue::Crossing { point: IntPoint::new(15, 12), edges: [edge(1, 2), edge(2, 3)] },
+        ]);
+        assert_eq!(shapes.validate().issues.len(), 4);
+    }
+
+    #[test]
+    fn test_many_edges() {
+        // more edges than the list broad phase takes, the rectangle crosses the parabola at its bottom and top
+        let parabola: Vec<IntPoint> = (-2500..=2500).map(|x| IntPoint::new(x, x * x)).collect();
+        assert!(parabola.validate().is_valid());
+
+        let rect = path(&[[-15, 20], [-15, 200], [15, 200], [15, 20]]);
+        let report = [parabola, rect].validate();
+        let points: Vec<IntPoint> = report.issues.iter()
+            .map(|issue| if let Issue::Crossing { point, .. } = issue { *point } else { panic!("{:?}", issue) })
+            .collect();
+        assert_eq!(points, path(&[[-15, 200], [-5, 20], [4, 20], [14, 200]]));
+    }
+
+    #[test]
+    fn test_wrong_orientation() {
+        let square = path(&[[0, 0], [0, 10], [10, 10], [10, 0]]);
+        let hole = path(&[[2, 2], [2, 8], [8, 8], [8, 2]]);
+        let report = [vec![square, hole]].validate();
+        assert_eq!(report.issues, vec![Issue::WrongOrientation { contour: ContourId { shape: 0, contour: 1 } }]);
+    }
+}
@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 use crate::geom::x_segment::XSegment;
 
 #[derive(Debug, Clone)]
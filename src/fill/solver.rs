@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+use crate::geom::x_segment::XSegment;
 use crate::segm::segment::{Segment, SegmentFill};
 use crate::segm::winding_count::WindingCount;
 
@@ -17,4 +19,19 @@ impl FillSolver {
             Self::tree_fill::<F, C>(segments)
         }
     }
-}
\ No newline at end of file
+
+    /// Sweeps split segments sorted by `a` and returns the region ids on both sides of every segment.
+    /// - `is_list`: Use the list sweep instead of the tree sweep.
+    /// - `add`: Returns the id of the region above the segment from its index and the id of the region below it.
+    ///
+    /// The id `0` is the region outside of everything. A vertical segment has the region on its right as
+    /// the bottom one and the region on its left as the top one.
+    #[inline]
+    pub(crate) fn scan<A: FnMut(usize, u32) -> u32>(is_list: bool, segments: &[XSegment], add: A) -> Vec<[u32; 2]> {
+        if is_list {
+            Self::list_scan(segments, add)
+        } else {
+            Self::tree_scan(segments, add)
+        }
+    }
+}
@@ -1,9 +1,12 @@
-use std::cmp::Ordering;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use i_float::int::point::IntPoint;
 use i_float::triangle::Triangle;
 use crate::fill::count_segment::CountSegment;
 use crate::fill::solver::{FillSolver, FillStrategy};
 use crate::geom::end::End;
+use crate::geom::x_segment::XSegment;
 use crate::segm::segment::{Segment, SegmentFill, NONE};
 use crate::segm::winding_count::WindingCount;
 use crate::util::log::Int;
@@ -12,7 +15,7 @@ struct ScanFillList<C> {
     buffer: Vec<CountSegment<C>>,
 }
 
-impl<C: WindingCount> ScanFillList<C> {
+impl<C: Copy> ScanFillList<C> {
     #[inline(always)]
     fn new(count: usize) -> Self {
         Self { buffer: Vec::with_capacity(count.log2_sqrt()) }
@@ -32,7 +35,7 @@ impl<C: WindingCount> ScanFillList<C> {
     }
 
     #[inline(always)]
-    fn find_under_and_nearest(&mut self, p: IntPoint) -> C {
+    fn find_under_and_nearest(&mut self, p: IntPoint, empty: C) -> C {
         match self.buffer.binary_search_by(|s|
         if s.x_segment.is_under_point(p) {
             Ordering::Less
@@ -43,7 +46,7 @@ impl<C: WindingCount> ScanFillList<C> {
             Ok(_) => unreachable!("This condition should never occur"),
             Err(index) => {
                 if index == 0 {
-                    C::new(0, 0)
+                    empty
                 } else {
                     unsafe { self.buffer.get_unchecked(index - 1) }.count
                 }
@@ -86,7 +89,7 @@ impl FillSolver {
                 Ordering::Greater
             });
 
-            let mut sum_count = scan_list.find_under_and_nearest(p);
+            let mut sum_count = scan_list.find_under_and_nearest(p, C::new(0, 0));
             let mut fill: SegmentFill;
 
             for se in buf.iter() {
@@ -103,4 +106,55 @@ impl FillSolver {
 
         result
     }
+
+    /// The list counterpart of `tree_scan`, see `FillSolver::scan`.
+    pub(super) fn list_scan<A: FnMut(usize, u32) -> u32>(segments: &[XSegment], mut add: A) -> Vec<[u32; 2]> {
+        let mut scan_list = ScanFillList::new(segments.len());
+        let mut buf = Vec::with_capacity(4);
+
+        let n = segments.len();
+        let mut result = vec![[0, 0]; n];
+        let mut i = 0;
+
+        let mut x0 = 0;
+
+        while i < n {
+            let p = segments[i].a;
+            if p.x != x0 {
+                scan_list.clear(p.x);
+                x0 = p.x;
+            }
+
+            buf.push(End { index: i, point: segments[i].b });
+            i += 1;
+
+            while i < n && segments[i].a == p {
+                buf.push(End { index: i, point: segments[i].b });
+                i += 1;
+            }
+
+            buf.sort_by(|s0, s1|
+            if Triangle::is_clockwise_point(p, s1.point, s0.point) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            });
+
+            let mut region = scan_list.find_under_and_nearest(p, 0);
+
+            for se in buf.iter() {
+                let bottom = region;
+                region = add(se.index, bottom);
+                result[se.index] = [bottom, region];
+                let x_segment = segments[se.index];
+                if x_segment.is_not_vertical() {
+                    scan_list.insert(CountSegment { count: region, x_segment });
+                }
+            }
+
+            buf.clear();
+        }
+
+        result
+    }
 }
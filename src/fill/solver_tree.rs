@@ -1,4 +1,6 @@
-use std::cmp::Ordering;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use i_float::int::point::IntPoint;
 use i_float::triangle::Triangle;
 use i_tree::node::{Color, EMPTY_REF};
@@ -13,15 +15,15 @@ use crate::util::log::Int;
 
 pub(super) struct ScanFillTree<C> {
     tree: Tree<CountSegment<C>>,
+    empty: C,
 }
 
-impl<C: WindingCount> ScanFillTree<C> {
+impl<C: Copy> ScanFillTree<C> {
     #[inline]
-    pub(super) fn new(count: usize) -> Self {
+    pub(super) fn new(count: usize, empty: C) -> Self {
         let capacity = count.log2_sqrt();
-        let count = C::new(0, 0);
         let x_segment = XSegment { a: IntPoint::ZERO, b: IntPoint::ZERO };
-        Self { tree: Tree::new(CountSegment { count, x_segment }, capacity) }
+        Self { tree: Tree::new(CountSegment { count: empty, x_segment }, capacity), empty }
     }
 
     pub(super) fn insert(&mut self, segment: CountSegment<C>) {
@@ -77,7 +79,7 @@ impl<C: WindingCount> ScanFillTree<C> {
 
     fn find_under_and_nearest(&mut self, p: IntPoint) -> C {
         let mut index = self.tree.root;
-        let mut result = C::new(0, 0);
+        let mut result = self.empty;
         while index != EMPTY_REF {
             let node = self.tree.node(index);
             if node.value.x_segment.b.x <= p.x {
@@ -104,7 +106,7 @@ impl<C: WindingCount> ScanFillTree<C> {
 impl FillSolver {
     pub(super) fn tree_fill<F: FillStrategy<C>, C: WindingCount>(segments: &[Segment<C>]) -> Vec<SegmentFill> {
         // Mark. self is sorted by x_segment.a
-        let mut scan_list = ScanFillTree::new(segments.len());
+        let mut scan_list = ScanFillTree::new(segments.len(), C::new(0, 0));
         let mut buf = Vec::with_capacity(4);
 
         let n = segments.len();
@@ -146,4 +148,49 @@ impl FillSolver {
 
         result
     }
-}
\ No newline at end of file
+
+    /// The tree counterpart of `list_scan`, see `FillSolver::scan`.
+    pub(super) fn tree_scan<A: FnMut(usize, u32) -> u32>(segments: &[XSegment], mut add: A) -> Vec<[u32; 2]> {
+        let mut scan_tree = ScanFillTree::new(segments.len(), 0);
+        let mut buf = Vec::with_capacity(4);
+
+        let n = segments.len();
+        let mut result = vec![[0, 0]; n];
+        let mut i = 0;
+
+        while i < n {
+            let p = segments[i].a;
+
+            buf.push(End { index: i, point: segments[i].b });
+            i += 1;
+
+            while i < n && segments[i].a == p {
+                buf.push(End { index: i, point: segments[i].b });
+                i += 1;
+            }
+
+            buf.sort_by(|s0, s1|
+            if Triangle::is_clockwise_point(p, s1.point, s0.point) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            });
+
+            let mut region = scan_tree.find_under_and_nearest(p);
+
+            for se in buf.iter() {
+                let bottom = region;
+                region = add(se.index, bottom);
+                result[se.index] = [bottom, region];
+                let x_segment = segments[se.index];
+                if x_segment.is_not_vertical() {
+                    scan_tree.insert(CountSegment { count: region, x_segment });
+                }
+            }
+
+            buf.clear();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,24 @@
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+
+/// Signed area of a float contour, positive for counterclockwise contours.
+/// The opposite sign of `PointPathExtension::unsafe_area` and not doubled.
+pub(crate) fn signed_area<P, T>(contour: &[P]) -> f64
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let mut area = 0.0;
+    let mut p0 = match contour.last() {
+        Some(p) => [p.x().to_f64(), p.y().to_f64()],
+        None => return 0.0,
+    };
+
+    for p in contour.iter() {
+        let p1 = [p.x().to_f64(), p.y().to_f64()];
+        area += p0[0] * p1[1] - p1[0] * p0[1];
+        p0 = p1;
+    }
+
+    0.5 * area
+}
@@ -0,0 +1,265 @@
+//! Float counterpart of [`crate::core::canonical`].
+
+use core::cmp::Ordering;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::{Contour, Shape, Shapes};
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay_rule::OverlayRule;
+use crate::float::area::signed_area;
+use crate::float::overlay::FloatOverlay;
+use crate::float::source::resource::OverlayResource;
+
+/// Trait `FloatCanonical` converts float geometry into a deterministic representation:
+/// duplicate points and collinear vertices are removed, every contour starts from its minimal point
+/// (ordered by `x`, then by `y`), the outer contour stays first, holes and shapes are sorted.
+pub trait FloatCanonical<P, T: FloatNumber> {
+    /// Converts `self` into the canonical form in place.
+    fn canonicalize(&mut self);
+
+    /// Returns a canonical copy of `self`.
+    fn to_canonical(&self) -> Self;
+}
+
+impl<P, T> FloatCanonical<P, T> for Contour<P>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    fn canonicalize(&mut self) {
+        self.dedup_by(|a, b| is_same_point(*a, *b));
+
+        while self.len() > 1 && is_same_point(self[0], self[self.len() - 1]) {
+            self.pop();
+        }
+
+        remove_collinear(self);
+
+        if self.len() < 3 {
+            self.clear();
+            return;
+        }
+
+        let start = self.iter().enumerate()
+            .min_by(|(_, a), (_, b)| compare_points(*a, *b))
+            .map_or(0, |(i, _)| i);
+        self.rotate_left(start);
+    }
+
+    #[inline]
+    fn to_canonical(&self) -> Self {
+        let mut contour = self.clone();
+        contour.canonicalize();
+        contour
+    }
+}
+
+impl<P, T> FloatCanonical<P, T> for Shape<P>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    fn canonicalize(&mut self) {
+        for contour in self.iter_mut() {
+            contour.canonicalize();
+        }
+
+        if self.first().is_none_or(|outer| outer.is_empty()) {
+            self.clear();
+            return;
+        }
+
+        self.retain(|contour| !contour.is_empty());
+        self[1..].sort_by(|a, b| compare_contours(a, b));
+    }
+
+    #[inline]
+    fn to_canonical(&self) -> Self {
+        let mut shape = self.clone();
+        shape.canonicalize();
+        shape
+    }
+}
+
+impl<P, T> FloatCanonical<P, T> for Shapes<P>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    fn canonicalize(&mut self) {
+        for shape in self.iter_mut() {
+            shape.canonicalize();
+        }
+
+        self.retain(|shape| !shape.is_empty());
+        self.sort_by(|a, b| {
+            a.iter().zip(b.iter())
+                .map(|(c0, c1)| compare_contours(c0, c1))
+                .find(|order| order.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        });
+    }
+
+    #[inline]
+    fn to_canonical(&self) -> Self {
+        let mut shapes = self.clone();
+        shapes.canonicalize();
+        shapes
+    }
+}
+
+/// Trait `FloatPointSetEq` compares float geometry by the area it covers.
+pub trait FloatPointSetEq<R, P, T>
+where
+    R: OverlayResource<P, T>,
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    /// Returns `true` if both arguments cover exactly the same point set, regardless of representation.
+    ///
+    /// Both arguments are interpreted with the `NonZero` fill rule.
+    fn eq_point_set(&self, other: &R) -> bool;
+
+    /// Returns `true` if both arguments cover the same point set, regardless of representation.
+    ///
+    /// - `other`: The geometry to compare with.
+    /// - `tolerance`: The maximum allowed width of a difference between the two point sets.
+    ///   Every piece of the symmetric difference must satisfy `area <= tolerance * perimeter / 2`,
+    ///   which for a thin sliver means its width is not larger than `tolerance`.
+    ///
+    /// Both arguments are interpreted with the `NonZero` fill rule.
+    fn eq_point_set_with_tolerance(&self, other: &R, tolerance: T) -> bool;
+}
+
+impl<S, R, P, T> FloatPointSetEq<R, P, T> for S
+where
+    S: OverlayResource<P, T>,
+    R: OverlayResource<P, T>,
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    #[inline]
+    fn eq_point_set(&self, other: &R) -> bool {
+        FloatOverlay::with_subj_and_clip(self, other).overlay(OverlayRule::Xor, FillRule::NonZero).is_empty()
+    }
+
+    fn eq_point_set_with_tolerance(&self, other: &R, tolerance: T) -> bool {
+        let xor = FloatOverlay::with_subj_and_clip(self, other).overlay(OverlayRule::Xor, FillRule::NonZero);
+
+        let tolerance = tolerance.to_f64();
+        xor.iter().all(|shape| {
+            let (area, perimeter) = shape.iter().fold((0.0, 0.0), |(area, perimeter), contour| {
+                (area + signed_area(contour), perimeter + perimeter_of(contour))
+            });
+            2.0 * area.abs() <= tolerance * perimeter
+        })
+    }
+}
+
+#[inline]
+fn is_same_point<P: FloatPointCompatible<T>, T: FloatNumber>(a: P, b: P) -> bool {
+    a.x() == b.x() && a.y() == b.y()
+}
+
+#[inline]
+fn compare_points<P: FloatPointCompatible<T>, T: FloatNumber>(a: &P, b: &P) -> Ordering {
+    a.x().partial_cmp(&b.x())
+        .unwrap_or(Ordering::Equal)
+        .then_with(|| a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+}
+
+fn compare_contours<P: FloatPointCompatible<T>, T: FloatNumber>(a: &Contour<P>, b: &Contour<P>) -> Ordering {
+    a.iter().zip(b.iter())
+        .map(|(p0, p1)| compare_points(p0, p1))
+        .find(|order| order.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+fn remove_collinear<P: FloatPointCompatible<T>, T: FloatNumber>(contour: &mut Contour<P>) {
+    let zero = T::from_float(0.0);
+    let mut i = 0;
+    let mut unchanged = 0;
+    while contour.len() >= 3 && unchanged < contour.len() {
+        let n = contour.len();
+        i %= n;
+        let p0 = contour[(i + n - 1) % n];
+        let p1 = contour[i];
+        let p2 = contour[(i + 1) % n];
+
+        let cross = (p1.x() - p0.x()) * (p2.y() - p1.y()) - (p1.y() - p0.y()) * (p2.x() - p1.x());
+        if cross == zero {
+            contour.remove(i);
+            unchanged = 0;
+        } else {
+            i += 1;
+            unchanged += 1;
+        }
+    }
+}
+
+fn perimeter_of<P: FloatPointCompatible<T>, T: FloatNumber>(contour: &Contour<P>) -> f64 {
+    let mut perimeter = 0.0;
+    let mut p0 = match contour.last() {
+        Some(p) => *p,
+        None => return 0.0,
+    };
+
+    for &p1 in contour.iter() {
+        let (dx, dy) = (p1.x().to_f64() - p0.x().to_f64(), p1.y().to_f64() - p0.y().to_f64());
+        perimeter += FloatNumber::sqrt(dx * dx + dy * dy);
+        p0 = p1;
+    }
+
+    perimeter
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use crate::float::canonical::{FloatCanonical, FloatPointSetEq};
+
+    #[test]
+    fn test_contour() {
+        let contour = vec![[1.0, 1.0], [1.0, 0.0], [0.5, 0.0], [0.0, 0.0], [0.0, 1.0], [1.0, 1.0]];
+        let canonical = contour.to_canonical();
+
+        assert_eq!(canonical, vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let mut shape = vec![
+            vec![[1.0, 1.0], [1.0, 0.0], [0.0, 0.0], [0.0, 1.0]],
+            vec![[0.5, 0.5], [0.5, 0.5], [0.5, 0.5]],
+        ];
+        shape.canonicalize();
+
+        assert_eq!(shape, vec![vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]]);
+    }
+
+    #[test]
+    fn test_shapes() {
+        let a = vec![vec![[2.0, 0.0], [2.0, 1.0], [3.0, 1.0], [3.0, 0.0]]];
+        let b = vec![vec![[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]]];
+
+        let s0 = vec![a.clone(), b.clone()].to_canonical();
+        let s1 = vec![b, a].to_canonical();
+
+        assert_eq!(s0, s1);
+    }
+
+    #[test]
+    fn test_point_set() {
+        let whole = vec![[0.0, 0.0], [0.0, 1.0], [2.0, 1.0], [2.0, 0.0]];
+        let halves = vec![
+            vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]],
+            vec![[1.0, 0.0], [1.0, 1.0], [2.0, 1.0], [2.0, 0.0]],
+        ];
+        let wider = vec![[0.0, 0.0], [0.0, 1.0], [2.001, 1.0], [2.001, 0.0]];
+
+        assert!(whole.eq_point_set(&halves));
+        assert!(!whole.eq_point_set(&wider));
+        assert!(whole.eq_point_set_with_tolerance(&wider, 0.01));
+        assert!(!whole.eq_point_set_with_tolerance(&wider, 0.0001));
+    }
+}
@@ -0,0 +1,114 @@
+//! Float counterpart of [`crate::core::cleanup`].
+
+use alloc::vec::Vec;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::{Shape, Shapes};
+use i_shape::float::adapter::ShapesToFloat;
+use crate::core::cleanup::{Clean, Cleanup};
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::float::graph::FloatOverlayGraph;
+use crate::float::overlay::FloatOverlay;
+
+/// Trait `FloatClean` removes small holes, small shapes, slivers, spikes and necks by the thresholds of `Cleanup`.
+pub trait FloatClean<P, T: FloatNumber> {
+    /// Cleans the shapes.
+    /// - `cleanup`: The thresholds in the units of the shapes.
+    /// # Returns
+    /// The cleaned shapes, outer contours are clockwise and holes are counterclockwise.
+    fn clean(&self, cleanup: &Cleanup<T>) -> Shapes<P>;
+}
+
+impl<P, T> FloatClean<P, T> for [Shape<P>]
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    fn clean(&self, cleanup: &Cleanup<T>) -> Shapes<P> {
+        let adapter = FloatPointAdapter::with_iter(self.iter().flatten().flatten());
+        let shapes: Vec<Vec<Vec<_>>> = self.iter()
+            .map(|shape| shape.iter().map(|contour| contour.iter().map(|p| adapter.float_to_int(p)).collect()).collect())
+            .collect();
+
+        shapes.clean(&cleanup.to_int(&adapter)).to_float(&adapter)
+    }
+}
+
+impl<T: FloatNumber> Cleanup<T> {
+    /// The thresholds on the integer grid of the adapter.
+    pub(crate) fn to_int<P: FloatPointCompatible<T>>(self, adapter: &FloatPointAdapter<P, T>) -> Cleanup {
+        let length = |value: T| (value * adapter.dir_scale).to_f64() as usize;
+        Cleanup {
+            min_outer_area: adapter.sqr_float_to_int(self.min_outer_area),
+            min_hole_area: adapter.sqr_float_to_int(self.min_hole_area),
+            min_sliver_ratio: length(self.min_sliver_ratio),
+            min_width: length(self.min_width),
+            max_spike_angle: self.max_spike_angle,
+        }
+    }
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatOverlay<P, T> {
+    /// Executes a single Boolean operation and cleans the result by the thresholds of `Cleanup`.
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    /// - `cleanup`: The thresholds in the units of the shapes.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The cleaned shapes, outer contours are clockwise and holes are counterclockwise.
+    #[inline]
+    pub fn overlay_with_cleanup_and_solver(self, overlay_rule: OverlayRule, fill_rule: FillRule, cleanup: &Cleanup<T>, solver: Solver) -> Shapes<P> {
+        let cleanup = cleanup.to_int(&self.adapter);
+        self.overlay
+            .overlay_with_cleanup_and_solver(overlay_rule, fill_rule, &cleanup, solver)
+            .to_float(&self.adapter)
+    }
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatOverlayGraph<P, T> {
+    /// Extracts shapes from the overlay graph and cleans them by the thresholds of `Cleanup`.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph.
+    /// - `cleanup`: The thresholds in the units of the shapes.
+    /// # Returns
+    /// The cleaned shapes, outer contours are clockwise and holes are counterclockwise.
+    #[inline]
+    pub fn extract_shapes_with_cleanup(&self, overlay_rule: OverlayRule, cleanup: &Cleanup<T>) -> Shapes<P> {
+        let cleanup = cleanup.to_int(&self.adapter);
+        self.graph
+            .extract_shapes_with_cleanup(overlay_rule, &cleanup)
+            .to_float(&self.adapter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use crate::core::cleanup::Cleanup;
+    use crate::core::fill_rule::FillRule;
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::float::cleanup::FloatClean;
+    use crate::float::overlay::FloatOverlay;
+
+    #[test]
+    fn test_hole() {
+        let outer = vec![[0.0, 0.0], [0.0, 10.0], [10.0, 10.0], [10.0, 0.0]];
+        let hole = vec![[1.0, 1.0], [1.5, 1.0], [1.5, 1.5], [1.0, 1.5]];
+        let cleanup = Cleanup { min_hole_area: 0.5, ..Default::default() };
+
+        let result = [vec![outer.clone(), hole.clone()]].as_slice().clean(&cleanup);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].len(), 1);
+
+        let result = FloatOverlay::with_subj_and_clip(&outer, &hole)
+            .overlay_with_cleanup_and_solver(OverlayRule::Difference, FillRule::NonZero, &cleanup, Default::default());
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].len(), 1);
+
+        let result = FloatOverlay::with_subj_and_clip(&outer, &hole)
+            .overlay_with_cleanup_and_solver(OverlayRule::Difference, FillRule::NonZero, &Cleanup::default(), Default::default());
+        assert_eq!(result[0].len(), 2);
+    }
+}
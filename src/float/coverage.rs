@@ -0,0 +1,74 @@
+//! Float counterpart of [`crate::core::coverage`].
+
+use alloc::vec::Vec;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::{Shape, Shapes};
+use i_shape::float::adapter::ShapesToFloat;
+use crate::core::coverage::SimplifyCoverage;
+use crate::core::generalize::Tolerance;
+use crate::core::solver::Solver;
+
+/// Trait `FloatSimplifyCoverage` simplifies float polygons that share boundaries without opening gaps or overlaps between them.
+pub trait FloatSimplifyCoverage<P, T: FloatNumber> {
+    /// Simplifies the coverage with the default solver.
+    /// - `tolerance`: The distance or the area tolerance in the units of the polygons.
+    /// # Returns
+    /// The simplified polygons in the order of the input, every input shape may become several shapes.
+    fn simplify_coverage(&self, tolerance: Tolerance<T>) -> Vec<Shapes<P>>;
+
+    /// Simplifies the coverage.
+    /// - `tolerance`: The distance or the area tolerance in the units of the polygons.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The simplified polygons in the order of the input, every input shape may become several shapes.
+    fn simplify_coverage_with_solver(&self, tolerance: Tolerance<T>, solver: Solver) -> Vec<Shapes<P>>;
+}
+
+impl<P, T> FloatSimplifyCoverage<P, T> for [Shape<P>]
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    #[inline]
+    fn simplify_coverage(&self, tolerance: Tolerance<T>) -> Vec<Shapes<P>> {
+        self.simplify_coverage_with_solver(tolerance, Default::default())
+    }
+
+    fn simplify_coverage_with_solver(&self, tolerance: Tolerance<T>, solver: Solver) -> Vec<Shapes<P>> {
+        let adapter = FloatPointAdapter::with_iter(self.iter().flatten().flatten());
+        let tolerance = match tolerance {
+            Tolerance::Distance(distance) => Tolerance::Distance((distance * adapter.dir_scale).to_f64() as usize),
+            Tolerance::Area(area) => Tolerance::Area(adapter.sqr_float_to_int(area)),
+        };
+
+        let shapes: Vec<Vec<Vec<_>>> = self.iter()
+            .map(|shape| shape.iter().map(|contour| contour.iter().map(|p| adapter.float_to_int(p)).collect()).collect())
+            .collect();
+
+        shapes.simplify_coverage_with_solver(tolerance, solver)
+            .into_iter()
+            .map(|shapes| shapes.to_float(&adapter))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use crate::core::generalize::Tolerance;
+    use crate::float::coverage::FloatSimplifyCoverage;
+
+    #[test]
+    fn test_shared_boundary() {
+        let left = vec![[0.0, 0.0], [0.0, 10.0], [5.0, 10.0], [5.2, 7.5], [4.9, 5.0], [5.1, 2.5], [5.0, 0.0]];
+        let right = vec![[5.0, 0.0], [5.1, 2.5], [4.9, 5.0], [5.2, 7.5], [5.0, 10.0], [10.0, 10.0], [10.0, 0.0]];
+        let coverage = [vec![left], vec![right]];
+
+        let result = coverage.as_slice().simplify_coverage(Tolerance::Distance(0.5));
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0][0][0].len(), 4);
+        assert_eq!(result[1][0][0].len(), 4);
+    }
+}
@@ -0,0 +1,65 @@
+//! Float counterpart of [`crate::core::coverage_check`].
+
+use alloc::vec::Vec;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::Shape;
+use i_shape::float::adapter::{ShapeToFloat, ShapesToFloat};
+use crate::core::coverage_check::{CheckCoverage, CoverageReport, Overlap};
+
+/// Trait `FloatCheckCoverage` reports the overlaps and the gaps between float polygons.
+pub trait FloatCheckCoverage<P, T: FloatNumber> {
+    /// Checks the coverage.
+    /// - `max_gap_area`: If set, only gaps with an area not larger than it, in the units of the shapes, are reported.
+    /// # Returns
+    /// The overlaps with the indices of the polygons involved and the gaps.
+    fn check_coverage(&self, max_gap_area: Option<T>) -> CoverageReport<P>;
+}
+
+impl<P, T> FloatCheckCoverage<P, T> for [Shape<P>]
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    fn check_coverage(&self, max_gap_area: Option<T>) -> CoverageReport<P> {
+        let adapter = FloatPointAdapter::with_iter(self.iter().flatten().flatten());
+        let shapes: Vec<Vec<Vec<_>>> = self.iter()
+            .map(|shape| shape.iter().map(|contour| contour.iter().map(|p| adapter.float_to_int(p)).collect()).collect())
+            .collect();
+
+        let report = shapes.check_coverage(max_gap_area.map(|area| adapter.sqr_float_to_int(area)));
+
+        CoverageReport {
+            overlaps: report.overlaps.into_iter()
+                .map(|overlap| Overlap { shape: overlap.shape.to_float(&adapter), polygons: overlap.polygons })
+                .collect(),
+            gaps: report.gaps.to_float(&adapter),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use crate::float::coverage_check::FloatCheckCoverage;
+
+    #[test]
+    fn test_gap_and_overlap() {
+        let shapes = [
+            vec![vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]],
+            vec![vec![[0.9, 0.0], [0.9, 1.0], [2.0, 1.0], [2.0, 0.0]]],
+            vec![vec![[0.0, 1.1], [0.0, 2.0], [2.0, 2.0], [2.0, 1.1]]],
+            vec![vec![[-1.0, -1.0], [-1.0, 3.0], [0.0, 3.0], [0.0, -1.0]]],
+            vec![vec![[2.0, -1.0], [2.0, 3.0], [3.0, 3.0], [3.0, -1.0]]],
+        ];
+
+        let report = shapes.as_slice().check_coverage(None);
+        assert_eq!(report.overlaps.len(), 1);
+        assert_eq!(report.overlaps[0].polygons, vec![0, 1]);
+        assert_eq!(report.gaps.len(), 1);
+
+        let small = shapes.as_slice().check_coverage(Some(0.1));
+        assert!(small.gaps.is_empty());
+    }
+}
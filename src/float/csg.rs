@@ -0,0 +1,125 @@
+//! Float counterpart of [`crate::core::csg`].
+
+use alloc::vec::Vec;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::Shapes;
+use i_shape::float::adapter::ShapesToFloat;
+use crate::core::csg::{Csg, CsgExpression};
+use crate::core::fill_rule::FillRule;
+use crate::core::solver::Solver;
+use crate::float::source::resource::OverlayResource;
+
+/// Collects float leaves of constructive solid geometry expressions to evaluate them in one arrangement.
+#[derive(Clone)]
+pub struct FloatCsg<P: FloatPointCompatible<T>, T: FloatNumber> {
+    csg: Csg,
+    adapter: FloatPointAdapter<P, T>,
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatCsg<P, T> {
+    /// Constructs a new `FloatCsg` with a pre-configured adapter.
+    /// - `adapter`: A `FloatPointAdapter` instance responsible for coordinate conversion between
+    ///   float and integer values.
+    /// - `capacity`: The expected number of edges.
+    #[inline]
+    pub fn with_adapter(adapter: FloatPointAdapter<P, T>, capacity: usize) -> Self {
+        Self { csg: Csg::new(capacity), adapter }
+    }
+
+    /// Creates a new `FloatCsg` from leaves, the leaf expression of a resource is `CsgExpression::Leaf(index)`.
+    /// - `leaves`: The `OverlayResource` of every leaf, it can be a `Contour`, `Contours` or `Shapes`.
+    pub fn with_leaves<R: OverlayResource<P, T> + ?Sized>(leaves: &[&R]) -> Self {
+        let iter = leaves.iter().flat_map(|leaf| leaf.iter_paths()).flatten();
+        let adapter = FloatPointAdapter::with_iter(iter);
+        let capacity = leaves.iter().flat_map(|leaf| leaf.iter_paths()).fold(0, |s, c| s + c.len());
+
+        let mut csg = Self::with_adapter(adapter, capacity);
+        for leaf in leaves.iter() {
+            csg.unsafe_add_leaf(*leaf);
+        }
+        csg
+    }
+
+    /// Adds a leaf.
+    /// - `resource`: A `OverlayResource`, it can be a `Contour`, `Contours` or `Shapes`.
+    /// - **Safety**: Marked `unsafe` because it assumes the paths are fully contained within the bounding box of the adapter.
+    /// # Returns
+    /// The expression of the new leaf.
+    pub fn unsafe_add_leaf<R: OverlayResource<P, T> + ?Sized>(&mut self, resource: &R) -> CsgExpression {
+        let contours: Vec<Vec<_>> = resource.iter_paths()
+            .map(|contour| contour.iter().map(|p| self.adapter.float_to_int(p)).collect())
+            .collect();
+        self.csg.add_leaf(&contours)
+    }
+
+    /// Evaluates an expression with the default solver.
+    /// - `expression`: The expression over the leaves of this `FloatCsg`.
+    /// - `fill_rule`: The fill rule applied to the winding number of every leaf separately.
+    /// # Returns
+    /// The shapes of the expression. The outer contours are clockwise and the holes are counterclockwise.
+    #[inline]
+    pub fn evaluate(self, expression: &CsgExpression, fill_rule: FillRule) -> Shapes<P> {
+        self.evaluate_with_solver(expression, fill_rule, Default::default())
+    }
+
+    /// Evaluates an expression.
+    /// - `expression`: The expression over the leaves of this `FloatCsg`.
+    /// - `fill_rule`: The fill rule applied to the winding number of every leaf separately.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The shapes of the expression. The outer contours are clockwise and the holes are counterclockwise.
+    pub fn evaluate_with_solver(self, expression: &CsgExpression, fill_rule: FillRule, solver: Solver) -> Shapes<P> {
+        let adapter = self.adapter;
+        self.csg.evaluate_with_solver(expression, fill_rule, solver).to_float(&adapter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use i_float::adapter::FloatPointAdapter;
+    use crate::core::csg::CsgExpression;
+    use crate::core::fill_rule::FillRule;
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::float::csg::FloatCsg;
+
+    #[test]
+    fn test_union_minus_hole() {
+        let a = vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]];
+        let b = vec![[1.0, 0.0], [1.0, 1.0], [2.0, 1.0], [2.0, 0.0]];
+        let c = vec![[0.5, 0.25], [0.5, 0.75], [1.5, 0.75], [1.5, 0.25]];
+
+        let expression = CsgExpression::Leaf(0)
+            .apply(OverlayRule::Union, CsgExpression::Leaf(1))
+            .apply(OverlayRule::Difference, CsgExpression::Leaf(2));
+
+        let result = FloatCsg::with_leaves(&[&a, &b, &c]).evaluate(&expression, FillRule::NonZero);
+        assert_eq!(result, vec![vec![
+            vec![[0.0, 0.0], [0.0, 1.0], [2.0, 1.0], [2.0, 0.0]],
+            vec![[0.5, 0.75], [0.5, 0.25], [1.5, 0.25], [1.5, 0.75]],
+        ]]);
+    }
+
+    #[test]
+    fn test_mixed_resources() {
+        // a contour, contours and shapes are leaves of the same expression
+        let contour = vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]];
+        let contours = vec![vec![[1.0, 0.0], [1.0, 1.0], [2.0, 1.0], [2.0, 0.0]]];
+        let shapes = vec![vec![vec![[0.5, 0.25], [0.5, 0.75], [1.5, 0.75], [1.5, 0.25]]]];
+
+        let adapter = FloatPointAdapter::with_iter([[0.0, 0.0], [2.0, 1.0]].iter());
+        let mut csg = FloatCsg::with_adapter(adapter, 12);
+        let a = csg.unsafe_add_leaf(&contour);
+        let b = csg.unsafe_add_leaf(&contours);
+        let c = csg.unsafe_add_leaf(&shapes);
+
+        let expression = a.apply(OverlayRule::Union, b).apply(OverlayRule::Difference, c);
+        let result = csg.evaluate(&expression, FillRule::NonZero);
+        assert_eq!(result, vec![vec![
+            vec![[0.0, 0.0], [0.0, 1.0], [2.0, 1.0], [2.0, 0.0]],
+            vec![[0.5, 0.75], [0.5, 0.25], [1.5, 0.25], [1.5, 0.75]],
+        ]]);
+    }
+}
@@ -0,0 +1,100 @@
+//! Float counterpart of [`crate::core::dissolve`].
+
+use alloc::vec::Vec;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::Contour;
+use i_shape::float::adapter::{PathToFloat, ShapesToFloat};
+use crate::core::dissolve::{Dissolve, Dissolved};
+use crate::core::fill_rule::FillRule;
+use crate::core::solver::Solver;
+
+/// Collects float contours labeled with an attribute key to merge all contours of the same key.
+#[derive(Clone)]
+pub struct FloatDissolve<K, P: FloatPointCompatible<T>, T: FloatNumber> {
+    dissolve: Dissolve<K>,
+    adapter: FloatPointAdapter<P, T>,
+}
+
+impl<K: Ord + Clone, P: FloatPointCompatible<T>, T: FloatNumber> FloatDissolve<K, P, T> {
+    /// Constructs a new `FloatDissolve` with a pre-configured adapter.
+    /// - `adapter`: A `FloatPointAdapter` instance responsible for coordinate conversion between
+    ///   float and integer values.
+    /// - `capacity`: The expected number of edges.
+    #[inline]
+    pub fn with_adapter(adapter: FloatPointAdapter<P, T>, capacity: usize) -> Self {
+        Self { dissolve: Dissolve::new(capacity), adapter }
+    }
+
+    /// Creates a new `FloatDissolve` from `(key, contour)` pairs.
+    /// - `pairs`: The closed paths with the key every path belongs to.
+    pub fn with_pairs(pairs: &[(K, Contour<P>)]) -> Self {
+        let adapter = FloatPointAdapter::with_iter(pairs.iter().flat_map(|(_, contour)| contour.iter()));
+        let capacity = pairs.iter().map(|(_, contour)| contour.len()).sum();
+
+        let mut dissolve = Self::with_adapter(adapter, capacity);
+        for (key, contour) in pairs.iter() {
+            dissolve = dissolve.unsafe_add_contour(key.clone(), contour);
+        }
+        dissolve
+    }
+
+    /// Adds a closed path.
+    /// - `key`: The key the contour belongs to.
+    /// - `contour`: A contour representing a closed path.
+    /// - **Safety**: Marked `unsafe` because it assumes the path is fully contained within the bounding box of the adapter.
+    #[inline]
+    pub fn unsafe_add_contour(mut self, key: K, contour: &[P]) -> Self {
+        let contour: Vec<_> = contour.iter().map(|p| self.adapter.float_to_int(p)).collect();
+        self.dissolve.add_contour(key, &contour);
+        self
+    }
+
+    /// Merges the contours of every key with the default solver.
+    /// - `fill_rule`: The fill rule applied to the winding number of every key separately.
+    /// - `boundaries`: If `true`, the boundaries between different keys are returned too.
+    /// # Returns
+    /// The union of every key and the requested boundaries.
+    #[inline]
+    pub fn into_dissolved(self, fill_rule: FillRule, boundaries: bool) -> Dissolved<K, P> {
+        self.into_dissolved_with_solver(fill_rule, boundaries, Default::default())
+    }
+
+    /// Merges the contours of every key.
+    /// - `fill_rule`: The fill rule applied to the winding number of every key separately.
+    /// - `boundaries`: If `true`, the boundaries between different keys are returned too.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The union of every key and the requested boundaries. A key that covers nothing is omitted.
+    pub fn into_dissolved_with_solver(self, fill_rule: FillRule, boundaries: bool, solver: Solver) -> Dissolved<K, P> {
+        let adapter = self.adapter;
+        let dissolved = self.dissolve.into_dissolved_with_solver(fill_rule, boundaries, solver);
+        Dissolved {
+            shapes: dissolved.shapes.into_iter().map(|(key, shapes)| (key, shapes.to_float(&adapter))).collect(),
+            boundaries: dissolved.boundaries.iter().map(|path| path.to_float(&adapter)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use crate::core::fill_rule::FillRule;
+    use crate::float::dissolve::FloatDissolve;
+
+    #[test]
+    fn test_two_keys() {
+        let pairs = [
+            ('a', vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]),
+            ('b', vec![[0.0, 1.0], [0.0, 2.0], [1.0, 2.0], [1.0, 1.0]]),
+            ('a', vec![[1.0, 0.0], [1.0, 1.0], [2.0, 1.0], [2.0, 0.0]]),
+        ];
+
+        let dissolved = FloatDissolve::with_pairs(&pairs).into_dissolved(FillRule::NonZero, true);
+        assert_eq!(dissolved.shapes.len(), 2);
+        assert_eq!(dissolved.shapes[0].0, 'a');
+        assert_eq!(dissolved.shapes[0].1, vec![vec![vec![[0.0, 0.0], [0.0, 1.0], [2.0, 1.0], [2.0, 0.0]]]]);
+        assert_eq!(dissolved.boundaries, vec![vec![[0.0, 1.0], [1.0, 1.0]]]);
+    }
+}
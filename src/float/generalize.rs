@@ -0,0 +1,62 @@
+//! Float counterpart of [`crate::core::generalize`].
+//!
+//! The shapes are converted to the integer grid the overlay works on, so the tolerance is rounded
+//! down to the grid step.
+
+use alloc::vec::Vec;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::{Shape, Shapes};
+use i_shape::float::adapter::ShapesToFloat;
+use crate::core::generalize::{Generalize, Tolerance};
+
+/// Trait `FloatGeneralize` simplifies float shapes with a tolerance without creating crossings between their contours.
+pub trait FloatGeneralize<P, T: FloatNumber> {
+    /// Simplifies the shapes.
+    /// - `tolerance`: The distance or the area tolerance in the units of the shapes.
+    /// # Returns
+    /// The simplified shapes. A vertex is kept if removing it breaks the validity of the input.
+    fn generalize(&self, tolerance: Tolerance<T>) -> Shapes<P>;
+}
+
+impl<P, T> FloatGeneralize<P, T> for [Shape<P>]
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    fn generalize(&self, tolerance: Tolerance<T>) -> Shapes<P> {
+        let adapter = FloatPointAdapter::with_iter(self.iter().flatten().flatten());
+        let tolerance = match tolerance {
+            Tolerance::Distance(distance) => Tolerance::Distance((distance * adapter.dir_scale).to_f64() as usize),
+            Tolerance::Area(area) => Tolerance::Area(adapter.sqr_float_to_int(area)),
+        };
+
+        let shapes: Vec<Vec<Vec<_>>> = self.iter()
+            .map(|shape| shape.iter().map(|contour| contour.iter().map(|p| adapter.float_to_int(p)).collect()).collect())
+            .collect();
+
+        shapes.generalize(tolerance).to_float(&adapter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use crate::core::generalize::Tolerance;
+    use crate::float::generalize::FloatGeneralize;
+
+    #[test]
+    fn test_noise() {
+        let contour = vec![[0.0, 0.0], [0.0, 5.0], [0.1, 10.0], [5.0, 10.1], [10.0, 10.0], [10.0, 0.0], [5.0, -0.1]];
+        let shapes = [vec![contour]];
+
+        let distance = shapes.as_slice().generalize(Tolerance::Distance(0.5));
+        assert_eq!(distance.len(), 1);
+        assert_eq!(distance[0][0].len(), 4);
+
+        let area = shapes.as_slice().generalize(Tolerance::Area(1.0));
+        assert_eq!(area.len(), 1);
+        assert_eq!(area[0][0].len(), 4);
+    }
+}
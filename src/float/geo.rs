@@ -0,0 +1,428 @@
+//! Integration with [`geo_types`](https://docs.rs/geo-types).
+//!
+//! `LineString`, `MultiLineString`, `Polygon` and `MultiPolygon` implement `OverlayResource`
+//! without copying, so they can be used with `FloatOverlay`, `SingleFloatOverlay`, `FloatClip`
+//! and `FloatSlice` directly. In this case rings are taken as stored (only the closing point is skipped),
+//! the points are `GeoCoord<T>`.
+//!
+//! The `GeoOverlay`, `GeoClip` and `GeoSlice` traits do the same operations but normalize ring
+//! orientation first (every interior is reversed if it has the same orientation as its exterior),
+//! and convert the result back into geo types with the OGC orientation: exterior counterclockwise,
+//! interiors clockwise.
+
+use alloc::vec::Vec;
+use core::slice;
+use geo_types::{Coord, CoordFloat, LineString, MultiLineString, MultiPolygon, Polygon};
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::{Paths, Shapes};
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::ShapeType;
+use crate::core::overlay_rule::OverlayRule;
+use crate::float::area::signed_area;
+use crate::float::overlay::FloatOverlay;
+use crate::float::source::resource::OverlayResource;
+use crate::float::string_overlay::FloatStringOverlay;
+use crate::string::clip::ClipRule;
+use crate::string::rule::StringRule;
+
+/// A `geo_types::Coord` that can be used as an overlay point.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoCoord<T: CoordFloat>(pub Coord<T>);
+
+impl<T: CoordFloat + FloatNumber> FloatPointCompatible<T> for GeoCoord<T> {
+    #[inline(always)]
+    fn from_xy(x: T, y: T) -> Self {
+        Self(Coord { x, y })
+    }
+
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.0.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.0.y
+    }
+}
+
+#[inline(always)]
+fn coords<T: CoordFloat>(line: &LineString<T>) -> &[GeoCoord<T>] {
+    // SAFETY: `GeoCoord<T>` is `repr(transparent)` over `Coord<T>`
+    unsafe { slice::from_raw_parts(line.0.as_ptr() as *const GeoCoord<T>, line.0.len()) }
+}
+
+/// Ring points without the closing one, the overlay expects open contours.
+#[inline]
+fn ring_coords<T: CoordFloat>(ring: &LineString<T>) -> &[GeoCoord<T>] {
+    let coords = coords(ring);
+    match coords {
+        [first, .., last] if first == last => &coords[..coords.len() - 1],
+        _ => coords,
+    }
+}
+
+pub struct GeoLinesIterator<'a, T: CoordFloat> {
+    lines: slice::Iter<'a, LineString<T>>,
+}
+
+impl<'a, T: CoordFloat> Iterator for GeoLinesIterator<'a, T> {
+    type Item = &'a [GeoCoord<T>];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines.next().map(coords)
+    }
+}
+
+pub struct GeoPolygonsIterator<'a, T: CoordFloat> {
+    polygons: &'a [Polygon<T>],
+    polygon_index: usize,
+    ring_index: usize,
+}
+
+impl<'a, T: CoordFloat> Iterator for GeoPolygonsIterator<'a, T> {
+    type Item = &'a [GeoCoord<T>];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(polygon) = self.polygons.get(self.polygon_index) {
+            let ring_index = self.ring_index;
+            if ring_index == 0 {
+                self.ring_index = 1;
+                return Some(ring_coords(polygon.exterior()));
+            }
+
+            if let Some(interior) = polygon.interiors().get(ring_index - 1) {
+                self.ring_index += 1;
+                return Some(ring_coords(interior));
+            }
+
+            self.polygon_index += 1;
+            self.ring_index = 0;
+        }
+
+        None
+    }
+}
+
+impl<T: CoordFloat + FloatNumber> OverlayResource<GeoCoord<T>, T> for LineString<T> {
+    type ResourceIter<'a> = GeoLinesIterator<'a, T>
+    where
+        T: 'a;
+
+    #[inline]
+    fn iter_paths(&self) -> Self::ResourceIter<'_> {
+        GeoLinesIterator { lines: slice::from_ref(self).iter() }
+    }
+}
+
+impl<T: CoordFloat + FloatNumber> OverlayResource<GeoCoord<T>, T> for MultiLineString<T> {
+    type ResourceIter<'a> = GeoLinesIterator<'a, T>
+    where
+        T: 'a;
+
+    #[inline]
+    fn iter_paths(&self) -> Self::ResourceIter<'_> {
+        GeoLinesIterator { lines: self.0.iter() }
+    }
+}
+
+impl<T: CoordFloat + FloatNumber> OverlayResource<GeoCoord<T>, T> for Polygon<T> {
+    type ResourceIter<'a> = GeoPolygonsIterator<'a, T>
+    where
+        T: 'a;
+
+    #[inline]
+    fn iter_paths(&self) -> Self::ResourceIter<'_> {
+        GeoPolygonsIterator { polygons: slice::from_ref(self), polygon_index: 0, ring_index: 0 }
+    }
+}
+
+impl<T: CoordFloat + FloatNumber> OverlayResource<GeoCoord<T>, T> for MultiPolygon<T> {
+    type ResourceIter<'a> = GeoPolygonsIterator<'a, T>
+    where
+        T: 'a;
+
+    #[inline]
+    fn iter_paths(&self) -> Self::ResourceIter<'_> {
+        GeoPolygonsIterator { polygons: &self.0, polygon_index: 0, ring_index: 0 }
+    }
+}
+
+/// Polygonal geo types: `Polygon` and `MultiPolygon`.
+pub trait GeoPolygonal<T: CoordFloat + FloatNumber>: OverlayResource<GeoCoord<T>, T> {
+    fn polygons(&self) -> &[Polygon<T>];
+}
+
+impl<T: CoordFloat + FloatNumber> GeoPolygonal<T> for Polygon<T> {
+    #[inline]
+    fn polygons(&self) -> &[Polygon<T>] {
+        slice::from_ref(self)
+    }
+}
+
+impl<T: CoordFloat + FloatNumber> GeoPolygonal<T> for MultiPolygon<T> {
+    #[inline]
+    fn polygons(&self) -> &[Polygon<T>] {
+        &self.0
+    }
+}
+
+/// Linear geo types: `LineString` and `MultiLineString`.
+pub trait GeoLinear<T: CoordFloat + FloatNumber>: OverlayResource<GeoCoord<T>, T> {}
+
+impl<T: CoordFloat + FloatNumber> GeoLinear<T> for LineString<T> {}
+
+impl<T: CoordFloat + FloatNumber> GeoLinear<T> for MultiLineString<T> {}
+
+/// Trait `GeoOverlay` mirrors `SingleFloatOverlay` for polygonal geo types.
+pub trait GeoOverlay<T: CoordFloat + FloatNumber> {
+    /// Applies a boolean operation between `self` and `other`.
+    /// - `other`: A `Polygon` or `MultiPolygon`.
+    /// - `overlay_rule`: The boolean operation rule to apply, such as union or intersection.
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    /// - Returns: A `MultiPolygon` with the exterior rings counterclockwise and interiors clockwise.
+    fn overlay_geo<R: GeoPolygonal<T>>(&self, other: &R, overlay_rule: OverlayRule, fill_rule: FillRule) -> MultiPolygon<T>;
+}
+
+impl<S, T> GeoOverlay<T> for S
+where
+    S: GeoPolygonal<T>,
+    T: CoordFloat + FloatNumber,
+{
+    fn overlay_geo<R: GeoPolygonal<T>>(&self, other: &R, overlay_rule: OverlayRule, fill_rule: FillRule) -> MultiPolygon<T> {
+        let iter = self.iter_paths().chain(other.iter_paths()).flatten();
+        let adapter = FloatPointAdapter::with_iter(iter);
+        let capacity = self.iter_paths().chain(other.iter_paths()).fold(0, |s, c| s + c.len());
+
+        let mut overlay = FloatOverlay::with_adapter(adapter, capacity);
+        for (polygons, shape_type) in [(self.polygons(), ShapeType::Subject), (other.polygons(), ShapeType::Clip)] {
+            for_each_oriented_ring(polygons, |ring| {
+                overlay.overlay.add_path_iter(ring.map(|p| overlay.adapter.float_to_int(p)), shape_type);
+            });
+        }
+
+        overlay.overlay(overlay_rule, fill_rule).to_multi_polygon()
+    }
+}
+
+/// Trait `GeoClip` mirrors `FloatClip` for linear geo types.
+pub trait GeoClip<T: CoordFloat + FloatNumber> {
+    /// Clips lines by a polygonal geometry.
+    /// - `shape`: A `Polygon` or `MultiPolygon`.
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    /// - `clip_rule`: Clip rule to determine how boundary and inversion settings affect the result.
+    fn clip_by_geo<R: GeoPolygonal<T>>(&self, shape: &R, fill_rule: FillRule, clip_rule: ClipRule) -> MultiLineString<T>;
+}
+
+impl<S, T> GeoClip<T> for S
+where
+    S: GeoLinear<T>,
+    T: CoordFloat + FloatNumber,
+{
+    fn clip_by_geo<R: GeoPolygonal<T>>(&self, shape: &R, fill_rule: FillRule, clip_rule: ClipRule) -> MultiLineString<T> {
+        string_overlay(shape, self)
+            .clip_string_lines_with_solver(fill_rule, clip_rule, Default::default())
+            .to_multi_line_string()
+    }
+}
+
+/// Trait `GeoSlice` mirrors `FloatSlice` for polygonal geo types.
+pub trait GeoSlice<T: CoordFloat + FloatNumber> {
+    /// Slices `self` by lines.
+    /// - `lines`: A `LineString` or `MultiLineString`.
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    fn slice_by_geo<R: GeoLinear<T>>(&self, lines: &R, fill_rule: FillRule) -> MultiPolygon<T>;
+}
+
+impl<S, T> GeoSlice<T> for S
+where
+    S: GeoPolygonal<T>,
+    T: CoordFloat + FloatNumber,
+{
+    fn slice_by_geo<R: GeoLinear<T>>(&self, lines: &R, fill_rule: FillRule) -> MultiPolygon<T> {
+        string_overlay(self, lines)
+            .into_graph(fill_rule)
+            .extract_shapes(StringRule::Slice)
+            .to_multi_polygon()
+    }
+}
+
+fn string_overlay<S, L, T>(shape: &S, lines: &L) -> FloatStringOverlay<GeoCoord<T>, T>
+where
+    S: GeoPolygonal<T>,
+    L: GeoLinear<T>,
+    T: CoordFloat + FloatNumber,
+{
+    let iter = shape.iter_paths().chain(lines.iter_paths()).flatten();
+    let adapter = FloatPointAdapter::with_iter(iter);
+    let capacity = shape.iter_paths().chain(lines.iter_paths()).fold(0, |s, c| s + c.len());
+
+    let mut overlay = FloatStringOverlay::with_adapter(adapter, capacity);
+    for_each_oriented_ring(shape.polygons(), |ring| {
+        overlay.overlay.add_shape_contour_iter(ring.map(|p| overlay.adapter.float_to_int(p)));
+    });
+
+    overlay.unsafe_add_string_lines(lines)
+}
+
+/// Calls `f` for every ring, interiors are reversed if they have the same orientation as the exterior.
+fn for_each_oriented_ring<T, F>(polygons: &[Polygon<T>], mut f: F)
+where
+    T: CoordFloat + FloatNumber,
+    F: FnMut(&mut dyn Iterator<Item=&GeoCoord<T>>),
+{
+    for polygon in polygons.iter() {
+        let exterior = ring_coords(polygon.exterior());
+        let is_exterior_positive = signed_area(exterior) >= 0.0;
+        f(&mut exterior.iter());
+
+        for interior in polygon.interiors().iter().map(ring_coords) {
+            if (signed_area(interior) >= 0.0) == is_exterior_positive {
+                f(&mut interior.iter().rev());
+            } else {
+                f(&mut interior.iter());
+            }
+        }
+    }
+}
+
+/// Converts overlay shapes into a `MultiPolygon`.
+pub trait ToMultiPolygon<T: CoordFloat> {
+    /// Returns a `MultiPolygon` with closed rings, the exterior counterclockwise and interiors clockwise.
+    fn to_multi_polygon(&self) -> MultiPolygon<T>;
+}
+
+impl<P, T> ToMultiPolygon<T> for Shapes<P>
+where
+    P: FloatPointCompatible<T>,
+    T: CoordFloat + FloatNumber,
+{
+    fn to_multi_polygon(&self) -> MultiPolygon<T> {
+        let polygons = self.iter()
+            .filter(|shape| !shape.is_empty())
+            .map(|shape| {
+                let exterior = ring(&shape[0], false);
+                let interiors = shape[1..].iter().map(|contour| ring(contour, true)).collect();
+                Polygon::new(exterior, interiors)
+            })
+            .collect();
+
+        MultiPolygon(polygons)
+    }
+}
+
+/// Converts overlay paths into a `MultiLineString`.
+pub trait ToMultiLineString<T: CoordFloat> {
+    fn to_multi_line_string(&self) -> MultiLineString<T>;
+}
+
+impl<P, T> ToMultiLineString<T> for Paths<P>
+where
+    P: FloatPointCompatible<T>,
+    T: CoordFloat + FloatNumber,
+{
+    fn to_multi_line_string(&self) -> MultiLineString<T> {
+        let lines = self.iter()
+            .map(|path| LineString(path.iter().map(|p| Coord { x: p.x(), y: p.y() }).collect()))
+            .collect();
+
+        MultiLineString(lines)
+    }
+}
+
+fn ring<P, T>(contour: &[P], is_hole: bool) -> LineString<T>
+where
+    P: FloatPointCompatible<T>,
+    T: CoordFloat + FloatNumber,
+{
+    let mut coords: Vec<Coord<T>> = contour.iter().map(|p| Coord { x: p.x(), y: p.y() }).collect();
+    let is_counterclockwise = signed_area(contour) > 0.0;
+    if is_counterclockwise == is_hole {
+        coords.reverse();
+    }
+
+    // `Polygon::new` closes the ring
+    LineString(coords)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use geo_types::{coord, line_string, polygon, LineString, MultiLineString, MultiPolygon, Polygon};
+    use crate::core::fill_rule::FillRule;
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::float::geo::{GeoClip, GeoOverlay, GeoSlice, ToMultiPolygon};
+    use crate::float::single::SingleFloatOverlay;
+    use crate::string::clip::ClipRule;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+        polygon![(x: x, y: y), (x: x + size, y: y), (x: x + size, y: y + size), (x: x, y: y + size)]
+    }
+
+    #[test]
+    fn test_resource() {
+        let a = square(0.0, 0.0, 2.0);
+        let b = square(1.0, 0.0, 2.0);
+
+        let shapes = a.overlay(&b, OverlayRule::Union, FillRule::NonZero);
+        assert_eq!(shapes.len(), 1);
+        assert_eq!(shapes[0].len(), 1);
+        assert_eq!(shapes[0][0].len(), 4);
+    }
+
+    #[test]
+    fn test_overlay_orientation() {
+        // both rings have the same orientation, the interior must still be a hole
+        let donut = Polygon::new(
+            square(0.0, 0.0, 4.0).exterior().clone(),
+            vec![square(1.0, 1.0, 2.0).exterior().clone()],
+        );
+        let right = MultiPolygon(vec![square(4.0, 0.0, 4.0)]);
+
+        let result = donut.overlay_geo(&right, OverlayRule::Union, FillRule::NonZero);
+        assert_eq!(result.0.len(), 1);
+
+        let polygon = &result.0[0];
+        assert_eq!(polygon.interiors().len(), 1);
+        assert!(polygon.exterior().is_closed());
+
+        let exterior: Vec<[f64; 2]> = polygon.exterior().0.iter().map(|c| [c.x, c.y]).collect();
+        let interior: Vec<[f64; 2]> = polygon.interiors()[0].0.iter().map(|c| [c.x, c.y]).collect();
+        assert!(super::signed_area::<[f64; 2], f64>(&exterior) > 0.0);
+        assert!(super::signed_area::<[f64; 2], f64>(&interior) < 0.0);
+    }
+
+    #[test]
+    fn test_clip() {
+        let line: LineString<f64> = line_string![(x: -1.0, y: 1.0), (x: 3.0, y: 1.0)];
+        let lines = MultiLineString(vec![line]);
+
+        let result = lines.clip_by_geo(&square(0.0, 0.0, 2.0), FillRule::NonZero, ClipRule { invert: false, boundary_included: false });
+        assert_eq!(result.0.len(), 1);
+        assert_eq!(result.0[0].0.len(), 2);
+        assert!(result.0[0].0.contains(&coord! { x: 0.0, y: 1.0 }));
+        assert!(result.0[0].0.contains(&coord! { x: 2.0, y: 1.0 }));
+    }
+
+    #[test]
+    fn test_slice() {
+        let line: LineString<f64> = line_string![(x: 1.0, y: -1.0), (x: 1.0, y: 3.0)];
+        let result = square(0.0, 0.0, 2.0).slice_by_geo(&line, FillRule::NonZero);
+        assert_eq!(result.0.len(), 2);
+    }
+
+    #[test]
+    fn test_to_multi_polygon() {
+        let shapes = vec![vec![vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]]];
+        let multi_polygon: MultiPolygon<f64> = shapes.to_multi_polygon();
+        let exterior = &multi_polygon.0[0].exterior().0;
+        assert_eq!(exterior.len(), 5);
+        assert_eq!(exterior[0], exterior[4]);
+        assert_eq!(exterior[1], coord! { x: 1.0, y: 1.0 });
+    }
+}
@@ -0,0 +1,77 @@
+//! `euclid::Point2D` points.
+
+use core::fmt::{self, Debug, Formatter};
+use euclid::Point2D;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use crate::float::interop::{impl_native_resources, PointWrapper};
+
+/// A `euclid::Point2D` that can be used as an overlay point, the unit `U` is kept.
+#[repr(transparent)]
+pub struct EuclidPoint2D<T, U>(pub Point2D<T, U>);
+
+// derive would require the unit to implement the traits as well
+impl<T: Copy, U> Clone for EuclidPoint2D<T, U> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Copy, U> Copy for EuclidPoint2D<T, U> {}
+
+impl<T: Debug, U> Debug for EuclidPoint2D<T, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EuclidPoint2D").field(&self.0.x).field(&self.0.y).finish()
+    }
+}
+
+impl<T: PartialEq, U> PartialEq for EuclidPoint2D<T, U> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+unsafe impl<T: Copy, U> PointWrapper for EuclidPoint2D<T, U> {
+    type Native = Point2D<T, U>;
+}
+
+impl<T: FloatNumber, U> FloatPointCompatible<T> for EuclidPoint2D<T, U> {
+    #[inline(always)]
+    fn from_xy(x: T, y: T) -> Self {
+        Self(Point2D::new(x, y))
+    }
+
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.0.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.0.y
+    }
+}
+
+impl_native_resources!(<T, U> EuclidPoint2D<T, U> => Point2D<T, U>, T where T: FloatNumber);
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use euclid::default::Point2D;
+    use crate::core::fill_rule::FillRule;
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::float::interop::IntoNative;
+    use crate::float::single::SingleFloatOverlay;
+
+    #[test]
+    fn test_overlay() {
+        let subj = vec![vec![vec![Point2D::new(0.0, 0.0), Point2D::new(0.0, 2.0), Point2D::new(2.0, 2.0), Point2D::new(2.0, 0.0)]]];
+        let clip = vec![Point2D::new(1.0, 0.0), Point2D::new(1.0, 2.0), Point2D::new(3.0, 2.0), Point2D::new(3.0, 0.0)];
+
+        let shapes: Vec<Vec<Vec<Point2D<f64>>>> = subj.overlay(&clip, OverlayRule::Difference, FillRule::NonZero).into_native();
+        assert_eq!(shapes, vec![vec![vec![Point2D::new(0.0, 0.0), Point2D::new(0.0, 2.0), Point2D::new(1.0, 2.0), Point2D::new(1.0, 0.0)]]]);
+    }
+}
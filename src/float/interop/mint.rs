@@ -0,0 +1,58 @@
+//! `mint::Point2` points.
+
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use mint::Point2;
+use crate::float::interop::{impl_native_resources, PointWrapper};
+
+/// A `mint::Point2` that can be used as an overlay point.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MintPoint2<T>(pub Point2<T>);
+
+unsafe impl<T: Copy> PointWrapper for MintPoint2<T> {
+    type Native = Point2<T>;
+}
+
+impl<T: FloatNumber> FloatPointCompatible<T> for MintPoint2<T> {
+    #[inline(always)]
+    fn from_xy(x: T, y: T) -> Self {
+        Self(Point2 { x, y })
+    }
+
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.0.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.0.y
+    }
+}
+
+impl_native_resources!(<T> MintPoint2<T> => Point2<T>, T where T: FloatNumber);
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use mint::Point2;
+    use crate::core::fill_rule::FillRule;
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::float::interop::IntoNative;
+    use crate::float::single::SingleFloatOverlay;
+
+    fn p(x: f64, y: f64) -> Point2<f64> {
+        Point2 { x, y }
+    }
+
+    #[test]
+    fn test_overlay() {
+        let subj = vec![p(0.0, 0.0), p(0.0, 2.0), p(2.0, 2.0), p(2.0, 0.0)];
+        let clip = vec![p(1.0, 0.0), p(1.0, 2.0), p(3.0, 2.0), p(3.0, 0.0)];
+
+        let shapes: Vec<Vec<Vec<Point2<f64>>>> = subj.overlay(&clip, OverlayRule::Union, FillRule::NonZero).into_native();
+        assert_eq!(shapes, vec![vec![vec![p(0.0, 0.0), p(0.0, 2.0), p(3.0, 2.0), p(3.0, 0.0)]]]);
+    }
+}
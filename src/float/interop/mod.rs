@@ -0,0 +1,147 @@
+//! Interop with point types of math libraries.
+//!
+//! `FloatPointCompatible` belongs to `i_float`, so the orphan rule forbids implementing it here for
+//! a point type of another crate. Every supported type gets a `repr(transparent)` wrapper instead:
+//!
+//! | feature    | native type             | wrapper               |
+//! |------------|-------------------------|-----------------------|
+//! | `mint`     | `mint::Point2<T>`       | `MintPoint2<T>`       |
+//! | `nalgebra` | `nalgebra::Point2<T>`   | `NalgebraPoint2<T>`   |
+//! | `euclid`   | `euclid::Point2D<T, U>` | `EuclidPoint2D<T, U>` |
+//!
+//! `glam::Vec2` and `glam::DVec2` need no wrapper: the `glam` feature enables the `i_float`
+//! implementation of `FloatPointCompatible` for them, so they work with the float API directly.
+//!
+//! Slices, `Vec`s and nested `Vec`s of a native type implement `OverlayResource` for its wrapper by
+//! reinterpreting the buffers in place, so they can be passed to `overlay`, `clip_by` and `slice_by`
+//! without copying. The result is built from wrappers, `IntoNative` converts it back, again without copying.
+//!
+//! ```ignore
+//! use mint::Point2;
+//! use i_overlay::core::fill_rule::FillRule;
+//! use i_overlay::core::overlay_rule::OverlayRule;
+//! use i_overlay::float::interop::IntoNative;
+//! use i_overlay::float::single::SingleFloatOverlay;
+//!
+//! let subj = vec![Point2 { x: 0.0, y: 0.0 }, Point2 { x: 0.0, y: 2.0 }, Point2 { x: 2.0, y: 2.0 }, Point2 { x: 2.0, y: 0.0 }];
+//! let clip = vec![Point2 { x: 1.0, y: 1.0 }, Point2 { x: 1.0, y: 3.0 }, Point2 { x: 3.0, y: 3.0 }, Point2 { x: 3.0, y: 1.0 }];
+//!
+//! let shapes: Vec<Vec<Vec<Point2<f64>>>> = subj.overlay(&clip, OverlayRule::Union, FillRule::NonZero).into_native();
+//! ```
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::slice;
+
+#[cfg(feature = "mint")]
+pub mod mint;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+#[cfg(feature = "euclid")]
+pub mod euclid;
+
+/// A point type that wraps a native point of another library.
+///
+/// # Safety
+/// The implementor must be `repr(transparent)` over `Native`.
+pub unsafe trait PointWrapper: Copy {
+    type Native;
+}
+
+#[inline(always)]
+fn cast_slice<W: PointWrapper>(slice: &[W::Native]) -> &[W] {
+    // SAFETY: `W` has the same layout as `W::Native`
+    unsafe { slice::from_raw_parts(slice.as_ptr() as *const W, slice.len()) }
+}
+
+/// Iterates over paths of native points as paths of wrappers.
+pub struct CastIterator<I, W> {
+    iter: I,
+    _phantom: PhantomData<W>,
+}
+
+impl<I, W> CastIterator<I, W> {
+    #[inline]
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, _phantom: PhantomData }
+    }
+}
+
+impl<'a, I, W> Iterator for CastIterator<I, W>
+where
+    I: Iterator<Item=&'a [W::Native]>,
+    W: PointWrapper + 'a,
+{
+    type Item = &'a [W];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(cast_slice)
+    }
+}
+
+/// Converts overlay results made of wrappers into native points.
+pub trait IntoNative {
+    type Output;
+
+    fn into_native(self) -> Self::Output;
+}
+
+impl<W: PointWrapper> IntoNative for Vec<W> {
+    type Output = Vec<W::Native>;
+
+    #[inline]
+    fn into_native(self) -> Self::Output {
+        let mut vec = ManuallyDrop::new(self);
+        // SAFETY: `W` has the same layout as `W::Native`, the buffer is taken over
+        unsafe { Vec::from_raw_parts(vec.as_mut_ptr() as *mut W::Native, vec.len(), vec.capacity()) }
+    }
+}
+
+impl<W: PointWrapper> IntoNative for Vec<Vec<W>> {
+    type Output = Vec<Vec<W::Native>>;
+
+    #[inline]
+    fn into_native(self) -> Self::Output {
+        self.into_iter().map(IntoNative::into_native).collect()
+    }
+}
+
+impl<W: PointWrapper> IntoNative for Vec<Vec<Vec<W>>> {
+    type Output = Vec<Vec<Vec<W::Native>>>;
+
+    #[inline]
+    fn into_native(self) -> Self::Output {
+        self.into_iter().map(IntoNative::into_native).collect()
+    }
+}
+
+/// Implements `OverlayResource` for slices, `Vec`s and nested `Vec`s of a native point type.
+macro_rules! impl_native_resources {
+    (<$($g:ident),*> $wrapper:ty => $native:ty, $t:ty $(where $($bound:tt)*)?) => {
+        impl_native_resources!(@impl <$($g),*> [$native], single SingleResourceIterator, $wrapper, $native, $t $(where $($bound)*)?);
+        impl_native_resources!(@impl <$($g),*> alloc::vec::Vec<$native>, single SingleResourceIterator, $wrapper, $native, $t $(where $($bound)*)?);
+        impl_native_resources!(@impl <$($g),*> [alloc::vec::Vec<$native>], double DoubleResourceIterator, $wrapper, $native, $t $(where $($bound)*)?);
+        impl_native_resources!(@impl <$($g),*> alloc::vec::Vec<alloc::vec::Vec<$native>>, double DoubleResourceIterator, $wrapper, $native, $t $(where $($bound)*)?);
+        impl_native_resources!(@impl <$($g),*> [alloc::vec::Vec<alloc::vec::Vec<$native>>], triple TripleResourceIterator, $wrapper, $native, $t $(where $($bound)*)?);
+        impl_native_resources!(@impl <$($g),*> alloc::vec::Vec<alloc::vec::Vec<alloc::vec::Vec<$native>>>, triple TripleResourceIterator, $wrapper, $native, $t $(where $($bound)*)?);
+    };
+    (@impl <$($g:ident),*> $source:ty, $m:ident $iter:ident, $wrapper:ty, $native:ty, $t:ty $(where $($bound:tt)*)?) => {
+        impl<$($g),*> $crate::float::source::resource::OverlayResource<$wrapper, $t> for $source
+        $(where $($bound)*)?
+        {
+            type ResourceIter<'a> = $crate::float::interop::CastIterator<$crate::float::source::$m::$iter<'a, $native>, $wrapper>
+            where
+                $wrapper: 'a,
+                Self: 'a;
+
+            #[inline]
+            fn iter_paths(&self) -> Self::ResourceIter<'_> {
+                $crate::float::interop::CastIterator::new($crate::float::source::$m::$iter::with_slice(self))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_native_resources;
@@ -0,0 +1,53 @@
+//! `nalgebra::Point2` points.
+
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use nalgebra::{Point2, Scalar};
+use crate::float::interop::{impl_native_resources, PointWrapper};
+
+/// A `nalgebra::Point2` that can be used as an overlay point.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NalgebraPoint2<T: Scalar>(pub Point2<T>);
+
+unsafe impl<T: Scalar + Copy> PointWrapper for NalgebraPoint2<T> {
+    type Native = Point2<T>;
+}
+
+impl<T: Scalar + FloatNumber> FloatPointCompatible<T> for NalgebraPoint2<T> {
+    #[inline(always)]
+    fn from_xy(x: T, y: T) -> Self {
+        Self(Point2::new(x, y))
+    }
+
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.0.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.0.y
+    }
+}
+
+impl_native_resources!(<T> NalgebraPoint2<T> => Point2<T>, T where T: Scalar + FloatNumber);
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use nalgebra::Point2;
+    use crate::core::fill_rule::FillRule;
+    use crate::float::interop::IntoNative;
+    use crate::float::slice::FloatSlice;
+
+    #[test]
+    fn test_slice() {
+        let shape = vec![Point2::new(0.0f32, 0.0), Point2::new(0.0, 2.0), Point2::new(2.0, 2.0), Point2::new(2.0, 0.0)];
+        let line = vec![Point2::new(1.0f32, -1.0), Point2::new(1.0, 3.0)];
+
+        let shapes: Vec<Vec<Vec<Point2<f32>>>> = shape.slice_by(&line, FillRule::NonZero).into_native();
+        assert_eq!(shapes.len(), 2);
+    }
+}
@@ -0,0 +1,96 @@
+//! Float counterpart of [`crate::string::network`].
+
+use alloc::vec::Vec;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::Paths;
+use crate::core::overlay::ShapeType;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::float::source::resource::OverlayResource;
+use crate::string::network::LineNetwork;
+
+/// Collects float subject and clip lines to overlay them with each other.
+#[derive(Clone)]
+pub struct FloatLineNetwork<P: FloatPointCompatible<T>, T: FloatNumber> {
+    network: LineNetwork,
+    adapter: FloatPointAdapter<P, T>,
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatLineNetwork<P, T> {
+    /// Creates a new `FloatLineNetwork` with subject and clip lines.
+    /// - `subj`: A `OverlayResource` with the subject open paths.
+    /// - `clip`: A `OverlayResource` with the clip open paths.
+    pub fn with_lines<R0, R1>(subj: &R0, clip: &R1) -> Self
+    where
+        R0: OverlayResource<P, T> + ?Sized,
+        R1: OverlayResource<P, T> + ?Sized,
+    {
+        let iter = subj.iter_paths().chain(clip.iter_paths()).flatten();
+        let adapter = FloatPointAdapter::with_iter(iter);
+        let capacity = subj.iter_paths().chain(clip.iter_paths()).fold(0, |s, path| s + path.len());
+        let mut network = LineNetwork::new(capacity);
+
+        for path in subj.iter_paths() {
+            let path: Vec<_> = path.iter().map(|p| adapter.float_to_int(p)).collect();
+            network.add_path(&path, ShapeType::Subject);
+        }
+        for path in clip.iter_paths() {
+            let path: Vec<_> = path.iter().map(|p| adapter.float_to_int(p)).collect();
+            network.add_path(&path, ShapeType::Clip);
+        }
+
+        Self { network, adapter }
+    }
+
+    /// Overlays the lines with the default solver.
+    /// - `overlay_rule`: The operation applied to the lines, `Union` dissolves both sets into one network.
+    /// - `join_chains`: If `true`, pieces meeting at a node of degree 2 are joined into one path.
+    #[inline]
+    pub fn overlay(self, overlay_rule: OverlayRule, join_chains: bool) -> Paths<P> {
+        self.overlay_with_solver(overlay_rule, join_chains, Default::default())
+    }
+
+    /// Overlays the lines.
+    /// - `overlay_rule`: The operation applied to the lines, `Union` dissolves both sets into one network.
+    /// - `join_chains`: If `true`, pieces meeting at a node of degree 2 are joined into one path.
+    /// - `solver`: Type of solver to use.
+    pub fn overlay_with_solver(self, overlay_rule: OverlayRule, join_chains: bool, solver: Solver) -> Paths<P> {
+        let paths = self.network.overlay_with_solver(overlay_rule, join_chains, solver);
+        let adapter = self.adapter;
+
+        paths.iter()
+            .map(|path| path.iter().map(|p| adapter.int_to_float(p)).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::float::line_network::FloatLineNetwork;
+
+    #[test]
+    fn test_union() {
+        let subj = vec![vec![[0.0, 0.0], [4.0, 0.0]]];
+        let clip = vec![vec![[2.0, -2.0], [2.0, 2.0]]];
+
+        let noded = FloatLineNetwork::with_lines(&subj, &clip).overlay(OverlayRule::Union, false);
+        assert_eq!(noded.len(), 4);
+
+        let joined = FloatLineNetwork::with_lines(&subj, &clip).overlay(OverlayRule::Union, true);
+        assert_eq!(joined.len(), 4);
+        assert!(joined.iter().all(|path| path.contains(&[2.0, 0.0])));
+    }
+
+    #[test]
+    fn test_intersect() {
+        let subj = vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0]];
+        let clip = vec![[2.0, 0.0], [4.0, 0.0], [4.0, 2.0], [6.0, 2.0]];
+
+        let result = FloatLineNetwork::with_lines(&subj, &clip).overlay(OverlayRule::Intersect, true);
+        assert_eq!(result, vec![vec![[2.0, 0.0], [4.0, 0.0], [4.0, 2.0]]]);
+    }
+}
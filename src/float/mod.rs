@@ -1,10 +1,30 @@
 pub mod overlay;
 pub mod graph;
 pub mod simplify;
+pub mod generalize;
+pub mod coverage;
+pub mod cleanup;
+pub mod partition;
+pub mod coverage_check;
+pub mod topology;
+pub mod dissolve;
+pub mod csg;
+pub mod canonical;
+pub mod validation;
+pub mod noding;
+pub mod line_network;
 pub mod string_overlay;
 pub mod string_graph;
 pub mod slice;
 pub mod clip;
 pub mod single;
 pub mod source;
-pub mod filter;
\ No newline at end of file
+pub mod filter;
+
+pub(crate) mod area;
+
+#[cfg(feature = "geo-types")]
+pub mod geo;
+
+#[cfg(any(feature = "mint", feature = "nalgebra", feature = "euclid"))]
+pub mod interop;
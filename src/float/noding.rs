@@ -0,0 +1,98 @@
+//! Float counterpart of [`crate::core::noding`].
+
+use alloc::vec::Vec;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use crate::core::noding::{NodedSegments, Noder};
+use crate::core::solver::Solver;
+use crate::float::source::resource::OverlayResource;
+
+/// Collects float segments to split them at their intersections.
+#[derive(Clone)]
+pub struct FloatNoder<P: FloatPointCompatible<T>, T: FloatNumber> {
+    noder: Noder,
+    adapter: FloatPointAdapter<P, T>,
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatNoder<P, T> {
+    /// Creates a new `FloatNoder` from separate segments.
+    pub fn with_segments(segments: &[[P; 2]]) -> Self {
+        let adapter = FloatPointAdapter::with_iter(segments.iter().flatten());
+        let mut noder = Noder::new(segments.len());
+        for [a, b] in segments.iter() {
+            noder.add_segment(adapter.float_to_int(a), adapter.float_to_int(b));
+        }
+
+        Self { noder, adapter }
+    }
+
+    /// Creates a new `FloatNoder` from paths.
+    /// - `paths`: A `OverlayResource` with the paths, every pair of consecutive points is a segment.
+    /// - `is_closed`: If `true`, the last point of every path is also connected to the first one.
+    pub fn with_paths<R: OverlayResource<P, T> + ?Sized>(paths: &R, is_closed: bool) -> Self {
+        let adapter = FloatPointAdapter::with_iter(paths.iter_paths().flatten());
+        let capacity = paths.iter_paths().fold(0, |s, path| s + path.len());
+        let mut noder = Noder::new(capacity);
+        for path in paths.iter_paths() {
+            let path: Vec<_> = path.iter().map(|p| adapter.float_to_int(p)).collect();
+            noder.add_path(&path, is_closed);
+        }
+
+        Self { noder, adapter }
+    }
+
+    /// Splits the segments with the default solver.
+    #[inline]
+    pub fn node(self) -> NodedSegments<P> {
+        self.node_with_solver(Default::default())
+    }
+
+    /// Splits the segments.
+    /// - `solver`: Type of solver to use.
+    pub fn node_with_solver(self, solver: Solver) -> NodedSegments<P> {
+        let result = self.noder.node_with_solver(solver);
+        let adapter = self.adapter;
+
+        NodedSegments {
+            segments: result.segments.iter().map(|s| s.map(|p| adapter.int_to_float(&p))).collect(),
+            intersections: result.intersections.iter().map(|p| adapter.int_to_float(p)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use crate::float::noding::FloatNoder;
+
+    #[test]
+    fn test_segments() {
+        let segments = [
+            [[0.0, 0.0], [4.0, 4.0]],
+            [[0.0, 4.0], [4.0, 0.0]],
+            [[-1.0, 1.0], [5.0, 1.0]],
+        ];
+
+        let result = FloatNoder::with_segments(&segments).node();
+        assert_eq!(result.intersections, vec![[1.0, 1.0], [2.0, 2.0], [3.0, 1.0]]);
+        assert_eq!(result.segments.len(), 9);
+    }
+
+    #[test]
+    fn test_paths() {
+        let paths = vec![
+            vec![[0.0, 0.0], [0.0, 2.0], [2.0, 2.0], [2.0, 0.0]],
+            vec![[1.0, -1.0], [1.0, 3.0]],
+        ];
+
+        let closed = FloatNoder::with_paths(&paths, true).node();
+        assert_eq!(closed.intersections, vec![[1.0, 0.0], [1.0, 2.0]]);
+        assert_eq!(closed.segments.len(), 9);
+
+        // the bottom edge is not a part of the open path
+        let open = FloatNoder::with_paths(&paths, false).node();
+        assert_eq!(open.intersections, vec![[1.0, 2.0]]);
+        assert_eq!(open.segments.len(), 6);
+    }
+}
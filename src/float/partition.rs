@@ -0,0 +1,122 @@
+//! Float counterpart of [`crate::core::partition`].
+
+use alloc::vec::Vec;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::float::adapter::ShapeToFloat;
+use crate::core::fill_rule::FillRule;
+use crate::core::partition::{Face, Partition};
+use crate::core::solver::Solver;
+use crate::float::graph::FloatOverlayGraph;
+use crate::float::source::resource::OverlayResource;
+
+/// Collects float contours labeled with a group id to split the plane into faces.
+#[derive(Clone)]
+pub struct FloatPartition<P: FloatPointCompatible<T>, T: FloatNumber> {
+    partition: Partition,
+    adapter: FloatPointAdapter<P, T>,
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatPartition<P, T> {
+    /// Constructs a new `FloatPartition` with a pre-configured adapter.
+    /// - `adapter`: A `FloatPointAdapter` instance responsible for coordinate conversion between
+    ///   float and integer values.
+    /// - `capacity`: The expected number of edges.
+    #[inline]
+    pub fn with_adapter(adapter: FloatPointAdapter<P, T>, capacity: usize) -> Self {
+        Self { partition: Partition::new(capacity), adapter }
+    }
+
+    /// Creates a new `FloatPartition` from groups, the id of a group is its index.
+    /// - `groups`: The `OverlayResource` of every group, it can be a `Contour`, `Contours` or `Shapes`.
+    pub fn with_groups<R: OverlayResource<P, T> + ?Sized>(groups: &[&R]) -> Self {
+        let iter = groups.iter().flat_map(|group| group.iter_paths()).flatten();
+        let adapter = FloatPointAdapter::with_iter(iter);
+        let capacity = groups.iter().flat_map(|group| group.iter_paths()).fold(0, |s, c| s + c.len());
+
+        let mut partition = Self::with_adapter(adapter, capacity);
+        for (id, group) in groups.iter().enumerate() {
+            partition = partition.unsafe_add_source(*group, id);
+        }
+        partition
+    }
+
+    /// Adds the contours of a resource to a group.
+    /// - `resource`: A `OverlayResource`, it can be a `Contour`, `Contours` or `Shapes`.
+    /// - `group`: The id of the group.
+    /// - **Safety**: Marked `unsafe` because it assumes the paths are fully contained within the bounding box of the adapter.
+    #[inline]
+    pub fn unsafe_add_source<R: OverlayResource<P, T> + ?Sized>(mut self, resource: &R, group: usize) -> Self {
+        for contour in resource.iter_paths() {
+            self = self.unsafe_add_contour(contour, group);
+        }
+        self
+    }
+
+    /// Adds a closed path to a group.
+    /// - `contour`: A contour representing a closed path.
+    /// - `group`: The id of the group.
+    /// - **Safety**: Marked `unsafe` because it assumes the path is fully contained within the bounding box of the adapter.
+    #[inline]
+    pub fn unsafe_add_contour(mut self, contour: &[P], group: usize) -> Self {
+        let contour: Vec<_> = contour.iter().map(|p| self.adapter.float_to_int(p)).collect();
+        self.partition.add_contour(&contour, group);
+        self
+    }
+
+    /// Splits the plane into faces with the default solver.
+    /// - `fill_rule`: The fill rule applied to the winding number of every group separately.
+    /// # Returns
+    /// Every face covered by at least one group, sorted by membership.
+    #[inline]
+    pub fn into_faces(self, fill_rule: FillRule) -> Vec<Face<P>> {
+        self.into_faces_with_solver(fill_rule, Default::default())
+    }
+
+    /// Splits the plane into faces.
+    /// - `fill_rule`: The fill rule applied to the winding number of every group separately.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// Every face covered by at least one group, sorted by membership.
+    pub fn into_faces_with_solver(self, fill_rule: FillRule, solver: Solver) -> Vec<Face<P>> {
+        let adapter = self.adapter;
+        self.partition.into_faces_with_solver(fill_rule, solver).into_iter()
+            .map(|face| Face { shape: face.shape.to_float(&adapter), membership: face.membership })
+            .collect()
+    }
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatOverlayGraph<P, T> {
+    /// Extracts every face covered by the subject or the clip, labeled with its membership:
+    /// `[0]` is covered only by the subject, `[1]` only by the clip and `[0, 1]` by both.
+    /// # Returns
+    /// The faces sorted by membership.
+    pub fn extract_faces(&self) -> Vec<Face<P>> {
+        self.graph.extract_faces().into_iter()
+            .map(|face| Face { shape: face.shape.to_float(&self.adapter), membership: face.membership })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use crate::core::fill_rule::FillRule;
+    use crate::float::overlay::FloatOverlay;
+    use crate::float::partition::FloatPartition;
+
+    #[test]
+    fn test_two_squares() {
+        let subj = vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]];
+        let clip = vec![[0.5, 0.5], [0.5, 1.5], [1.5, 1.5], [1.5, 0.5]];
+
+        let faces = FloatPartition::with_groups(&[&subj, &clip]).into_faces(FillRule::NonZero);
+        assert_eq!(faces.len(), 3);
+        assert_eq!(faces[1].membership, vec![0, 1]);
+        assert_eq!(faces[1].shape[0].len(), 4);
+
+        let graph_faces = FloatOverlay::with_subj_and_clip(&subj, &clip).into_graph(FillRule::NonZero).extract_faces();
+        assert_eq!(graph_faces, faces);
+    }
+}
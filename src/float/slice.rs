@@ -1,11 +1,13 @@
 use i_float::float::compatible::FloatPointCompatible;
 use i_float::float::number::FloatNumber;
-use i_shape::base::data::Shapes;
+use alloc::vec::Vec;
+use i_shape::base::data::{Shape, Shapes};
 use crate::core::fill_rule::FillRule;
 use crate::core::solver::Solver;
 use crate::float::filter::ContourFilter;
 use crate::float::source::resource::OverlayResource;
 use crate::float::string_overlay::FloatStringOverlay;
+use crate::string::label::LabeledShape;
 use crate::string::rule::StringRule;
 
 /// The `FloatSlice` trait provides methods to slice geometric shapes using a given path or set of paths,
@@ -43,6 +45,14 @@ where
     ///
     /// Returns a `Shapes<P>` collection representing the sliced geometry.
     fn slice_by_with_filter_and_solver(&self, resource: &R, fill_rule: FillRule, filter: ContourFilter<T>, solver: Solver) -> Shapes<P>;
+
+    /// Slices the current shapes by string lines and labels every piece with its side of every line.
+    ///
+    /// - `resource`: A string lines, `sides` of a piece follow the order of its paths.
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    ///
+    /// Returns a `LabeledShape` for every piece of the sliced geometry.
+    fn slice_by_labeled(&self, resource: &R, fill_rule: FillRule) -> Vec<LabeledShape<Shape<P>>>;
 }
 
 
@@ -66,13 +76,21 @@ where
             .into_graph_with_solver(fill_rule, solver)
             .extract_shapes_with_filter(StringRule::Slice, filter)
     }
+
+    #[inline]
+    fn slice_by_labeled(&self, resource: &R0, fill_rule: FillRule) -> Vec<LabeledShape<Shape<P>>> {
+        FloatStringOverlay::with_shape_and_string(self, resource).slice_labeled(fill_rule)
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
     use crate::core::fill_rule::FillRule;
     use crate::float::simplify::SimplifyShape;
+    use crate::float::slice::FloatSlice;
+    use crate::string::label::CutSide;
 
     #[test]
     fn test_contour_slice() {
@@ -95,4 +113,28 @@ mod tests {
         assert_eq!(shapes[0].len(), 1);
         assert_eq!(shapes[0][0].len(), 4);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_labeled_slice() {
+        let rect = vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]];
+        let lines = vec![
+            vec![[0.25, -1.0], [0.25, 2.0]],
+            vec![[0.75, 2.0], [0.75, -1.0]],
+        ];
+
+        let pieces = rect.slice_by_labeled(&lines, FillRule::NonZero);
+        assert_eq!(pieces.len(), 3);
+
+        for piece in pieces.iter() {
+            let min_x = piece.shape[0].iter().fold(f64::MAX, |m, p| m.min(p[0]));
+            let expected = if min_x < 0.1 {
+                vec![CutSide::Left, CutSide::Untouched]
+            } else if min_x < 0.5 {
+                vec![CutSide::Right, CutSide::Right]
+            } else {
+                vec![CutSide::Untouched, CutSide::Left]
+            };
+            assert_eq!(piece.sides, expected);
+        }
+    }
+}
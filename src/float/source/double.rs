@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use i_float::float::compatible::FloatPointCompatible;
 use i_float::float::number::FloatNumber;
 use crate::float::source::resource::OverlayResource;
@@ -9,7 +10,7 @@ pub struct DoubleResourceIterator<'a, P> {
 
 impl<'a, P> DoubleResourceIterator<'a, P> {
     #[inline]
-    fn with_slice(slice: &'a [Vec<P>]) -> Self {
+    pub(crate) fn with_slice(slice: &'a [Vec<P>]) -> Self {
         Self { slice, index: 0 }
     }
 }
@@ -96,6 +97,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
     use crate::float::source::resource::OverlayResource;
 
     #[test]
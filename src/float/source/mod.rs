@@ -1,4 +1,4 @@
 pub mod resource;
-mod single;
-mod double;
-mod triple;
\ No newline at end of file
+pub(crate) mod single;
+pub(crate) mod double;
+pub(crate) mod triple;
\ No newline at end of file
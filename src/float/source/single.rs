@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use i_float::float::compatible::FloatPointCompatible;
 use i_float::float::number::FloatNumber;
 use crate::float::source::resource::OverlayResource;
@@ -9,7 +10,7 @@ pub struct SingleResourceIterator<'a, P> {
 
 impl<'a, P> SingleResourceIterator<'a, P> {
     #[inline]
-    fn with_slice(slice: &'a [P]) -> Self {
+    pub(crate) fn with_slice(slice: &'a [P]) -> Self {
         Self { slice, finished: false }
     }
 }
@@ -94,6 +95,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
     use crate::float::source::resource::OverlayResource;
 
     #[test]
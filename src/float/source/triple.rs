@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use i_float::float::compatible::FloatPointCompatible;
 use i_float::float::number::FloatNumber;
 use crate::float::source::resource::OverlayResource;
@@ -10,7 +11,7 @@ pub struct TripleResourceIterator<'a, P> {
 
 impl<'a, P> TripleResourceIterator<'a, P> {
     #[inline]
-    fn with_slice(slice: &'a [Vec<Vec<P>>]) -> Self {
+    pub(crate) fn with_slice(slice: &'a [Vec<Vec<P>>]) -> Self {
         Self { slice, i: 0, j: 0 }
     }
 }
@@ -86,6 +87,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
     use crate::float::source::resource::OverlayResource;
 
     #[test]
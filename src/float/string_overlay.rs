@@ -1,13 +1,17 @@
+use alloc::vec::Vec;
 use i_float::adapter::FloatPointAdapter;
 use i_float::float::compatible::FloatPointCompatible;
 use i_float::float::number::FloatNumber;
-use i_shape::base::data::Paths;
+use i_shape::base::data::{Paths, Shape};
 use i_shape::float::adapter::ShapeToFloat;
 use crate::core::fill_rule::FillRule;
 use crate::core::solver::Solver;
 use crate::float::source::resource::OverlayResource;
 use crate::float::string_graph::FloatStringGraph;
+use crate::string::classify::ClassifiedPath;
 use crate::string::clip::ClipRule;
+use crate::string::label::LabeledShape;
+use crate::string::source::{SourcePiece, SourcePieces};
 use crate::string::overlay::StringOverlay;
 
 /// The `FloatStringOverlay` struct is a builder for overlaying geometric shapes by converting
@@ -103,12 +107,19 @@ impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatStringOverlay<P, T> {
     /// - **Safety**: Marked `unsafe` because it assumes each path is fully contained within the bounding box.
     #[inline]
     pub fn unsafe_add_string_line(mut self, path: &[P]) -> Self {
-        for w in path.windows(2) {
-            let a = self.adapter.float_to_int(&w[0]);
-            let b = self.adapter.float_to_int(&w[1]);
-            self.overlay.add_string_line([a, b]);
-        }
+        let adapter = &self.adapter;
+        self.overlay.add_string_path_iter(path.iter().map(|p| adapter.float_to_int(p)));
+        self
+    }
 
+    /// Adds an open string line path to the overlay and tags it with an id.
+    /// - `path`: A path representing a string line.
+    /// - `id`: The id of the path, the paths added without an id get an id greater than every id used before them.
+    /// - **Safety**: Marked `unsafe` because it assumes each path is fully contained within the bounding box.
+    #[inline]
+    pub fn unsafe_add_string_line_with_id(mut self, path: &[P], id: usize) -> Self {
+        let adapter = &self.adapter;
+        self.overlay.add_string_path_iter_with_id(path.iter().map(|p| adapter.float_to_int(p)), id);
         self
     }
 
@@ -144,4 +155,91 @@ impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatStringOverlay<P, T> {
         let paths = self.overlay.clip_string_lines_with_solver(fill_rule, clip_rule, solver);
         paths.to_float(&self.adapter)
     }
-}
\ No newline at end of file
+
+    /// Clips the string lines and returns the inside, outside and boundary parts together.
+    ///
+    /// ### Parameters:
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    /// - Returns: The parts tagged with their class and the index of their string path, sorted by the index and by class.
+    #[inline]
+    pub fn clip_string_lines_classified(self, fill_rule: FillRule) -> Vec<ClassifiedPath<P>> {
+        self.clip_string_lines_classified_with_solver(fill_rule, Default::default())
+    }
+
+    /// Clips the string lines and returns the inside, outside and boundary parts together.
+    ///
+    /// ### Parameters:
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    /// - `solver`: Type of solver to use.
+    /// - Returns: The parts tagged with their class and the index of their string path, sorted by the index and by class.
+    pub fn clip_string_lines_classified_with_solver(self, fill_rule: FillRule, solver: Solver) -> Vec<ClassifiedPath<P>> {
+        let parts = self.overlay.clip_string_lines_classified_with_solver(fill_rule, solver);
+        let adapter = self.adapter;
+        parts.into_iter()
+            .map(|part| ClassifiedPath {
+                path: part.path.iter().map(|p| adapter.int_to_float(p)).collect(),
+                class: part.class,
+                index: part.index,
+            })
+            .collect()
+    }
+
+    /// Clips the string lines and groups the result by the id of the string paths.
+    ///
+    /// ### Parameters:
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    /// - `clip_rule`: Clip rule to determine how the boundary and inversion settings affect the result.
+    /// - Returns: The parts with their range along the source path, grouped by id and sorted by range.
+    #[inline]
+    pub fn clip_string_lines_by_source(self, fill_rule: FillRule, clip_rule: ClipRule) -> Vec<SourcePieces<P, T>> {
+        self.clip_string_lines_by_source_with_solver(fill_rule, clip_rule, Default::default())
+    }
+
+    /// Clips the string lines and groups the result by the id of the string paths.
+    ///
+    /// ### Parameters:
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    /// - `clip_rule`: Clip rule to determine how the boundary and inversion settings affect the result.
+    /// - `solver`: Type of solver to use.
+    /// - Returns: The parts with their range along the source path, grouped by id and sorted by range.
+    pub fn clip_string_lines_by_source_with_solver(self, fill_rule: FillRule, clip_rule: ClipRule, solver: Solver) -> Vec<SourcePieces<P, T>> {
+        let groups = self.overlay.clip_string_lines_by_source_with_solver(fill_rule, clip_rule, solver);
+        let adapter = self.adapter;
+        let scale = adapter.inv_scale;
+        groups.into_iter()
+            .map(|group| SourcePieces {
+                id: group.id,
+                pieces: group.pieces.into_iter()
+                    .map(|piece| SourcePiece {
+                        path: piece.path.iter().map(|p| adapter.int_to_float(p)).collect(),
+                        range: piece.range.map(|t| T::from_float(t) * scale),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Slices the shapes by the string paths and labels every piece with its side of every path.
+    ///
+    /// ### Parameters:
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    /// - Returns: The pieces, `sides` of a piece follow the order the string paths were added in.
+    #[inline]
+    pub fn slice_labeled(self, fill_rule: FillRule) -> Vec<LabeledShape<Shape<P>>> {
+        self.slice_labeled_with_solver(fill_rule, Default::default())
+    }
+
+    /// Slices the shapes by the string paths and labels every piece with its side of every path.
+    ///
+    /// ### Parameters:
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    /// - `solver`: Type of solver to use.
+    /// - Returns: The pieces, `sides` of a piece follow the order the string paths were added in.
+    pub fn slice_labeled_with_solver(self, fill_rule: FillRule, solver: Solver) -> Vec<LabeledShape<Shape<P>>> {
+        let pieces = self.overlay.slice_labeled_with_min_area_and_solver(fill_rule, 0, solver);
+        let adapter = self.adapter;
+        pieces.into_iter()
+            .map(|piece| LabeledShape { shape: piece.shape.to_float(&adapter), sides: piece.sides })
+            .collect()
+    }
+}
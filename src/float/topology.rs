@@ -0,0 +1,67 @@
+//! Float counterpart of [`crate::core::topology`].
+
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::Shapes;
+use i_shape::float::adapter::ShapesToFloat;
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::core::topology::TopologyPolicy;
+use crate::float::graph::FloatOverlayGraph;
+use crate::float::overlay::FloatOverlay;
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatOverlay<P, T> {
+    /// Executes a single Boolean operation and returns the shapes with the rings required by the policy.
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    /// - `policy`: The policy for the rings.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The shapes, outer contours are clockwise and holes are counterclockwise.
+    #[inline]
+    pub fn overlay_with_policy_and_solver(self, overlay_rule: OverlayRule, fill_rule: FillRule, policy: TopologyPolicy, solver: Solver) -> Shapes<P> {
+        self.overlay
+            .overlay_with_policy_and_solver(overlay_rule, fill_rule, policy, solver)
+            .to_float(&self.adapter)
+    }
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatOverlayGraph<P, T> {
+    /// Extracts shapes from the overlay graph with the rings required by the policy.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph.
+    /// - `policy`: The policy for the rings.
+    /// # Returns
+    /// The shapes, outer contours are clockwise and holes are counterclockwise.
+    #[inline]
+    pub fn extract_shapes_with_policy(&self, overlay_rule: OverlayRule, policy: TopologyPolicy) -> Shapes<P> {
+        self.graph
+            .extract_shapes_with_policy(overlay_rule, policy)
+            .to_float(&self.adapter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use crate::core::fill_rule::FillRule;
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::core::topology::TopologyPolicy;
+    use crate::float::overlay::FloatOverlay;
+
+    #[test]
+    fn test_hole_touching_outer() {
+        let square = vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]];
+        let triangle = vec![[0.5, 1.0], [0.7, 0.5], [0.3, 0.5]];
+
+        let graph = FloatOverlay::with_subj_and_clip(&square, &triangle).into_graph(FillRule::NonZero);
+
+        let minimal = graph.extract_shapes_with_policy(OverlayRule::Difference, TopologyPolicy::MinimalRings);
+        assert_eq!(minimal, graph.extract_shapes(OverlayRule::Difference));
+
+        let ogc = graph.extract_shapes_with_policy(OverlayRule::Difference, TopologyPolicy::OgcValid);
+        assert_eq!(ogc.len(), 1);
+        assert_eq!(ogc[0].len(), 2);
+        assert_eq!(ogc[0][1].len(), 3);
+    }
+}
@@ -0,0 +1,119 @@
+//! Float counterpart of [`crate::core::validation`].
+//!
+//! The geometry is converted to the integer grid the overlay works on, so points closer than
+//! the grid step are reported as the same point.
+
+use alloc::vec::Vec;
+use i_float::adapter::FloatPointAdapter;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_float::int::point::IntPoint;
+use i_shape::base::data::{Contour, Shape};
+use crate::core::validation::{Validate, ValidationReport};
+
+/// Trait `FloatValidate` reports problems of float geometry without changing it.
+///
+/// - `[P]` is validated as a single contour.
+/// - `[Contour<P>]` is validated as independent contours.
+/// - `[Shape<P>]` is validated as shapes, holes are also checked for the orientation opposite to their outer contour.
+pub trait FloatValidate<P, T: FloatNumber> {
+    fn validate(&self) -> ValidationReport<P>;
+}
+
+impl<P, T> FloatValidate<P, T> for [P]
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    fn validate(&self) -> ValidationReport<P> {
+        let adapter = FloatPointAdapter::with_iter(self.iter());
+        let contour = to_int(&adapter, self);
+        to_float(&adapter, contour.validate())
+    }
+}
+
+impl<P, T> FloatValidate<P, T> for [Contour<P>]
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    fn validate(&self) -> ValidationReport<P> {
+        let adapter = FloatPointAdapter::with_iter(self.iter().flatten());
+        let contours: Vec<_> = self.iter().map(|contour| to_int(&adapter, contour)).collect();
+        to_float(&adapter, contours.validate())
+    }
+}
+
+impl<P, T> FloatValidate<P, T> for [Shape<P>]
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    fn validate(&self) -> ValidationReport<P> {
+        let adapter = FloatPointAdapter::with_iter(self.iter().flatten().flatten());
+        let shapes: Vec<Vec<_>> = self.iter()
+            .map(|shape| shape.iter().map(|contour| to_int(&adapter, contour)).collect())
+            .collect();
+        to_float(&adapter, shapes.validate())
+    }
+}
+
+#[inline]
+fn to_int<P, T>(adapter: &FloatPointAdapter<P, T>, contour: &[P]) -> Vec<IntPoint>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    contour.iter().map(|p| adapter.float_to_int(p)).collect()
+}
+
+#[inline]
+fn to_float<P, T>(adapter: &FloatPointAdapter<P, T>, report: ValidationReport) -> ValidationReport<P>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let issues = report.issues.into_iter()
+        .map(|issue| issue.map_points(|p| adapter.int_to_float(&p)))
+        .collect();
+
+    ValidationReport { issues }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use crate::core::validation::{ContourId, EdgeId, Issue};
+    use crate::float::validation::FloatValidate;
+
+    #[test]
+    fn test_crossing() {
+        let bow_tie = [[0.0, 0.0], [1.0, 1.0], [1.0, 0.0], [0.0, 1.0f64]];
+        let report = bow_tie.validate();
+
+        assert_eq!(report.issues.len(), 1);
+        let Issue::Crossing { point, edges } = report.issues[0] else { panic!("expected crossing") };
+        let range = 0.5 - 1e-6..0.5 + 1e-6;
+        assert!(range.contains(&point[0]) && range.contains(&point[1]));
+        assert_eq!(edges, [EdgeId { shape: 0, contour: 0, index: 0 }, EdgeId { shape: 0, contour: 0, index: 2 }]);
+    }
+
+    #[test]
+    fn test_shapes() {
+        let shapes = [
+            vec![
+                vec![[0.0, 0.0], [0.0, 10.0], [10.0, 10.0], [10.0, 0.0]],
+                vec![[2.0, 2.0], [2.0, 8.0], [8.0, 8.0], [8.0, 2.0]],
+            ],
+            vec![
+                vec![[20.0, 0.0], [20.0, 10.0], [20.0, 10.0]],
+            ],
+        ];
+        let report = shapes.validate();
+
+        assert_eq!(report.issues, vec![
+            Issue::TooFewPoints { contour: ContourId { shape: 1, contour: 0 } },
+            Issue::WrongOrientation { contour: ContourId { shape: 0, contour: 1 } },
+        ]);
+    }
+}
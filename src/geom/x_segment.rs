@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 use i_float::int::point::IntPoint;
 use i_float::triangle::Triangle;
 use i_key_sort::index::{BinKey, BinLayout};
@@ -0,0 +1,32 @@
+use alloc::string::String;
+use core::fmt;
+
+/// Error returned when a text geometry can not be converted into iOverlay types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatError {
+    /// The text is not a valid document of the expected format.
+    Parse(String),
+    /// The geometry type is not supported by the requested conversion, e.g. a `Point`
+    /// or a `LineString` where polygons are expected.
+    UnexpectedGeometry(&'static str),
+    /// A position has less than two coordinates or a coordinate is not finite.
+    InvalidPosition,
+    /// A polygon ring is not closed or has less than four positions.
+    InvalidRing,
+    /// A line has less than two positions.
+    InvalidLine,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Parse(message) => write!(f, "parse error: {}", message),
+            FormatError::UnexpectedGeometry(name) => write!(f, "unexpected geometry type: {}", name),
+            FormatError::InvalidPosition => write!(f, "invalid position"),
+            FormatError::InvalidRing => write!(f, "polygon ring must be closed and have at least four positions"),
+            FormatError::InvalidLine => write!(f, "line must have at least two positions"),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
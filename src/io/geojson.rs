@@ -0,0 +1,223 @@
+//! GeoJSON input and output.
+//!
+//! Any GeoJSON object is accepted: a bare geometry, a `Feature`, a `FeatureCollection`
+//! or a `GeometryCollection`. All matching geometries found in it are merged into one result.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::{Path, Paths, Shape, Shapes};
+use geojson::{GeoJson, Geometry, PolygonType, Position, Value};
+use crate::io::error::FormatError;
+use crate::io::ring::{read_line, read_point, read_ring, write_line, write_ring};
+
+/// Reads all `Polygon` and `MultiPolygon` geometries from a GeoJSON text.
+///
+/// - `text`: A GeoJSON document.
+/// - Returns: The shapes on success. Any other geometry type or malformed ring is reported as an error.
+pub fn shapes_from_geojson<P, T>(text: &str) -> Result<Shapes<P>, FormatError>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let mut shapes = Vec::new();
+    for value in values(text)? {
+        match value {
+            Value::Polygon(polygon) => shapes.push(read_polygon(polygon)?),
+            Value::MultiPolygon(polygons) => {
+                for polygon in polygons {
+                    shapes.push(read_polygon(polygon)?);
+                }
+            }
+            other => return Err(FormatError::UnexpectedGeometry(other.type_name())),
+        }
+    }
+
+    Ok(shapes)
+}
+
+/// Reads all `LineString` and `MultiLineString` geometries from a GeoJSON text.
+///
+/// - `text`: A GeoJSON document.
+/// - Returns: The lines on success. Any other geometry type or malformed line is reported as an error.
+pub fn paths_from_geojson<P, T>(text: &str) -> Result<Paths<P>, FormatError>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let mut paths = Vec::new();
+    for value in values(text)? {
+        match value {
+            Value::LineString(line) => paths.push(read_line(read_positions(line)?)?),
+            Value::MultiLineString(lines) => {
+                for line in lines {
+                    paths.push(read_line(read_positions(line)?)?);
+                }
+            }
+            other => return Err(FormatError::UnexpectedGeometry(other.type_name())),
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Writes shapes as a GeoJSON `MultiPolygon` geometry with the RFC 7946 ring orientation.
+pub fn shapes_to_geojson<P, T>(shapes: &[Shape<P>]) -> String
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let polygons = shapes.iter()
+        .map(|shape| shape.iter().enumerate()
+            .map(|(i, contour)| write_positions(write_ring(contour, i > 0)))
+            .collect())
+        .collect();
+
+    Geometry::new(Value::MultiPolygon(polygons)).to_string()
+}
+
+/// Writes paths as a GeoJSON `MultiLineString` geometry.
+pub fn paths_to_geojson<P, T>(paths: &[Path<P>]) -> String
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let lines = paths.iter()
+        .map(|path| write_positions(write_line(path)))
+        .collect();
+
+    Geometry::new(Value::MultiLineString(lines)).to_string()
+}
+
+fn values(text: &str) -> Result<Vec<Value>, FormatError> {
+    let geojson: GeoJson = text.parse().map_err(|e: geojson::Error| FormatError::Parse(e.to_string()))?;
+
+    let mut values = Vec::new();
+    match geojson {
+        GeoJson::Geometry(geometry) => collect_values(geometry, &mut values),
+        GeoJson::Feature(feature) => {
+            if let Some(geometry) = feature.geometry {
+                collect_values(geometry, &mut values);
+            }
+        }
+        GeoJson::FeatureCollection(collection) => {
+            for geometry in collection.features.into_iter().filter_map(|feature| feature.geometry) {
+                collect_values(geometry, &mut values);
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+fn collect_values(geometry: Geometry, values: &mut Vec<Value>) {
+    match geometry.value {
+        Value::GeometryCollection(geometries) => {
+            for geometry in geometries {
+                collect_values(geometry, values);
+            }
+        }
+        value => values.push(value),
+    }
+}
+
+fn read_polygon<P, T>(polygon: PolygonType) -> Result<Shape<P>, FormatError>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let mut shape = Vec::with_capacity(polygon.len());
+    for (i, ring) in polygon.into_iter().enumerate() {
+        shape.push(read_ring(read_positions(ring)?, i > 0)?);
+    }
+
+    Ok(shape)
+}
+
+fn read_positions<P, T>(positions: Vec<Position>) -> Result<Vec<P>, FormatError>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    positions.iter()
+        .map(|position| match position.as_slice() {
+            [x, y, ..] => read_point(*x, *y),
+            _ => Err(FormatError::InvalidPosition),
+        })
+        .collect()
+}
+
+#[inline]
+fn write_positions(points: Vec<[f64; 2]>) -> Vec<Position> {
+    points.into_iter().map(|p| p.to_vec()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use i_shape::base::data::{Paths, Shapes};
+    use crate::core::fill_rule::FillRule;
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::float::single::SingleFloatOverlay;
+    use crate::io::error::FormatError;
+    use crate::io::geojson::{paths_from_geojson, paths_to_geojson, shapes_from_geojson, shapes_to_geojson};
+
+    #[test]
+    fn test_polygon_with_hole() {
+        // RFC 7946 orientation: exterior counterclockwise, hole clockwise
+        let text = r#"{"type":"Polygon","coordinates":[
+            [[0,0],[4,0],[4,4],[0,4],[0,0]],
+            [[1,1],[1,3],[3,3],[3,1],[1,1]]
+        ]}"#;
+
+        let shapes: Shapes<[f64; 2]> = shapes_from_geojson(text).unwrap();
+        assert_eq!(shapes.len(), 1);
+        assert_eq!(shapes[0].len(), 2);
+
+        // iOverlay orientation: exterior clockwise, hole counterclockwise
+        assert_eq!(shapes[0][0], vec![[0.0, 4.0], [4.0, 4.0], [4.0, 0.0], [0.0, 0.0]]);
+        assert_eq!(shapes[0][1], vec![[3.0, 1.0], [3.0, 3.0], [1.0, 3.0], [1.0, 1.0]]);
+
+        let union = shapes.overlay(&Vec::<[f64; 2]>::new(), OverlayRule::Union, FillRule::NonZero);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].len(), 2);
+
+        let written = shapes_to_geojson(&union);
+        let restored: Shapes<[f64; 2]> = shapes_from_geojson(&written).unwrap();
+        assert_eq!(restored, union);
+        assert!(written.contains(r#""type":"MultiPolygon""#));
+    }
+
+    #[test]
+    fn test_feature_collection() {
+        let text = r#"{"type":"FeatureCollection","features":[
+            {"type":"Feature","properties":null,"geometry":{"type":"LineString","coordinates":[[0,0],[1,1]]}},
+            {"type":"Feature","properties":null,"geometry":{"type":"MultiLineString","coordinates":[[[2,2],[3,3],[4,2]]]}}
+        ]}"#;
+
+        let paths: Paths<[f64; 2]> = paths_from_geojson(text).unwrap();
+        assert_eq!(paths, vec![vec![[0.0, 0.0], [1.0, 1.0]], vec![[2.0, 2.0], [3.0, 3.0], [4.0, 2.0]]]);
+
+        let restored: Paths<[f64; 2]> = paths_from_geojson(&paths_to_geojson(&paths)).unwrap();
+        assert_eq!(restored, paths);
+    }
+
+    #[test]
+    fn test_errors() {
+        let not_closed = r#"{"type":"Polygon","coordinates":[[[0,0],[4,0],[4,4],[0,4]]]}"#;
+        assert_eq!(shapes_from_geojson::<[f64; 2], f64>(not_closed), Err(FormatError::InvalidRing));
+
+        let point = r#"{"type":"Point","coordinates":[0,0]}"#;
+        assert_eq!(shapes_from_geojson::<[f64; 2], f64>(point), Err(FormatError::UnexpectedGeometry("Point")));
+
+        let short_line = r#"{"type":"LineString","coordinates":[[0,0]]}"#;
+        assert!(paths_from_geojson::<[f64; 2], f64>(short_line).is_err());
+
+        let bad_position = r#"{"type":"LineString","coordinates":[[0],[1,1]]}"#;
+        assert!(paths_from_geojson::<[f64; 2], f64>(bad_position).is_err());
+
+        assert!(matches!(shapes_from_geojson::<[f64; 2], f64>("{"), Err(FormatError::Parse(_))));
+    }
+}
@@ -0,0 +1,20 @@
+//! Conversion between iOverlay geometry and common text formats.
+//!
+//! - `geojson` feature: [RFC 7946](https://datatracker.ietf.org/doc/html/rfc7946) GeoJSON.
+//! - `wkt` feature: OGC Well-Known Text.
+//!
+//! Polygon and MultiPolygon input is read into `Shapes<P>`, LineString and MultiLineString input
+//! into `Paths<P>`. Both can be passed directly to the float overlay and string APIs.
+//!
+//! Ring orientation is normalized in both directions: on input the exterior ring is converted
+//! to the iOverlay convention (clockwise) and interior rings become holes (counterclockwise),
+//! whatever their original orientation was. On output the RFC 7946 convention is used:
+//! the exterior ring is counterclockwise, holes are clockwise, and every ring is closed.
+
+pub mod error;
+pub(crate) mod ring;
+
+#[cfg(feature = "geojson")]
+pub mod geojson;
+#[cfg(feature = "wkt")]
+pub mod wkt;
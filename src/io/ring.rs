@@ -0,0 +1,85 @@
+use alloc::vec::Vec;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::{Contour, Path};
+use crate::float::area::signed_area;
+use crate::io::error::FormatError;
+
+#[inline]
+pub(crate) fn read_point<P, T>(x: f64, y: f64) -> Result<P, FormatError>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    if !x.is_finite() || !y.is_finite() {
+        return Err(FormatError::InvalidPosition);
+    }
+    Ok(P::from_xy(T::from_float(x), T::from_float(y)))
+}
+
+/// Converts a closed ring into a contour with the iOverlay orientation:
+/// clockwise for the exterior ring and counterclockwise for holes.
+pub(crate) fn read_ring<P, T>(mut points: Vec<P>, is_hole: bool) -> Result<Contour<P>, FormatError>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) if points.len() >= 4 => (*first, *last),
+        _ => return Err(FormatError::InvalidRing),
+    };
+
+    if first.x() != last.x() || first.y() != last.y() {
+        return Err(FormatError::InvalidRing);
+    }
+
+    points.pop();
+
+    let is_clockwise = signed_area(&points) < 0.0;
+    if is_clockwise == is_hole {
+        points.reverse();
+    }
+
+    Ok(points)
+}
+
+pub(crate) fn read_line<P, T>(points: Vec<P>) -> Result<Path<P>, FormatError>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    if points.len() < 2 {
+        return Err(FormatError::InvalidLine);
+    }
+    Ok(points)
+}
+
+/// Converts a contour into a closed ring with the RFC 7946 orientation:
+/// counterclockwise for the exterior ring and clockwise for holes.
+pub(crate) fn write_ring<P, T>(contour: &[P], is_hole: bool) -> Vec<[f64; 2]>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let mut ring: Vec<[f64; 2]> = contour.iter().map(|p| [p.x().to_f64(), p.y().to_f64()]).collect();
+
+    let is_counterclockwise = signed_area(contour) > 0.0;
+    if is_counterclockwise == is_hole {
+        ring.reverse();
+    }
+
+    if let Some(&first) = ring.first() {
+        ring.push(first);
+    }
+
+    ring
+}
+
+#[inline]
+pub(crate) fn write_line<P, T>(path: &[P]) -> Vec<[f64; 2]>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    path.iter().map(|p| [p.x().to_f64(), p.y().to_f64()]).collect()
+}
@@ -0,0 +1,194 @@
+//! WKT input and output.
+//!
+//! `GEOMETRYCOLLECTION` is accepted and all matching geometries found in it are merged into one result.
+//! Z and M coordinates are ignored.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
+use i_float::float::compatible::FloatPointCompatible;
+use i_float::float::number::FloatNumber;
+use i_shape::base::data::{Path, Paths, Shape, Shapes};
+use wkt::types::{Coord, LineString, MultiLineString, MultiPolygon, Polygon};
+use wkt::Wkt;
+use crate::io::error::FormatError;
+use crate::io::ring::{read_line, read_point, read_ring, write_line, write_ring};
+
+/// Reads all `POLYGON` and `MULTIPOLYGON` geometries from a WKT text.
+///
+/// - `text`: A WKT geometry.
+/// - Returns: The shapes on success. Any other geometry type or malformed ring is reported as an error.
+pub fn shapes_from_wkt<P, T>(text: &str) -> Result<Shapes<P>, FormatError>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let mut shapes = Vec::new();
+    for item in items(text)? {
+        match item {
+            Wkt::Polygon(polygon) => shapes.push(read_polygon(polygon)?),
+            Wkt::MultiPolygon(multi_polygon) => {
+                for polygon in multi_polygon.0 {
+                    shapes.push(read_polygon(polygon)?);
+                }
+            }
+            other => return Err(FormatError::UnexpectedGeometry(type_name(&other))),
+        }
+    }
+
+    Ok(shapes)
+}
+
+/// Reads all `LINESTRING` and `MULTILINESTRING` geometries from a WKT text.
+///
+/// - `text`: A WKT geometry.
+/// - Returns: The lines on success. Any other geometry type or malformed line is reported as an error.
+pub fn paths_from_wkt<P, T>(text: &str) -> Result<Paths<P>, FormatError>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let mut paths = Vec::new();
+    for item in items(text)? {
+        match item {
+            Wkt::LineString(line) => paths.push(read_line(read_coords(line)?)?),
+            Wkt::MultiLineString(multi_line) => {
+                for line in multi_line.0 {
+                    paths.push(read_line(read_coords(line)?)?);
+                }
+            }
+            other => return Err(FormatError::UnexpectedGeometry(type_name(&other))),
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Writes shapes as a WKT `MULTIPOLYGON` with the exterior ring counterclockwise and holes clockwise.
+pub fn shapes_to_wkt<P, T>(shapes: &[Shape<P>]) -> String
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let polygons = shapes.iter()
+        .map(|shape| Polygon(shape.iter().enumerate()
+            .map(|(i, contour)| write_coords(write_ring(contour, i > 0)))
+            .collect()))
+        .collect();
+
+    MultiPolygon(polygons).to_string()
+}
+
+/// Writes paths as a WKT `MULTILINESTRING`.
+pub fn paths_to_wkt<P, T>(paths: &[Path<P>]) -> String
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let lines = paths.iter()
+        .map(|path| write_coords(write_line(path)))
+        .collect();
+
+    MultiLineString(lines).to_string()
+}
+
+fn items(text: &str) -> Result<Vec<Wkt<f64>>, FormatError> {
+    let wkt = Wkt::<f64>::from_str(text).map_err(|e| FormatError::Parse(e.to_string()))?;
+
+    let mut items = Vec::new();
+    collect_items(wkt, &mut items);
+
+    Ok(items)
+}
+
+fn collect_items(wkt: Wkt<f64>, items: &mut Vec<Wkt<f64>>) {
+    match wkt {
+        Wkt::GeometryCollection(collection) => {
+            for item in collection.0 {
+                collect_items(item, items);
+            }
+        }
+        item => items.push(item),
+    }
+}
+
+fn read_polygon<P, T>(polygon: Polygon<f64>) -> Result<Shape<P>, FormatError>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    let mut shape = Vec::with_capacity(polygon.0.len());
+    for (i, ring) in polygon.0.into_iter().enumerate() {
+        shape.push(read_ring(read_coords(ring)?, i > 0)?);
+    }
+
+    Ok(shape)
+}
+
+#[inline]
+fn read_coords<P, T>(line: LineString<f64>) -> Result<Vec<P>, FormatError>
+where
+    P: FloatPointCompatible<T>,
+    T: FloatNumber,
+{
+    line.0.iter().map(|c| read_point(c.x, c.y)).collect()
+}
+
+#[inline]
+fn write_coords(points: Vec<[f64; 2]>) -> LineString<f64> {
+    LineString(points.into_iter().map(|[x, y]| Coord { x, y, z: None, m: None }).collect())
+}
+
+fn type_name(wkt: &Wkt<f64>) -> &'static str {
+    match wkt {
+        Wkt::Point(_) => "Point",
+        Wkt::LineString(_) => "LineString",
+        Wkt::Polygon(_) => "Polygon",
+        Wkt::MultiPoint(_) => "MultiPoint",
+        Wkt::MultiLineString(_) => "MultiLineString",
+        Wkt::MultiPolygon(_) => "MultiPolygon",
+        Wkt::GeometryCollection(_) => "GeometryCollection",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use i_shape::base::data::{Paths, Shapes};
+    use crate::io::error::FormatError;
+    use crate::io::wkt::{paths_from_wkt, paths_to_wkt, shapes_from_wkt, shapes_to_wkt};
+
+    #[test]
+    fn test_polygon_with_hole() {
+        // wrong orientation on input is fixed
+        let text = "POLYGON((0 0,0 4,4 4,4 0,0 0),(1 1,1 3,3 3,3 1,1 1))";
+
+        let shapes: Shapes<[f64; 2]> = shapes_from_wkt(text).unwrap();
+        assert_eq!(shapes[0][0], vec![[0.0, 0.0], [0.0, 4.0], [4.0, 4.0], [4.0, 0.0]]);
+        assert_eq!(shapes[0][1], vec![[3.0, 1.0], [3.0, 3.0], [1.0, 3.0], [1.0, 1.0]]);
+
+        let written = shapes_to_wkt(&shapes);
+        assert_eq!(written, "MULTIPOLYGON(((4 0,4 4,0 4,0 0,4 0),(1 1,1 3,3 3,3 1,1 1)))");
+
+        let restored: Shapes<[f64; 2]> = shapes_from_wkt(&written).unwrap();
+        assert_eq!(restored, shapes);
+    }
+
+    #[test]
+    fn test_lines() {
+        let text = "GEOMETRYCOLLECTION(LINESTRING(0 0,1 1),MULTILINESTRING((2 2,3 3,4 2)))";
+
+        let paths: Paths<[f64; 2]> = paths_from_wkt(text).unwrap();
+        assert_eq!(paths, vec![vec![[0.0, 0.0], [1.0, 1.0]], vec![[2.0, 2.0], [3.0, 3.0], [4.0, 2.0]]]);
+
+        let restored: Paths<[f64; 2]> = paths_from_wkt(&paths_to_wkt(&paths)).unwrap();
+        assert_eq!(restored, paths);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(shapes_from_wkt::<[f64; 2], f64>("POLYGON((0 0,4 0,4 4,0 4))"), Err(FormatError::InvalidRing));
+        assert_eq!(shapes_from_wkt::<[f64; 2], f64>("LINESTRING(0 0,1 1)"), Err(FormatError::UnexpectedGeometry("LineString")));
+        assert!(matches!(paths_from_wkt::<[f64; 2], f64>("LINESTRING(0 0,"), Err(FormatError::Parse(_))));
+    }
+}
@@ -186,7 +186,21 @@
 //!
 //! println!("result: {:?}", result);
 //! ```
+//!
+//! ## Serde
+//! With the `serde` feature enabled, `FillRule`, `OverlayRule`, `ShapeType`, `Solver`, `Strategy`,
+//! `Precision`, `MultithreadOptions`, `ClipRule`, `StringRule`, `ContourFilter` and `VectorEdge`
+//! implement `Serialize` and `Deserialize`. The representation is stable:
+//! - enums are stored by variant name, e.g. `"NonZero"` or `"Union"`,
+//! - structs are stored as maps with the same field names as in Rust,
+//! - a `Solver` may omit any field, the missing ones are taken from `Solver::AUTO`,
+//! - points are stored as `{ "x": .., "y": .. }`.
+//!
+//! ```json
+//! { "strategy": "Tree", "precision": "Absolute", "multithreading": null }
+//! ```
 
+extern crate alloc;
 
 pub mod fill;
 pub mod core;
@@ -194,6 +208,10 @@ pub mod vector;
 pub mod float;
 pub mod string;
 pub mod segm;
+#[cfg(any(feature = "geojson", feature = "wkt"))]
+pub mod io;
+#[cfg(feature = "capi")]
+pub mod capi;
 
 pub(crate) mod split;
 pub(crate) mod bind;
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use i_float::int::point::IntPoint;
 use i_float::triangle::Triangle;
 use crate::core::overlay::ShapeType;
@@ -92,6 +93,7 @@ impl<C: Send> Segment<C> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
     use i_float::int::point::IntPoint;
     use crate::core::overlay::ShapeType;
     use crate::segm::build::BuildSegments;
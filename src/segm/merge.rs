@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use crate::segm::segment::Segment;
 use crate::segm::winding_count::WindingCount;
 
@@ -8,6 +9,10 @@ pub(crate) trait ShapeSegmentsMerge {
 impl<C: WindingCount> ShapeSegmentsMerge for Vec<Segment<C>> {
     fn merge_if_needed(&mut self) {
         if self.len() < 2 { return; }
+        if !C::IS_MERGEABLE {
+            drop_empty(self);
+            return;
+        }
 
         let mut prev = &self[0].x_segment;
         for i in 1..self.len() {
@@ -48,8 +53,35 @@ fn merge<C: WindingCount>(segments: &mut [Segment<C>], after: usize) -> usize {
     j
 }
 
+/// Drops the runs of equal segments whose counts sum to an empty count, the rest are kept apart.
+fn drop_empty<C: WindingCount>(segments: &mut Vec<Segment<C>>) {
+    let mut counts = Vec::new();
+    let mut i = 0;
+    let mut j = 0;
+    while i < segments.len() {
+        let mut k = i + 1;
+        while k < segments.len() && segments[k].x_segment == segments[i].x_segment {
+            k += 1;
+        }
+
+        let is_empty = k - i > 1 && {
+            counts.clear();
+            counts.extend(segments[i..k].iter().map(|s| s.count));
+            C::is_empty_sum(&counts)
+        };
+
+        if !is_empty {
+            segments.copy_within(i..k, j);
+            j += k - i;
+        }
+        i = k;
+    }
+    segments.truncate(j);
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
     use i_float::int::point::IntPoint;
     use super::*;
     use crate::segm::winding_count::ShapeCountBoolean;
@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 use crate::core::overlay::ShapeType;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -20,6 +20,15 @@ pub(crate) trait WindingCount
 where
     Self: Clone + Copy + Send,
 {
+    /// `false` if the split must keep equal segments apart instead of merging their counts.
+    /// Equal segments kept apart are dropped together if `is_empty_sum` of their counts is `true`.
+    const IS_MERGEABLE: bool = true;
+
+    #[inline(always)]
+    fn is_empty_sum(counts: &[Self]) -> bool {
+        !counts.iter().fold(Self::new(0, 0), |sum, &count| sum.add(count)).is_not_empty()
+    }
+
     fn is_not_empty(&self) -> bool;
     fn new(subj: i32, clip: i32) -> Self;
     fn with_shape_type(shape_type: ShapeType) -> (Self, Self);
@@ -0,0 +1,98 @@
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use crate::core::solver::Solver;
+use crate::geom::x_segment::XSegment;
+use crate::segm::segment::Segment;
+use crate::split::cross_solver::{CrossSolver, CrossType};
+use crate::split::segment_tree::SegmentTree;
+use crate::split::solver::{PairHandler, SplitSolver};
+use crate::split::solver_tree::VerticalRange;
+use crate::split::space_layout::SpaceLayout;
+
+/// How two segments touch each other.
+/// - `Cross`: The segments cross at a point inside both of them, the exact point may be rounded to an end.
+/// - `Touch`: An end of one segment lies inside the segment `inner`.
+/// - `Same`: The segments have the same ends.
+/// - `Overlap`: The segments are collinear and share the part between the two points.
+pub(crate) enum Contact {
+    Cross(IntPoint),
+    Touch { point: IntPoint, inner: usize },
+    Same,
+    Overlap([IntPoint; 2]),
+}
+
+/// The contact of the segments `i` and `j`.
+pub(crate) struct SegmentContact {
+    pub(crate) i: usize,
+    pub(crate) j: usize,
+    pub(crate) contact: Contact,
+}
+
+struct ContactCollector<F> {
+    filter: F,
+    contacts: Vec<SegmentContact>,
+}
+
+impl<F: Fn(usize, usize) -> bool> PairHandler for ContactCollector<F> {
+    fn cross(&mut self, i: usize, j: usize, ei: &XSegment, ej: &XSegment, radius: i64) -> bool {
+        if !(self.filter)(i, j) {
+            return false;
+        }
+
+        let cross = if let Some(cross) = CrossSolver::cross(ei, ej, radius) {
+            cross
+        } else {
+            return false;
+        };
+
+        let contact = match cross.cross_type {
+            CrossType::Pure => Contact::Cross(cross.point),
+            CrossType::TargetEnd | CrossType::OtherEnd if cross.is_round => Contact::Cross(cross.point),
+            CrossType::TargetEnd => Contact::Touch { point: cross.point, inner: j },
+            CrossType::OtherEnd => Contact::Touch { point: cross.point, inner: i },
+            CrossType::Overlay => {
+                if ei == ej {
+                    Contact::Same
+                } else if CrossSolver::collinear(ei, ej) != 0 {
+                    Contact::Overlap([ei.a.max(ej.a), ei.b.min(ej.b)])
+                } else {
+                    return false;
+                }
+            }
+        };
+
+        self.contacts.push(SegmentContact { i, j, contact });
+
+        cross.is_round
+    }
+}
+
+pub(crate) trait FindContacts {
+    /// Finds the contacts of segments with the broad phase of the split, nothing is split.
+    /// - `solver`: Selects the broad phase, the precision is not used.
+    /// - `filter`: Returns `false` for the pairs that are not tested.
+    fn find_contacts<F: Fn(usize, usize) -> bool>(&self, solver: Solver, filter: F) -> Vec<SegmentContact>;
+}
+
+impl<C: Send> FindContacts for [Segment<C>] {
+    /// The segments must be sorted by `x_segment`.
+    fn find_contacts<F: Fn(usize, usize) -> bool>(&self, solver: Solver, filter: F) -> Vec<SegmentContact> {
+        let mut collector = ContactCollector { filter, contacts: Vec::new() };
+        if self.len() < 2 {
+            return collector.contacts;
+        }
+
+        let ver_range = self.ver_range();
+        let height = ver_range.width() as usize;
+
+        if solver.is_list_split(self) || height < SpaceLayout::MIN_HEIGHT {
+            SplitSolver::list_pairs(self, 0, &mut collector);
+        } else {
+            let layout = SpaceLayout::new(height, self.len());
+            let mut tree = SegmentTree::new(ver_range, layout.power, 0);
+            SplitSolver::tree_pairs(self, &mut tree, &mut collector);
+        }
+
+        collector.contacts
+    }
+}
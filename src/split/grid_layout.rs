@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use alloc::vec;
+use alloc::vec::Vec;
 use i_float::int::rect::IntRect;
 use crate::bind::segment::IdSegment;
 use crate::geom::x_segment::XSegment;
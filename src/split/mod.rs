@@ -9,3 +9,5 @@ mod solver_tree;
 mod line_mark;
 mod grid_layout;
 mod solver_fragment;
+mod snap;
+pub(crate) mod contact;
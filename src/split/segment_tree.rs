@@ -1,7 +1,8 @@
+use alloc::vec;
+use alloc::vec::Vec;
 use crate::geom::line_range::LineRange;
 use crate::split::fragment::Fragment;
-use crate::split::line_mark::LineMark;
-use crate::split::solver::SplitSolver;
+use crate::split::solver::PairHandler;
 use crate::util::remove::SwapRemoveIndex;
 
 #[derive(Debug, Clone)]
@@ -173,7 +174,7 @@ impl SegmentTree {
         }
     }
 
-    pub fn intersect(&mut self, this: &Fragment, marks: &mut Vec<LineMark>) -> bool {
+    pub fn intersect<H: PairHandler>(&mut self, this: &Fragment, handler: &mut H) -> bool {
         let mut s = 1 << self.power;
         let mut i = s - 1;
         let range = this.y_range();
@@ -182,7 +183,7 @@ impl SegmentTree {
         let mut any_round = false;
 
         while s > 0 {
-            let is_round = self.cross_node(i, this, marks);
+            let is_round = self.cross_node(i, this, handler);
             any_round = is_round || any_round;
             s >>= 1;
 
@@ -207,7 +208,7 @@ impl SegmentTree {
         let mut j = i - s;
         let mut sj = s;
         while sj > 1 {
-            let is_round = self.cross_node(j, this, marks);
+            let is_round = self.cross_node(j, this, handler);
             any_round = is_round || any_round;
 
             let middle = self.nodes[j].range.middle();
@@ -232,7 +233,7 @@ impl SegmentTree {
         j = i + s;
         sj = s;
         while sj > 1 {
-            let is_round = self.cross_node(j, this, marks);
+            let is_round = self.cross_node(j, this, handler);
             any_round = is_round || any_round;
 
             let middle = self.nodes[j].range.middle();
@@ -255,7 +256,7 @@ impl SegmentTree {
         i = i_lt;
 
         while i <= i_rt {
-            let is_round = self.cross_node(i, this, marks);
+            let is_round = self.cross_node(i, this, handler);
             any_round = is_round || any_round;
             i += 1;
         }
@@ -270,7 +271,7 @@ impl SegmentTree {
         }
     }
 
-    fn cross_node(&mut self, index: usize, this: &Fragment, marks: &mut Vec<LineMark>) -> bool {
+    fn cross_node<H: PairHandler>(&mut self, index: usize, this: &Fragment, handler: &mut H) -> bool {
         let swipe_line = this.rect.min_x;
         let mut any_round = false;
 
@@ -293,21 +294,19 @@ impl SegmentTree {
             // MARK: the intersection, ensuring the right order for deterministic results
 
             let is_round = if this.x_segment < scan.x_segment {
-                SplitSolver::cross(
+                handler.cross(
                     this.index,
                     scan.index,
                     &this.x_segment,
                     &scan.x_segment,
-                    marks,
                     self.radius,
                 )
             } else {
-                SplitSolver::cross(
+                handler.cross(
                     scan.index,
                     this.index,
                     &scan.x_segment,
                     &this.x_segment,
-                    marks,
                     self.radius,
                 )
             };
@@ -335,6 +334,8 @@ impl SegmentTree {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
     use i_float::int::point::IntPoint;
     use crate::geom::x_segment::XSegment;
     use crate::geom::line_range::LineRange;
@@ -0,0 +1,184 @@
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use crate::geom::x_segment::XSegment;
+use crate::segm::segment::Segment;
+use crate::segm::winding_count::WindingCount;
+use crate::split::line_mark::LineMark;
+use crate::split::solver::SplitSolver;
+
+impl SplitSolver {
+    /// Upper bound on snap rounds. Real data converges in a few rounds, the cap only protects
+    /// against degenerate input where rerouted pieces keep hitting new hot pixels.
+    const MAX_SNAP_ROUNDS: usize = 64;
+
+    /// Iterated snap rounding.
+    ///
+    /// Every vertex of the arrangement (source vertex or rounded intersection) owns a hot pixel,
+    /// a unit square centered at it. A segment that passes through a hot pixel is rerouted
+    /// through its center, so a rounded vertex can never end up on the other side of a segment.
+    /// The rerouted pieces are split again and the process repeats until no segment passes
+    /// through a foreign hot pixel, or `MAX_SNAP_ROUNDS` is reached.
+    ///
+    /// Every round ends with a regular cross split, so the result never has crossing segments.
+    /// If the cap is reached, the result is still valid, but some vertex may remain inside
+    /// the hot pixel of a foreign segment (closer than half a unit to it).
+    pub(super) fn snap_split<C: WindingCount>(&mut self, mut segments: Vec<Segment<C>>) -> Vec<Segment<C>> {
+        let mut marks = Vec::new();
+        let mut round = 0;
+        while segments.len() > 1 {
+            Self::hot_pixel_marks(&segments, &mut marks);
+
+            if marks.is_empty() {
+                break;
+            }
+
+            if round == Self::MAX_SNAP_ROUNDS {
+                break;
+            }
+            round += 1;
+
+            segments = self.apply(&mut marks, segments, true);
+            marks.clear();
+
+            segments = self.cross_split(segments);
+        }
+
+        segments
+    }
+
+    fn hot_pixel_marks<C: Send>(segments: &[Segment<C>], marks: &mut Vec<LineMark>) {
+        let mut vertices = Vec::with_capacity(2 * segments.len());
+        for s in segments.iter() {
+            vertices.push(s.x_segment.a);
+            vertices.push(s.x_segment.b);
+        }
+        vertices.sort_unstable();
+        vertices.dedup();
+
+        for (i, s) in segments.iter().enumerate() {
+            let x_segment = &s.x_segment;
+            let y_range = x_segment.y_range();
+
+            // vertices are sorted by x, a hot pixel can touch the segment only if x is inside [a.x, b.x]
+            let start = vertices.partition_point(|p| p.x < x_segment.a.x);
+            for &p in vertices[start..].iter() {
+                if p.x > x_segment.b.x {
+                    break;
+                }
+
+                if p.y < y_range.min || p.y > y_range.max || p == x_segment.a || p == x_segment.b {
+                    continue;
+                }
+
+                if x_segment.is_cross_hot_pixel(p) {
+                    marks.push(LineMark { index: i, point: p });
+                }
+            }
+        }
+    }
+}
+
+impl XSegment {
+    /// Checks if the segment passes through the unit square centered at `p`.
+    /// The square is half-open: `[x - 0.5, x + 0.5) x [y - 0.5, y + 0.5)`, so of the four corners
+    /// only the bottom-left one belongs to it. A segment that touches that corner is rerouted,
+    /// a segment that touches any other corner belongs to a neighbor pixel and is not.
+    #[inline]
+    fn is_cross_hot_pixel(&self, p: IntPoint) -> bool {
+        // all values are doubled, so the pixel corners are integer
+        let ax = 2 * self.a.x as i64;
+        let ay = 2 * self.a.y as i64;
+        let vx = 2 * (self.b.x as i64 - self.a.x as i64);
+        let vy = 2 * (self.b.y as i64 - self.a.y as i64);
+
+        let px = 2 * p.x as i64;
+        let py = 2 * p.y as i64;
+
+        let mut is_any_left = false;
+        let mut is_any_right = false;
+        for (cx, cy) in [(px - 1, py - 1), (px - 1, py + 1), (px + 1, py - 1), (px + 1, py + 1)] {
+            let cross = (vx as i128) * ((cy - ay) as i128) - (vy as i128) * ((cx - ax) as i128);
+            is_any_left |= cross > 0;
+            is_any_right |= cross < 0;
+        }
+
+        if is_any_left && is_any_right {
+            return true;
+        }
+
+        // the bottom-left corner, the segment ends are integer so it can only lie inside the segment
+        let (cx, cy) = (px - 1, py - 1);
+        let cross = (vx as i128) * ((cy - ay) as i128) - (vy as i128) * ((cx - ax) as i128);
+        let (min_y, max_y) = if vy > 0 { (ay, ay + vy) } else { (ay + vy, ay) };
+        cross == 0 && ax < cx && cx < ax + vx && min_y < cy && cy < max_y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::solver::{Precision, Solver};
+    use crate::geom::x_segment::XSegment;
+    use crate::segm::segment::Segment;
+    use crate::segm::winding_count::{ShapeCountBoolean, WindingCount};
+    use crate::split::solver::SplitSolver;
+
+    #[test]
+    fn test_hot_pixel() {
+        let s = XSegment { a: IntPoint::new(0, 0), b: IntPoint::new(10, 3) };
+
+        assert!(s.is_cross_hot_pixel(IntPoint::new(3, 1)));
+        assert!(s.is_cross_hot_pixel(IntPoint::new(7, 2)));
+        assert!(!s.is_cross_hot_pixel(IntPoint::new(3, 2)));
+        assert!(!s.is_cross_hot_pixel(IntPoint::new(5, 0)));
+    }
+
+    #[test]
+    fn test_hot_pixel_corner() {
+        // the segment passes through (0.5, 0.5) and touches 4 pixels by a corner
+        let s = XSegment { a: IntPoint::new(0, 1), b: IntPoint::new(1, 0) };
+
+        // (0.5, 0.5) is the bottom-left corner of (1, 1), the only pixel it belongs to
+        assert!(s.is_cross_hot_pixel(IntPoint::new(1, 1)));
+        assert!(!s.is_cross_hot_pixel(IntPoint::new(0, 0)));
+
+        let s = XSegment { a: IntPoint::new(0, 0), b: IntPoint::new(1, 1) };
+        assert!(!s.is_cross_hot_pixel(IntPoint::new(1, 0)));
+        assert!(!s.is_cross_hot_pixel(IntPoint::new(0, 1)));
+    }
+
+    #[test]
+    fn test_snap_to_near_vertex() {
+        // the vertex (3, 1) lies very close to the long segment,
+        // snap rounding must reroute the segment through it
+        let segments = vec![
+            segment(IntPoint::new(0, 0), IntPoint::new(10, 3)),
+            segment(IntPoint::new(3, 1), IntPoint::new(3, 5)),
+        ];
+
+        let solver = Solver { precision: Precision::SnapRounding, ..Solver::LIST };
+        let result = SplitSolver::new(solver).split(segments);
+
+        assert_eq!(result.len(), 3);
+        assert!(result.iter().any(|s| s.x_segment.b == IntPoint::new(3, 1) && s.x_segment.a == IntPoint::new(0, 0)));
+        assert!(result.iter().any(|s| s.x_segment.a == IntPoint::new(3, 1) && s.x_segment.b == IntPoint::new(10, 3)));
+    }
+
+    #[test]
+    fn test_no_snap_for_far_vertex() {
+        let segments = vec![
+            segment(IntPoint::new(0, 0), IntPoint::new(10, 0)),
+            segment(IntPoint::new(3, 2), IntPoint::new(3, 5)),
+        ];
+
+        let solver = Solver { precision: Precision::SnapRounding, ..Solver::LIST };
+        let result = SplitSolver::new(solver).split(segments);
+
+        assert_eq!(result.len(), 2);
+    }
+
+    fn segment(a: IntPoint, b: IntPoint) -> Segment<ShapeCountBoolean> {
+        Segment::create_and_validate(a, b, ShapeCountBoolean::new(1, 0))
+    }
+}
@@ -1,7 +1,9 @@
+use alloc::vec;
+use alloc::vec::Vec;
 use i_float::int::point::IntPoint;
 use i_key_sort::index::{BinKey, BinLayout};
 use i_key_sort::key_sort::Bin;
-use crate::core::solver::Solver;
+use crate::core::solver::{Precision, Solver};
 use crate::segm::segment::Segment;
 use crate::segm::winding_count::WindingCount;
 use crate::split::cross_solver::{CrossType, CrossSolver, EndMask};
@@ -25,6 +27,20 @@ impl<C: WindingCount> SplitSegments<C> for Vec<Segment<C>> {
     }
 }
 
+/// Tests the pairs of segments found by the broad phase.
+pub(super) trait PairHandler {
+    /// Tests the segments `i` and `j`, `ei` is less than `ej`.
+    /// Returns `true` if the intersection point was rounded.
+    fn cross(&mut self, i: usize, j: usize, ei: &XSegment, ej: &XSegment, radius: i64) -> bool;
+}
+
+impl PairHandler for Vec<LineMark> {
+    #[inline(always)]
+    fn cross(&mut self, i: usize, j: usize, ei: &XSegment, ej: &XSegment, radius: i64) -> bool {
+        SplitSolver::cross(i, j, ei, ej, self, radius)
+    }
+}
+
 pub(super) struct SplitSolver {
     pub(super) solver: Solver,
 }
@@ -37,6 +53,16 @@ impl SplitSolver {
 
     #[inline]
     pub(crate) fn split<C: WindingCount>(&mut self, segments: Vec<Segment<C>>) -> Vec<Segment<C>> {
+        let segments = self.cross_split(segments);
+        if self.solver.precision == Precision::SnapRounding {
+            self.snap_split(segments)
+        } else {
+            segments
+        }
+    }
+
+    #[inline]
+    pub(super) fn cross_split<C: WindingCount>(&mut self, segments: Vec<Segment<C>>) -> Vec<Segment<C>> {
         let is_list = self.solver.is_list_split(&segments);
         if is_list {
             return self.list_split(segments);
@@ -379,4 +405,30 @@ impl SplitSolver {
             marks.reverse();
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::solver::Solver;
+    use crate::segm::segment::Segment;
+    use crate::segm::winding_count::{ShapeCountBoolean, WindingCount};
+    use crate::split::solver::SplitSegments;
+
+    #[test]
+    fn test_two_crossing_segments() {
+        // two segments are enough for a crossing, they used to be returned unsplit
+        for solver in [Solver::LIST, Solver::TREE, Solver::FRAG] {
+            let segments = vec![
+                Segment::create_and_validate(IntPoint::new(0, 0), IntPoint::new(10, 10), ShapeCountBoolean::new(1, 0)),
+                Segment::create_and_validate(IntPoint::new(0, 10), IntPoint::new(10, 0), ShapeCountBoolean::new(0, 1)),
+            ];
+
+            let result = segments.split_segments(solver);
+
+            assert_eq!(result.len(), 4);
+            assert!(result.iter().all(|s| s.x_segment.a == IntPoint::new(5, 5) || s.x_segment.b == IntPoint::new(5, 5)));
+        }
+    }
+}
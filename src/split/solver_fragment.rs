@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use crate::bind::segment::IdSegment;
 use crate::segm::segment::Segment;
 use crate::segm::winding_count::WindingCount;
@@ -22,7 +23,7 @@ impl SplitSolver {
 
         let mut iter = 0;
 
-        while need_to_fix && segments.len() > 2 {
+        while need_to_fix && segments.len() > 1 {
 
             buffer.init_fragment_buffer(segments.iter().map(|it| it.x_segment));
             for (i, segment) in segments.iter().enumerate() {
@@ -1,6 +1,7 @@
+use alloc::vec::Vec;
 use crate::segm::segment::Segment;
 use crate::segm::winding_count::WindingCount;
-use crate::split::solver::SplitSolver;
+use crate::split::solver::{PairHandler, SplitSolver};
 
 impl SplitSolver {
     pub(super) fn list_split<C: WindingCount>(&mut self, mut segments: Vec<Segment<C>>) -> Vec<Segment<C>> {
@@ -9,29 +10,11 @@ impl SplitSolver {
 
         let mut iter = 0;
 
-        while need_to_fix && segments.len() > 2 {
-            need_to_fix = false;
+        while need_to_fix && segments.len() > 1 {
             marks.clear();
 
             let radius: i64 = self.solver.radius(iter);
-
-            for i in 0..segments.len() - 1 {
-                let ei = &segments[i].x_segment;
-                let ri = ei.y_range();
-                for (j, s) in segments.iter().enumerate().skip(i + 1) {
-                    let ej = &s.x_segment;
-                    if ei.b.x < ej.a.x {
-                        break;
-                    }
-
-                    if ej.is_not_intersect_y_range(&ri) {
-                        continue;
-                    }
-
-                    let is_round = SplitSolver::cross(i, j, ei, ej, &mut marks, radius);
-                    need_to_fix = need_to_fix || is_round
-                }
-            }
+            need_to_fix = Self::list_pairs(&segments, radius, &mut marks);
 
             if marks.is_empty() {
                 return segments;
@@ -49,4 +32,27 @@ impl SplitSolver {
 
         segments
     }
-}
\ No newline at end of file
+
+    /// Tests every pair of sorted segments with overlapping bounds.
+    pub(super) fn list_pairs<C: Send, H: PairHandler>(segments: &[Segment<C>], radius: i64, handler: &mut H) -> bool {
+        let mut any_round = false;
+        for (i, si) in segments.iter().enumerate() {
+            let ei = &si.x_segment;
+            let ri = ei.y_range();
+            for (j, s) in segments.iter().enumerate().skip(i + 1) {
+                let ej = &s.x_segment;
+                if ei.b.x < ej.a.x {
+                    break;
+                }
+
+                if ej.is_not_intersect_y_range(&ri) {
+                    continue;
+                }
+
+                let is_round = handler.cross(i, j, ei, ej, radius);
+                any_round = any_round || is_round
+            }
+        }
+        any_round
+    }
+}
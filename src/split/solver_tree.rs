@@ -1,9 +1,10 @@
+use alloc::vec::Vec;
 use crate::geom::line_range::LineRange;
 use crate::segm::segment::Segment;
 use crate::segm::winding_count::WindingCount;
 use crate::split::fragment::Fragment;
 use crate::split::segment_tree::SegmentTree;
-use crate::split::solver::SplitSolver;
+use crate::split::solver::{PairHandler, SplitSolver};
 use crate::split::space_layout::SpaceLayout;
 
 
@@ -24,18 +25,9 @@ impl SplitSolver {
 
         let mut iter = 0;
 
-        while need_to_fix && segments.len() > 2 {
-            need_to_fix = false;
-
+        while need_to_fix && segments.len() > 1 {
             tree.radius = self.solver.radius(iter);
-
-            for (i, e) in segments.iter().enumerate() {
-                let fragment = Fragment::with_index_and_segment(i, e.x_segment);
-                let any_round = tree.intersect(&fragment, &mut marks);
-                need_to_fix = any_round || need_to_fix;
-
-                tree.insert(fragment);
-            }
+            need_to_fix = Self::tree_pairs(&segments, &mut tree, &mut marks);
 
             if marks.is_empty() {
                 return segments;
@@ -52,13 +44,26 @@ impl SplitSolver {
 
         segments
     }
+
+    /// Tests the pairs of sorted segments found by the tree, the tree is left filled.
+    pub(super) fn tree_pairs<C: Send, H: PairHandler>(segments: &[Segment<C>], tree: &mut SegmentTree, handler: &mut H) -> bool {
+        let mut any_round = false;
+        for (i, e) in segments.iter().enumerate() {
+            let fragment = Fragment::with_index_and_segment(i, e.x_segment);
+            let is_round = tree.intersect(&fragment, handler);
+            any_round = is_round || any_round;
+
+            tree.insert(fragment);
+        }
+        any_round
+    }
 }
 
-trait VerticalRange {
+pub(super) trait VerticalRange {
     fn ver_range(&self) -> LineRange;
 }
 
-impl<C: Send> VerticalRange for Vec<Segment<C>> {
+impl<C: Send> VerticalRange for [Segment<C>] {
     fn ver_range(&self) -> LineRange {
         let mut min_y = self[0].x_segment.a.y;
         let mut max_y = min_y;
@@ -0,0 +1,215 @@
+//! Clips string lines by shapes and keeps all the parts at once.
+//!
+//! A `ClipRule` selects one class of string parts: inside, outside, with or without the boundary.
+//! Here the split and the fill run once and every part is tagged with its class and with the index
+//! of the input line it comes from.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use crate::core::fill_rule::FillRule;
+use crate::core::link::OverlayLinkBuilder;
+use crate::core::solver::Solver;
+use crate::segm::segment::{CLIP_BOTH, SUBJ_BOTH};
+use crate::segm::winding_count::{STRING_BACK_CLIP, STRING_FORWARD_CLIP};
+use crate::string::overlay::StringOverlay;
+
+/// The position of a string part relative to the shapes.
+/// - `Inside`: The part is inside the filled area.
+/// - `Outside`: The part is outside the filled area.
+/// - `Boundary`: The part goes along the boundary of the filled area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClipClass {
+    Inside,
+    Outside,
+    Boundary,
+}
+
+/// A part of an input string line.
+/// - `path`: The points of the part, in the direction of the input line. A closed part repeats its first point at the end.
+/// - `class`: The position of the part relative to the shapes.
+/// - `index`: The index of the input line, every `add_string_*` call adds one line.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClassifiedPath<P = IntPoint> {
+    pub path: Vec<P>,
+    pub class: ClipClass,
+    pub index: usize,
+}
+
+impl StringOverlay {
+    /// Clips the string lines and returns the inside, outside and boundary parts together.
+    /// - `fill_rule`: Specifies the rule determining the filled areas.
+    /// # Returns
+    /// The parts sorted by the input line index and by class. Parts of different lines are never joined,
+    /// a part shared by several lines is returned for every one of them.
+    #[inline]
+    pub fn clip_string_lines_classified(self, fill_rule: FillRule) -> Vec<ClassifiedPath> {
+        self.clip_string_lines_classified_with_solver(fill_rule, Default::default())
+    }
+
+    /// Clips the string lines and returns the inside, outside and boundary parts together.
+    /// - `fill_rule`: Specifies the rule determining the filled areas.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The parts sorted by the input line index and by class. Parts of different lines are never joined,
+    /// a part shared by several lines is returned for every one of them.
+    pub fn clip_string_lines_classified_with_solver(self, fill_rule: FillRule, solver: Solver) -> Vec<ClassifiedPath> {
+        let lines = if let Some(lines) = self.split_lines(solver) { lines } else { return Vec::new(); };
+
+        let fills = OverlayLinkBuilder::fill_string(&lines.segments, fill_rule, solver);
+
+        let mut edges = Vec::new();
+        for (index, (s, &fill)) in lines.segments.iter().zip(fills.iter()).enumerate() {
+            if fill & CLIP_BOTH == 0 {
+                continue;
+            }
+            let class = match (fill & SUBJ_BOTH).count_ones() {
+                0 => ClipClass::Outside,
+                1 => ClipClass::Boundary,
+                _ => ClipClass::Inside,
+            };
+            let (a, b) = (s.x_segment.a, s.x_segment.b);
+            for &(index, clip) in lines.get(index) {
+                // a string going from a to b is stored as back
+                if clip & STRING_BACK_CLIP != 0 {
+                    edges.push(Edge { index, class, a, b });
+                }
+                if clip & STRING_FORWARD_CLIP != 0 {
+                    edges.push(Edge { index, class, a: b, b: a });
+                }
+            }
+        }
+
+        edges.sort_unstable_by_key(|e| (e.index, e.class, e.a, e.b));
+
+        let mut paths = Vec::new();
+        let mut i = 0;
+        while i < edges.len() {
+            let first = &edges[i];
+            let n = edges[i..].iter().take_while(|e| e.index == first.index && e.class == first.class).count();
+            let group = &edges[i..i + n];
+            for path in join_directed(group) {
+                paths.push(ClassifiedPath { path, class: first.class, index: first.index });
+            }
+            i += n;
+        }
+
+        paths
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    index: usize,
+    class: ClipClass,
+    a: IntPoint,
+    b: IntPoint,
+}
+
+/// Joins directed edges of one line and class into paths, a path is broken at every node
+/// that has more than one way in or out.
+/// `edges` must be sorted by the start point.
+fn join_directed(edges: &[Edge]) -> Vec<Vec<IntPoint>> {
+    let mut ends: Vec<IntPoint> = edges.iter().map(|e| e.b).collect();
+    ends.sort_unstable();
+
+    let out_range = |p: IntPoint| {
+        let start = edges.partition_point(|e| e.a < p);
+        let end = edges.partition_point(|e| e.a <= p);
+        start..end
+    };
+    let in_count = |p: IntPoint| ends.partition_point(|&e| e <= p) - ends.partition_point(|&e| e < p);
+    let is_chain_node = |p: IntPoint| in_count(p) == 1 && out_range(p).len() == 1;
+
+    let mut visited = vec![false; edges.len()];
+    let mut paths = Vec::new();
+
+    let walk = |first: usize, visited: &mut [bool]| {
+        let mut path = vec![edges[first].a];
+        let mut i = first;
+        loop {
+            visited[i] = true;
+            let b = edges[i].b;
+            path.push(b);
+            if !is_chain_node(b) {
+                break;
+            }
+            i = out_range(b).start;
+            if visited[i] {
+                break;
+            }
+        }
+        path
+    };
+
+    for i in 0..edges.len() {
+        if !visited[i] && !is_chain_node(edges[i].a) {
+            paths.push(walk(i, &mut visited));
+        }
+    }
+
+    // the rest are loops
+    for i in 0..edges.len() {
+        if !visited[i] {
+            paths.push(walk(i, &mut visited));
+        }
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::fill_rule::FillRule;
+    use crate::core::solver::Solver;
+    use crate::string::classify::{ClassifiedPath, ClipClass};
+    use crate::string::overlay::StringOverlay;
+
+    fn square() -> vec::Vec<IntPoint> {
+        vec![IntPoint::new(0, 0), IntPoint::new(0, 10), IntPoint::new(10, 10), IntPoint::new(10, 0)]
+    }
+
+    #[test]
+    fn test_classes() {
+        for solver in [Solver::LIST, Solver::TREE, Solver::FRAG] {
+            let mut overlay = StringOverlay::with_shape_contour(&square());
+            overlay.add_string_line([IntPoint::new(15, 5), IntPoint::new(-5, 5)]);
+            overlay.add_string_path(&[IntPoint::new(0, -5), IntPoint::new(0, 15)]);
+
+            let result = overlay.clip_string_lines_classified_with_solver(FillRule::NonZero, solver);
+            assert_eq!(result, vec![
+                ClassifiedPath { path: vec![IntPoint::new(10, 5), IntPoint::new(0, 5)], class: ClipClass::Inside, index: 0 },
+                ClassifiedPath { path: vec![IntPoint::new(0, 5), IntPoint::new(-5, 5)], class: ClipClass::Outside, index: 0 },
+                ClassifiedPath { path: vec![IntPoint::new(15, 5), IntPoint::new(10, 5)], class: ClipClass::Outside, index: 0 },
+                ClassifiedPath { path: vec![IntPoint::new(0, -5), IntPoint::new(0, 0)], class: ClipClass::Outside, index: 1 },
+                ClassifiedPath { path: vec![IntPoint::new(0, 10), IntPoint::new(0, 15)], class: ClipClass::Outside, index: 1 },
+                ClassifiedPath { path: vec![IntPoint::new(0, 0), IntPoint::new(0, 5), IntPoint::new(0, 10)], class: ClipClass::Boundary, index: 1 },
+            ]);
+        }
+    }
+
+    #[test]
+    fn test_shared_and_closed() {
+        let mut overlay = StringOverlay::with_shape_contour(&square());
+        overlay.add_string_path(&[IntPoint::new(2, 2), IntPoint::new(8, 2)]);
+        // shares the part from 4 to 8 with the first line
+        overlay.add_string_path(&[IntPoint::new(4, 2), IntPoint::new(12, 2)]);
+        overlay.add_string_contour(&[IntPoint::new(3, 4), IntPoint::new(3, 8), IntPoint::new(7, 8)]);
+
+        let result = overlay.clip_string_lines_classified(FillRule::NonZero);
+        assert_eq!(result, vec![
+            ClassifiedPath { path: vec![IntPoint::new(2, 2), IntPoint::new(4, 2), IntPoint::new(8, 2)], class: ClipClass::Inside, index: 0 },
+            ClassifiedPath { path: vec![IntPoint::new(4, 2), IntPoint::new(8, 2), IntPoint::new(10, 2)], class: ClipClass::Inside, index: 1 },
+            ClassifiedPath { path: vec![IntPoint::new(10, 2), IntPoint::new(12, 2)], class: ClipClass::Outside, index: 1 },
+            ClassifiedPath {
+                path: vec![IntPoint::new(3, 4), IntPoint::new(3, 8), IntPoint::new(7, 8), IntPoint::new(3, 4)],
+                class: ClipClass::Inside,
+                index: 2,
+            },
+        ]);
+    }
+}
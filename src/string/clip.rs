@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use i_float::int::point::IntPoint;
 use i_shape::int::path::IntPath;
 use i_shape::int::shape::{IntShape, IntShapes};
@@ -11,6 +12,7 @@ use crate::string::line::IntLine;
 use crate::string::overlay::StringOverlay;
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClipRule {
     /// Configuration for clipping lines with rules to determine inclusion or exclusion based on boundary and inversion.
     /// - `invert`: If true, inverts the clipping area selection, affecting which lines are included in the output.
@@ -239,6 +241,7 @@ impl IntClip for [IntPoint] {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
     use i_float::int::point::IntPoint;
     use i_shape::int::path::IntPath;
     use crate::core::fill_rule::FillRule;
@@ -1,3 +1,5 @@
+use alloc::vec;
+use alloc::vec::Vec;
 use i_float::triangle::Triangle;
 use i_shape::int::path::IntPath;
 use i_shape::int::shape::IntShapes;
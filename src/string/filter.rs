@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use crate::string::rule::StringRule;
 use crate::string::graph::StringGraph;
 use crate::segm::segment::{CLIP_BOTH, SUBJ_BOTH, SUBJ_BOTTOM, SUBJ_TOP};
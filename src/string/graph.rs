@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use crate::core::graph::OverlayGraph;
 use crate::core::link::OverlayLink;
 use crate::core::node::OverlayNode;
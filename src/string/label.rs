@@ -0,0 +1,227 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use i_shape::int::shape::IntShape;
+use crate::core::fill_rule::FillRule;
+use crate::core::link::OverlayLinkBuilder;
+use crate::core::solver::Solver;
+use crate::segm::winding_count::{STRING_BACK_CLIP, STRING_FORWARD_CLIP};
+use crate::string::graph::StringGraph;
+use crate::string::overlay::StringOverlay;
+use crate::string::rule::StringRule;
+
+/// The side of a cutting path a sliced piece lies on. Left and right are taken looking along the path.
+/// - `Untouched`: The path is not a part of the piece boundary.
+/// - `Left`: The piece is bounded by the path and lies on its left side.
+/// - `Right`: The piece is bounded by the path and lies on its right side.
+/// - `Both`: The path bounds the piece from both sides, e.g. the path turns around the piece.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CutSide {
+    Untouched,
+    Left,
+    Right,
+    Both,
+}
+
+impl CutSide {
+    /// Returns `true` if the cutting path is a part of the piece boundary.
+    #[inline(always)]
+    pub fn is_bounding(&self) -> bool {
+        *self != CutSide::Untouched
+    }
+
+    #[inline(always)]
+    fn join(self, other: Self) -> Self {
+        match (self, other) {
+            (CutSide::Untouched, side) | (side, CutSide::Untouched) => side,
+            (a, b) if a == b => a,
+            _ => CutSide::Both,
+        }
+    }
+}
+
+/// A piece of a sliced shape with its side of every cutting path.
+/// - `shape`: The piece, the first contour is the outer boundary, the others are holes.
+/// - `sides`: The side of every cutting path, in the order the paths were given.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LabeledShape<S = IntShape> {
+    pub shape: S,
+    pub sides: Vec<CutSide>,
+}
+
+impl StringOverlay {
+    /// Slices the shapes by the string lines and labels every piece with its side of every line.
+    /// - `fill_rule`: Specifies the rule determining the filled areas.
+    /// # Returns
+    /// The pieces, `sides` of a piece follow the order the string lines were added in.
+    #[inline]
+    pub fn slice_labeled(self, fill_rule: FillRule) -> Vec<LabeledShape> {
+        self.slice_labeled_with_min_area_and_solver(fill_rule, 0, Default::default())
+    }
+
+    /// Slices the shapes by the string lines and labels every piece with its side of every line.
+    /// - `fill_rule`: Specifies the rule determining the filled areas.
+    /// - `min_area`: The minimum area that a piece must have to be included in the results.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The pieces, `sides` of a piece follow the order the string lines were added in.
+    ///
+    /// The split keeps the index and the direction of every line going along a piece,
+    /// so a piece edge lying on a link is labeled by the lines of that link.
+    pub fn slice_labeled_with_min_area_and_solver(self, fill_rule: FillRule, min_area: usize, solver: Solver) -> Vec<LabeledShape> {
+        let count = self.string_starts.len();
+        let lines = if let Some(lines) = self.split_lines(solver) { lines } else { return Vec::new(); };
+
+        // every link is built from the piece with the same index
+        let links = OverlayLinkBuilder::build_string_all_split(&lines.segments, fill_rule, solver);
+        let graph = StringGraph::new(solver, links);
+        let shapes = graph.extract_shapes_min_area(StringRule::Slice, min_area);
+
+        let mut cut_links: Vec<(IntPoint, IntPoint, usize)> = graph.links.iter().enumerate()
+            .filter(|&(i, _)| !lines.get(i).is_empty())
+            .map(|(i, link)| (link.a.point, link.b.point, i))
+            .collect();
+        cut_links.sort_unstable_by_key(|l| (l.0, l.1));
+
+        shapes.into_iter().map(|shape| {
+            let mut sides = vec![CutSide::Untouched; count];
+            for contour in shape.iter() {
+                let mut p = if let Some(&p) = contour.last() { p } else { continue; };
+                for &q in contour.iter() {
+                    let is_direct = p < q;
+                    let (a, b) = if is_direct { (p, q) } else { (q, p) };
+                    if let Ok(index) = cut_links.binary_search_by(|l| (l.0, l.1).cmp(&(a, b))) {
+                        label_edge(is_direct, lines.get(cut_links[index].2), &mut sides);
+                    }
+                    p = q;
+                }
+            }
+            LabeledShape { shape, sides }
+        }).collect()
+    }
+}
+
+/// Updates the sides of the lines going along a contour edge.
+/// - `is_direct`: The edge goes from the smaller end of the link to the larger one.
+/// - `lines`: The index and the directions of every line going along the link.
+fn label_edge(is_direct: bool, lines: &[(usize, u8)], sides: &mut [CutSide]) {
+    // a string going from the smaller end to the larger one is stored as back
+    let (along, against) = if is_direct {
+        (STRING_BACK_CLIP, STRING_FORWARD_CLIP)
+    } else {
+        (STRING_FORWARD_CLIP, STRING_BACK_CLIP)
+    };
+
+    // contours are clockwise and holes are counterclockwise, so the piece is on the right of the edge
+    for &(index, clip) in lines {
+        let side = &mut sides[index];
+        if clip & along != 0 {
+            *side = side.join(CutSide::Right);
+        }
+        if clip & against != 0 {
+            *side = side.join(CutSide::Left);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::fill_rule::FillRule;
+    use crate::string::label::CutSide;
+    use crate::string::slice::IntSlice;
+
+    fn square(x0: i32, y0: i32, x1: i32, y1: i32) -> vec::Vec<IntPoint> {
+        vec![IntPoint::new(x0, y0), IntPoint::new(x0, y1), IntPoint::new(x1, y1), IntPoint::new(x1, y0)]
+    }
+
+    fn max_point(contour: &[IntPoint]) -> IntPoint {
+        *contour.iter().max().unwrap()
+    }
+
+    #[test]
+    fn test_one_line() {
+        let lines = [[IntPoint::new(-5, 5), IntPoint::new(15, 5)]];
+        let pieces = square(0, 0, 10, 10).slice_by_lines_labeled(&lines, FillRule::NonZero);
+
+        assert_eq!(pieces.len(), 2);
+        for piece in pieces.iter() {
+            let is_top = piece.shape[0].iter().all(|p| p.y >= 5);
+            let expected = if is_top { CutSide::Left } else { CutSide::Right };
+            assert_eq!(piece.sides, vec![expected]);
+        }
+    }
+
+    #[test]
+    fn test_cross() {
+        let lines = [
+            [IntPoint::new(-5, 5), IntPoint::new(15, 5)],
+            [IntPoint::new(5, 15), IntPoint::new(5, -5)],
+            [IntPoint::new(20, 0), IntPoint::new(20, 10)],
+        ];
+        let pieces = square(0, 0, 10, 10).slice_by_lines_labeled(&lines, FillRule::NonZero);
+
+        assert_eq!(pieces.len(), 4);
+        for piece in pieces.iter() {
+            let max = max_point(&piece.shape[0]);
+            let horizontal = if max.y == 10 { CutSide::Left } else { CutSide::Right };
+            // the vertical line goes down, so its left side is on the right
+            let vertical = if max.x == 10 { CutSide::Left } else { CutSide::Right };
+            assert_eq!(piece.sides, vec![horizontal, vertical, CutSide::Untouched]);
+        }
+    }
+
+    #[test]
+    fn test_shared_part() {
+        // the second line goes back along a part of the first one
+        let lines = [
+            [IntPoint::new(-5, 5), IntPoint::new(15, 5)],
+            [IntPoint::new(8, 5), IntPoint::new(2, 5)],
+        ];
+        let pieces = square(0, 0, 10, 10).slice_by_lines_labeled(&lines, FillRule::NonZero);
+
+        assert_eq!(pieces.len(), 2);
+        for piece in pieces.iter() {
+            let is_top = piece.shape[0].iter().all(|p| p.y >= 5);
+            let expected = if is_top { [CutSide::Left, CutSide::Right] } else { [CutSide::Right, CutSide::Left] };
+            assert_eq!(piece.sides, expected.to_vec());
+        }
+    }
+
+    #[test]
+    fn test_dangling_and_hole() {
+        // the path enters the square and returns the same way, it does not split the square
+        let path = vec![IntPoint::new(-5, 5), IntPoint::new(5, 5), IntPoint::new(-5, 5)];
+        let pieces = square(0, 0, 10, 10).slice_by_paths_labeled(&[path], FillRule::NonZero);
+        assert_eq!(pieces.len(), 1);
+        assert_eq!(pieces[0].sides, vec![CutSide::Untouched]);
+
+        // a path cutting a square with a hole
+        let shape = vec![square(0, 0, 30, 30), square(10, 10, 20, 20).into_iter().rev().collect()];
+        let path = vec![IntPoint::new(15, -5), IntPoint::new(15, 35)];
+        let pieces = shape.slice_by_paths_labeled(&[path], FillRule::NonZero);
+        assert_eq!(pieces.len(), 2);
+        for piece in pieces.iter() {
+            let is_left = piece.shape[0].iter().all(|p| p.x <= 15);
+            let expected = if is_left { CutSide::Left } else { CutSide::Right };
+            assert!(piece.sides[0].is_bounding());
+            assert_eq!(piece.sides, vec![expected]);
+        }
+    }
+
+    #[test]
+    fn test_u_turn() {
+        let path = vec![IntPoint::new(-5, 3), IntPoint::new(5, 3), IntPoint::new(5, 7), IntPoint::new(-5, 7)];
+        let pieces = square(0, 0, 10, 10).slice_by_paths_labeled(&[path], FillRule::NonZero);
+
+        assert_eq!(pieces.len(), 2);
+        for piece in pieces.iter() {
+            let is_inner = max_point(&piece.shape[0]) == IntPoint::new(5, 7);
+            let expected = if is_inner { CutSide::Left } else { CutSide::Right };
+            assert_eq!(piece.sides, vec![expected]);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Splits string lines and shapes while keeping the index of the line every split piece comes from.
+
+use alloc::vec::Vec;
+use crate::core::overlay::ShapeType;
+use crate::core::solver::Solver;
+use crate::segm::segment::Segment;
+use crate::segm::winding_count::{ShapeCountString, WindingCount};
+use crate::split::solver::SplitSegments;
+use crate::string::overlay::StringOverlay;
+
+const NONE: usize = usize::MAX;
+
+impl StringOverlay {
+    /// Splits the segments and collects the lines that go along every piece.
+    /// Returns `None` if there is nothing to split.
+    pub(super) fn split_lines(self, solver: Solver) -> Option<LinePieces> {
+        let starts = self.string_starts;
+        let segments: Vec<Segment<IndexedCount>> = self.segments.into_iter().enumerate()
+            .map(|(i, s)| {
+                let index = if s.count.subj == 0 {
+                    starts.partition_point(|&start| start <= i) - 1
+                } else {
+                    NONE
+                };
+                Segment { x_segment: s.x_segment, count: IndexedCount { count: s.count, index } }
+            })
+            .collect();
+
+        if segments.is_empty() {
+            return None;
+        }
+
+        let pieces = segments.split_segments(solver);
+        if pieces.is_empty() {
+            return None;
+        }
+
+        Some(LinePieces::new(&pieces))
+    }
+}
+
+/// The distinct split pieces with the lines that go along every piece.
+pub(super) struct LinePieces {
+    /// The pieces sorted, with the summed shape count and the directions of all lines.
+    pub(super) segments: Vec<Segment<ShapeCountString>>,
+    offsets: Vec<usize>,
+    /// The line index and its directions along the piece.
+    lines: Vec<(usize, u8)>,
+}
+
+impl LinePieces {
+    /// Merges the equal pieces, the split keeps them apart to keep the index of every line.
+    fn new(pieces: &[Segment<IndexedCount>]) -> Self {
+        let mut segments = Vec::with_capacity(pieces.len());
+        let mut offsets = Vec::with_capacity(pieces.len() + 1);
+        offsets.push(0);
+        let mut lines: Vec<(usize, u8)> = Vec::new();
+
+        let mut i = 0;
+        while i < pieces.len() {
+            let x_segment = pieces[i].x_segment;
+            let mut count = ShapeCountString::new(0, 0);
+            let start = lines.len();
+            while i < pieces.len() && pieces[i].x_segment == x_segment {
+                let piece = pieces[i].count;
+                count = count.add(piece.count);
+                if piece.index != NONE {
+                    lines.push((piece.index, piece.count.clip));
+                }
+                i += 1;
+            }
+
+            // a line that goes along the piece twice keeps both directions
+            lines[start..].sort_unstable_by_key(|&(index, _)| index);
+            let mut end = start;
+            for k in start..lines.len() {
+                if end > start && lines[end - 1].0 == lines[k].0 {
+                    lines[end - 1].1 |= lines[k].1;
+                } else {
+                    lines[end] = lines[k];
+                    end += 1;
+                }
+            }
+            lines.truncate(end);
+
+            segments.push(Segment { x_segment, count });
+            offsets.push(lines.len());
+        }
+
+        Self { segments, offsets, lines }
+    }
+
+    #[inline]
+    pub(super) fn get(&self, index: usize) -> &[(usize, u8)] {
+        &self.lines[self.offsets[index]..self.offsets[index + 1]]
+    }
+}
+
+/// The string count with the index of the line it comes from, shape segments have no index.
+/// The split keeps equal pieces apart, so every line keeps its own pieces.
+#[derive(Debug, Clone, Copy)]
+struct IndexedCount {
+    count: ShapeCountString,
+    index: usize,
+}
+
+impl WindingCount for IndexedCount {
+    const IS_MERGEABLE: bool = false;
+
+    #[inline(always)]
+    fn is_not_empty(&self) -> bool {
+        self.count.is_not_empty()
+    }
+
+    #[inline(always)]
+    fn new(subj: i32, clip: i32) -> Self {
+        Self { count: ShapeCountString::new(subj, clip), index: NONE }
+    }
+
+    #[inline(always)]
+    fn with_shape_type(shape_type: ShapeType) -> (Self, Self) {
+        let (direct, invert) = ShapeCountString::with_shape_type(shape_type);
+        (Self { count: direct, index: NONE }, Self { count: invert, index: NONE })
+    }
+
+    #[inline(always)]
+    fn add(self, count: Self) -> Self {
+        // only summed to drop empty pieces, the lines are collected by `LinePieces`
+        Self { count: self.count.add(count.count), index: self.index.min(count.index) }
+    }
+
+    #[inline(always)]
+    fn apply(&mut self, count: Self) {
+        *self = self.add(count);
+    }
+
+    #[inline(always)]
+    fn invert(self) -> Self {
+        Self { count: self.count.invert(), index: self.index }
+    }
+}
@@ -5,5 +5,10 @@ pub mod overlay;
 pub mod graph;
 pub mod split;
 pub mod clip;
+pub mod classify;
+pub mod source;
 pub mod extract;
+pub mod network;
+pub mod label;
+mod lines;
 mod filter;
\ No newline at end of file
@@ -0,0 +1,322 @@
+//! This module overlays open polylines with open polylines.
+//!
+//! `LineNetwork` nodes the subject and clip lines against each other, merges overlapping collinear
+//! pieces and keeps the pieces selected by an `OverlayRule`. The rule is applied to the lines
+//! themselves: `Intersect` keeps the pieces shared by both sets, `Difference` keeps the pieces of the
+//! subject that are not covered by the clip and so on. The result is a planar network: its paths
+//! touch each other only at their end points.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::int::point::IntPoint;
+use i_shape::int::path::IntPath;
+use crate::core::overlay::ShapeType;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::segm::segment::Segment;
+use crate::segm::winding_count::WindingCount;
+use crate::split::solver::SplitSegments;
+use crate::string::line::IntLine;
+
+/// Collects subject and clip lines to overlay them with each other.
+#[derive(Clone)]
+pub struct LineNetwork {
+    segments: Vec<Segment<Membership>>,
+}
+
+impl LineNetwork {
+    /// Constructs a new `LineNetwork`.
+    /// - `capacity`: The expected number of segments.
+    pub fn new(capacity: usize) -> Self {
+        Self { segments: Vec::with_capacity(capacity) }
+    }
+
+    /// Creates a new `LineNetwork` with subject and clip lines.
+    /// - `subj_paths`: An array of open paths for the subject.
+    /// - `clip_paths`: An array of open paths for the clip.
+    pub fn with_lines(subj_paths: &[IntPath], clip_paths: &[IntPath]) -> Self {
+        let capacity = subj_paths.iter().chain(clip_paths.iter()).map(|path| path.len()).sum();
+        let mut network = Self::new(capacity);
+        network.add_paths(subj_paths, ShapeType::Subject);
+        network.add_paths(clip_paths, ShapeType::Clip);
+        network
+    }
+
+    /// Adds a single line, a zero length line is ignored.
+    /// - `line`: An `IntLine` defined by two points.
+    /// - `shape_type`: Specifies the role of the line, either `Subject` or `Clip`.
+    #[inline]
+    pub fn add_line(&mut self, line: IntLine, shape_type: ShapeType) {
+        let [a, b] = line;
+        if a != b {
+            let (count, _) = Membership::with_shape_type(shape_type);
+            self.segments.push(Segment::create_and_validate(a, b, count));
+        }
+    }
+
+    /// Adds an open path, every pair of consecutive points is a line.
+    /// - `path`: The points of the path.
+    /// - `shape_type`: Specifies the role of the path, either `Subject` or `Clip`.
+    pub fn add_path(&mut self, path: &[IntPoint], shape_type: ShapeType) {
+        for pair in path.windows(2) {
+            self.add_line([pair[0], pair[1]], shape_type);
+        }
+    }
+
+    /// Adds multiple open paths.
+    /// - `paths`: An array of open paths.
+    /// - `shape_type`: Specifies the role of the paths, either `Subject` or `Clip`.
+    pub fn add_paths(&mut self, paths: &[IntPath], shape_type: ShapeType) {
+        for path in paths.iter() {
+            self.add_path(path, shape_type);
+        }
+    }
+
+    /// Overlays the lines with the default solver.
+    /// - `overlay_rule`: The operation applied to the lines, `Union` dissolves both sets into one network.
+    /// - `join_chains`: If `true`, pieces meeting at a node of degree 2 are joined into one path,
+    ///   otherwise every piece between two nodes is returned as a separate two point path.
+    /// # Returns
+    /// A vector of open paths. A closed ring without other nodes is returned as a path whose
+    /// last point repeats the first one.
+    #[inline]
+    pub fn overlay(self, overlay_rule: OverlayRule, join_chains: bool) -> Vec<IntPath> {
+        self.overlay_with_solver(overlay_rule, join_chains, Default::default())
+    }
+
+    /// Overlays the lines.
+    /// - `overlay_rule`: The operation applied to the lines, `Union` dissolves both sets into one network.
+    /// - `join_chains`: If `true`, pieces meeting at a node of degree 2 are joined into one path,
+    ///   otherwise every piece between two nodes is returned as a separate two point path.
+    /// - `solver`: Type of solver to use.
+    pub fn overlay_with_solver(self, overlay_rule: OverlayRule, join_chains: bool, solver: Solver) -> Vec<IntPath> {
+        if self.segments.is_empty() {
+            return Vec::new();
+        }
+
+        let edges: Vec<[IntPoint; 2]> = self.segments
+            .split_segments(solver)
+            .into_iter()
+            .filter(|s| s.count.is_selected(overlay_rule))
+            .map(|s| [s.x_segment.a, s.x_segment.b])
+            .collect();
+
+        if join_chains {
+            join(&edges)
+        } else {
+            edges.into_iter().map(|e| e.to_vec()).collect()
+        }
+    }
+}
+
+/// Joins edges through the nodes of degree 2.
+/// `edges` must be sorted, so the first edge of a ring starts at the smallest point of this ring.
+pub(crate) fn join(edges: &[[IntPoint; 2]]) -> Vec<IntPath> {
+    let mut nodes: Vec<IntPoint> = edges.iter().flatten().copied().collect();
+    nodes.sort_unstable();
+    nodes.dedup();
+
+    let ends: Vec<[usize; 2]> = edges.iter()
+        .map(|e| e.map(|p| nodes.binary_search(&p).unwrap()))
+        .collect();
+
+    // adjacency lists of the nodes in one buffer
+    let mut offsets = vec![0; nodes.len() + 1];
+    for &[a, b] in ends.iter() {
+        offsets[a + 1] += 1;
+        offsets[b + 1] += 1;
+    }
+    for i in 1..offsets.len() {
+        offsets[i] += offsets[i - 1];
+    }
+    let mut fill = offsets.clone();
+    let mut adjacency = vec![0; 2 * ends.len()];
+    for (e, &[a, b]) in ends.iter().enumerate() {
+        adjacency[fill[a]] = e;
+        fill[a] += 1;
+        adjacency[fill[b]] = e;
+        fill[b] += 1;
+    }
+
+    let degree = |n: usize| offsets[n + 1] - offsets[n];
+    let other = |e: usize, n: usize| if ends[e][0] == n { ends[e][1] } else { ends[e][0] };
+
+    let mut visited = vec![false; edges.len()];
+    let mut paths = Vec::new();
+
+    let walk = |start: usize, first_edge: usize, visited: &mut [bool]| {
+        let mut path = vec![nodes[start]];
+        let mut node = start;
+        let mut e = first_edge;
+        loop {
+            visited[e] = true;
+            node = other(e, node);
+            path.push(nodes[node]);
+            if degree(node) != 2 || node == start {
+                break;
+            }
+            let next = adjacency[offsets[node]..offsets[node + 1]].iter().copied().find(|&i| !visited[i]);
+            match next {
+                Some(i) => e = i,
+                None => break,
+            }
+        }
+        path
+    };
+
+    for n in 0..nodes.len() {
+        if degree(n) == 2 {
+            continue;
+        }
+        for &e in adjacency[offsets[n]..offsets[n + 1]].iter() {
+            if !visited[e] {
+                paths.push(walk(n, e, &mut visited));
+            }
+        }
+    }
+
+    // the rest are rings with nodes of degree 2 only
+    for e in 0..edges.len() {
+        if !visited[e] {
+            paths.push(walk(ends[e][0], e, &mut visited));
+        }
+    }
+
+    paths
+}
+
+const SUBJ: u8 = 1;
+const CLIP: u8 = 2;
+const BOTH: u8 = SUBJ | CLIP;
+
+/// The sets a piece of the network belongs to. Equal pieces are merged and their sets are united.
+#[derive(Debug, Clone, Copy)]
+struct Membership(u8);
+
+impl Membership {
+    #[inline(always)]
+    fn is_selected(&self, overlay_rule: OverlayRule) -> bool {
+        match overlay_rule {
+            OverlayRule::Subject => self.0 & SUBJ == SUBJ,
+            OverlayRule::Clip => self.0 & CLIP == CLIP,
+            OverlayRule::Intersect => self.0 == BOTH,
+            OverlayRule::Union => true,
+            OverlayRule::Difference => self.0 == SUBJ,
+            OverlayRule::InverseDifference => self.0 == CLIP,
+            OverlayRule::Xor => self.0 != BOTH,
+        }
+    }
+}
+
+impl WindingCount for Membership {
+    #[inline(always)]
+    fn is_not_empty(&self) -> bool {
+        // a piece always belongs to at least one set
+        true
+    }
+
+    #[inline(always)]
+    fn new(subj: i32, clip: i32) -> Self {
+        let subj = if subj != 0 { SUBJ } else { 0 };
+        let clip = if clip != 0 { CLIP } else { 0 };
+        Self(subj | clip)
+    }
+
+    #[inline(always)]
+    fn with_shape_type(shape_type: ShapeType) -> (Self, Self) {
+        let count = match shape_type {
+            ShapeType::Subject => Self(SUBJ),
+            ShapeType::Clip => Self(CLIP),
+        };
+        (count, count)
+    }
+
+    #[inline(always)]
+    fn add(self, count: Self) -> Self {
+        Self(self.0 | count.0)
+    }
+
+    #[inline(always)]
+    fn apply(&mut self, count: Self) {
+        self.0 |= count.0;
+    }
+
+    #[inline(always)]
+    fn invert(self) -> Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::overlay::ShapeType;
+    use crate::core::overlay_rule::OverlayRule;
+    use crate::core::solver::Solver;
+    use crate::string::network::LineNetwork;
+
+    #[test]
+    fn test_cross() {
+        let subj = vec![vec![IntPoint::new(0, 0), IntPoint::new(10, 0)]];
+        let clip = vec![vec![IntPoint::new(5, -5), IntPoint::new(5, 5)]];
+
+        for solver in [Solver::LIST, Solver::TREE, Solver::FRAG] {
+            let result = LineNetwork::with_lines(&subj, &clip).overlay_with_solver(OverlayRule::Union, false, solver);
+            assert_eq!(result, vec![
+                vec![IntPoint::new(0, 0), IntPoint::new(5, 0)],
+                vec![IntPoint::new(5, -5), IntPoint::new(5, 0)],
+                vec![IntPoint::new(5, 0), IntPoint::new(5, 5)],
+                vec![IntPoint::new(5, 0), IntPoint::new(10, 0)],
+            ]);
+        }
+    }
+
+    #[test]
+    fn test_overlap_rules() {
+        let subj = vec![vec![IntPoint::new(0, 0), IntPoint::new(10, 0)]];
+        let clip = vec![vec![IntPoint::new(5, 0), IntPoint::new(15, 0)]];
+
+        let intersect = LineNetwork::with_lines(&subj, &clip).overlay(OverlayRule::Intersect, true);
+        assert_eq!(intersect, vec![vec![IntPoint::new(5, 0), IntPoint::new(10, 0)]]);
+
+        let difference = LineNetwork::with_lines(&subj, &clip).overlay(OverlayRule::Difference, true);
+        assert_eq!(difference, vec![vec![IntPoint::new(0, 0), IntPoint::new(5, 0)]]);
+
+        let xor = LineNetwork::with_lines(&subj, &clip).overlay(OverlayRule::Xor, true);
+        assert_eq!(xor, vec![
+            vec![IntPoint::new(0, 0), IntPoint::new(5, 0)],
+            vec![IntPoint::new(10, 0), IntPoint::new(15, 0)],
+        ]);
+
+        // collinear pieces are dissolved and the chain is joined through the degree 2 nodes
+        let union = LineNetwork::with_lines(&subj, &clip).overlay(OverlayRule::Union, true);
+        assert_eq!(union, vec![vec![IntPoint::new(0, 0), IntPoint::new(5, 0), IntPoint::new(10, 0), IntPoint::new(15, 0)]]);
+    }
+
+    #[test]
+    fn test_join() {
+        // a T junction: the chains stop at the node of degree 3 and start from the smallest end node
+        let mut network = LineNetwork::new(4);
+        network.add_path(&[IntPoint::new(0, 0), IntPoint::new(0, 10), IntPoint::new(10, 10)], ShapeType::Subject);
+        network.add_path(&[IntPoint::new(5, 10), IntPoint::new(5, 20)], ShapeType::Subject);
+
+        let result = network.overlay(OverlayRule::Subject, true);
+        assert_eq!(result, vec![
+            vec![IntPoint::new(0, 0), IntPoint::new(0, 10), IntPoint::new(5, 10)],
+            vec![IntPoint::new(5, 10), IntPoint::new(5, 20)],
+            vec![IntPoint::new(5, 10), IntPoint::new(10, 10)],
+        ]);
+    }
+
+    #[test]
+    fn test_ring() {
+        let ring = vec![
+            IntPoint::new(0, 0), IntPoint::new(0, 10), IntPoint::new(10, 10), IntPoint::new(10, 0), IntPoint::new(0, 0),
+        ];
+        let result = LineNetwork::with_lines(&[ring], &[]).overlay(OverlayRule::Union, true);
+        assert_eq!(result, vec![vec![
+            IntPoint::new(0, 0), IntPoint::new(0, 10), IntPoint::new(10, 10), IntPoint::new(10, 0), IntPoint::new(0, 0),
+        ]]);
+    }
+}
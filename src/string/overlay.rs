@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use i_float::int::point::IntPoint;
 use i_shape::int::count::PointsCount;
 use i_shape::int::path::IntPath;
@@ -17,6 +18,12 @@ use crate::string::line::IntLine;
 #[derive(Clone)]
 pub struct StringOverlay {
     pub(super) segments: Vec<Segment<ShapeCountString>>,
+    // the first segment of every added string line, paths and contours count as one line
+    pub(super) string_starts: Vec<usize>,
+    // the id of every added string line
+    pub(super) string_ids: Vec<usize>,
+    // the id of the next line added without an id, it is greater than every id used before
+    next_string_id: usize,
 }
 
 impl StringOverlay {
@@ -27,6 +34,9 @@ impl StringOverlay {
     pub fn new(capacity: usize) -> Self {
         Self {
             segments: Vec::with_capacity(capacity),
+            string_starts: Vec::new(),
+            string_ids: Vec::new(),
+            next_string_id: 0,
         }
     }
 
@@ -103,12 +113,34 @@ impl StringOverlay {
     /// - `line`: An `IntLine` representing the open line (defined by two points).
     #[inline]
     pub fn add_string_line(&mut self, line: IntLine) {
+        self.add_string_line_with_id(line, self.next_string_id);
+    }
+
+    /// Adds a single line (open path) to the overlay and tags it with an id.
+    /// - `line`: An `IntLine` representing the open line (defined by two points).
+    /// - `id`: The id of the line, the lines added without an id get an id greater than every id used before them.
+    ///   Lines with the same id are grouped together.
+    #[inline]
+    pub fn add_string_line_with_id(&mut self, line: IntLine, id: usize) {
+        self.begin_string(id);
+        self.push_string_line(line);
+    }
+
+    #[inline]
+    fn begin_string(&mut self, id: usize) {
+        self.string_starts.push(self.segments.len());
+        self.string_ids.push(id);
+        self.next_string_id = self.next_string_id.max(id.saturating_add(1));
+    }
+
+    #[inline]
+    fn push_string_line(&mut self, line: IntLine) {
         let a = line[0];
         let b = line[1];
         let segment = match a.cmp(&b) {
-            std::cmp::Ordering::Less => Segment { x_segment: XSegment { a, b }, count: ShapeCountString { subj: 0, clip: STRING_BACK_CLIP } },
-            std::cmp::Ordering::Greater => Segment { x_segment: XSegment { a: b, b: a }, count: ShapeCountString { subj: 0, clip: STRING_FORWARD_CLIP } },
-            std::cmp::Ordering::Equal => return,
+            core::cmp::Ordering::Less => Segment { x_segment: XSegment { a, b }, count: ShapeCountString { subj: 0, clip: STRING_BACK_CLIP } },
+            core::cmp::Ordering::Greater => Segment { x_segment: XSegment { a: b, b: a }, count: ShapeCountString { subj: 0, clip: STRING_FORWARD_CLIP } },
+            core::cmp::Ordering::Equal => return,
         };
 
         self.segments.push(segment);
@@ -127,12 +159,34 @@ impl StringOverlay {
     /// - `path`: A path representing a string line.
     #[inline]
     pub fn add_string_path(&mut self, path: &[IntPoint]) {
-        if path.len() < 2 {
-            return;
-        }
-        let mut a = if let Some(&p) = path.first() { p } else { return; };
-        for &b in path.iter().skip(1) {
-            self.add_string_line([a, b]);
+        self.add_string_path_iter(path.iter().copied());
+    }
+
+    /// Adds a string path to the overlay and tags it with an id.
+    /// - `path`: A path representing a string line.
+    /// - `id`: The id of the path, the lines added without an id get an id greater than every id used before them.
+    ///   Lines with the same id are grouped together.
+    #[inline]
+    pub fn add_string_path_with_id(&mut self, path: &[IntPoint], id: usize) {
+        self.add_string_path_iter_with_id(path.iter().copied(), id);
+    }
+
+    /// Adds a string path to the overlay using an iterator.
+    /// - `iter`: An iterator over the points of a path representing a string line.
+    #[inline]
+    pub fn add_string_path_iter<I: Iterator<Item=IntPoint>>(&mut self, iter: I) {
+        self.add_string_path_iter_with_id(iter, self.next_string_id);
+    }
+
+    /// Adds a string path to the overlay using an iterator and tags it with an id.
+    /// - `iter`: An iterator over the points of a path representing a string line.
+    /// - `id`: The id of the path, the lines added without an id get an id greater than every id used before them.
+    ///   Lines with the same id are grouped together.
+    pub fn add_string_path_iter_with_id<I: Iterator<Item=IntPoint>>(&mut self, mut iter: I, id: usize) {
+        self.begin_string(id);
+        let mut a = if let Some(p) = iter.next() { p } else { return; };
+        for b in iter {
+            self.push_string_line([a, b]);
             a = b;
         }
     }
@@ -141,12 +195,13 @@ impl StringOverlay {
     /// - `contour`: A contour representing a string line closed path. This path is interpreted as closed, so it doesn’t require the start and endpoint to be the same for processing.
     #[inline]
     pub fn add_string_contour(&mut self, contour: &[IntPoint]) {
+        self.begin_string(self.next_string_id);
         if contour.len() < 2 {
             return;
         }
         let mut a = if let Some(&p) = contour.last() { p } else { return; };
         for &b in contour.iter() {
-            self.add_string_line([a, b]);
+            self.push_string_line([a, b]);
             a = b;
         }
     }
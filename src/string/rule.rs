@@ -1,6 +1,7 @@
 use crate::segm::segment::{SegmentFill, SUBJ_BOTTOM};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StringRule {
     Slice
 }
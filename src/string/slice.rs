@@ -1,7 +1,9 @@
+use alloc::vec::Vec;
 use i_float::int::point::IntPoint;
 use i_shape::int::path::IntPath;
 use i_shape::int::shape::{IntShape, IntShapes};
 use crate::core::fill_rule::FillRule;
+use crate::string::label::LabeledShape;
 use crate::string::line::IntLine;
 use crate::string::overlay::StringOverlay;
 use crate::string::rule::StringRule;
@@ -11,6 +13,16 @@ pub trait IntSlice {
     fn slice_by_lines(&self, lines: &[IntLine], fill_rule: FillRule) -> IntShapes;
     fn slice_by_path(&self, path: &IntPath, fill_rule: FillRule) -> IntShapes;
     fn slice_by_paths(&self, paths: &[IntPath], fill_rule: FillRule) -> IntShapes;
+
+    /// Slices the shapes by lines and labels every piece with its side of every line.
+    /// - `lines`: The cutting lines, `sides` of a piece follow their order.
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    fn slice_by_lines_labeled(&self, lines: &[IntLine], fill_rule: FillRule) -> Vec<LabeledShape>;
+
+    /// Slices the shapes by paths and labels every piece with its side of every path.
+    /// - `paths`: The cutting paths, `sides` of a piece follow their order.
+    /// - `fill_rule`: Fill rule to determine filled areas (non-zero, even-odd, positive, negative).
+    fn slice_by_paths_labeled(&self, paths: &[IntPath], fill_rule: FillRule) -> Vec<LabeledShape>;
 }
 
 impl IntSlice for IntShapes {
@@ -41,6 +53,19 @@ impl IntSlice for IntShapes {
         overlay.add_string_paths(paths);
         overlay.into_graph(fill_rule).extract_shapes(StringRule::Slice)
     }
+
+    #[inline]
+    fn slice_by_lines_labeled(&self, lines: &[IntLine], fill_rule: FillRule) -> Vec<LabeledShape> {
+        let paths: Vec<IntPath> = lines.iter().map(|line| line.to_vec()).collect();
+        self.slice_by_paths_labeled(&paths, fill_rule)
+    }
+
+    #[inline]
+    fn slice_by_paths_labeled(&self, paths: &[IntPath], fill_rule: FillRule) -> Vec<LabeledShape> {
+        let mut overlay = StringOverlay::with_shapes(self);
+        overlay.add_string_paths(paths);
+        overlay.slice_labeled(fill_rule)
+    }
 }
 
 impl IntSlice for IntShape {
@@ -71,6 +96,19 @@ impl IntSlice for IntShape {
         overlay.add_string_paths(paths);
         overlay.into_graph(fill_rule).extract_shapes(StringRule::Slice)
     }
+
+    #[inline]
+    fn slice_by_lines_labeled(&self, lines: &[IntLine], fill_rule: FillRule) -> Vec<LabeledShape> {
+        let paths: Vec<IntPath> = lines.iter().map(|line| line.to_vec()).collect();
+        self.slice_by_paths_labeled(&paths, fill_rule)
+    }
+
+    #[inline]
+    fn slice_by_paths_labeled(&self, paths: &[IntPath], fill_rule: FillRule) -> Vec<LabeledShape> {
+        let mut overlay = StringOverlay::with_shape(self);
+        overlay.add_string_paths(paths);
+        overlay.slice_labeled(fill_rule)
+    }
 }
 
 impl IntSlice for [IntPoint] {
@@ -101,4 +139,17 @@ impl IntSlice for [IntPoint] {
         overlay.add_string_paths(paths);
         overlay.into_graph(fill_rule).extract_shapes(StringRule::Slice)
     }
+
+    #[inline]
+    fn slice_by_lines_labeled(&self, lines: &[IntLine], fill_rule: FillRule) -> Vec<LabeledShape> {
+        let paths: Vec<IntPath> = lines.iter().map(|line| line.to_vec()).collect();
+        self.slice_by_paths_labeled(&paths, fill_rule)
+    }
+
+    #[inline]
+    fn slice_by_paths_labeled(&self, paths: &[IntPath], fill_rule: FillRule) -> Vec<LabeledShape> {
+        let mut overlay = StringOverlay::with_shape_contour(self);
+        overlay.add_string_paths(paths);
+        overlay.slice_labeled(fill_rule)
+    }
 }
\ No newline at end of file
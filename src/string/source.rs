@@ -0,0 +1,261 @@
+//! Clips string lines by shapes and keeps every part linked to the line it comes from.
+//!
+//! The parts are grouped by the id of their input line and never joined with parts of other lines.
+//! Every part also has its range along the input line: the distance from the first point of the line
+//! to the first and to the last point of the part.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use i_float::float::number::FloatNumber;
+use i_float::int::point::IntPoint;
+use crate::core::fill_rule::FillRule;
+use crate::core::solver::Solver;
+use crate::segm::winding_count::STRING_BACK_CLIP;
+use crate::string::classify::ClipClass;
+use crate::string::clip::ClipRule;
+use crate::string::overlay::StringOverlay;
+
+/// A part of an input string line.
+/// - `path`: The points of the part, in the direction of the input line.
+/// - `range`: The distances along the input line from its first point to the first and to the last point of the part.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SourcePiece<P = IntPoint, T = f64> {
+    pub path: Vec<P>,
+    pub range: [T; 2],
+}
+
+/// The parts of the input lines with the same id.
+/// - `id`: The id the lines were added with.
+/// - `pieces`: The parts sorted by their range.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SourcePieces<P = IntPoint, T = f64> {
+    pub id: usize,
+    pub pieces: Vec<SourcePiece<P, T>>,
+}
+
+impl ClipRule {
+    #[inline]
+    pub(crate) fn is_included(&self, class: ClipClass) -> bool {
+        match class {
+            ClipClass::Inside => !self.invert,
+            ClipClass::Outside => self.invert,
+            ClipClass::Boundary => self.invert != self.boundary_included,
+        }
+    }
+}
+
+impl StringOverlay {
+    /// Clips the string lines and groups the result by the id of the input lines.
+    /// - `fill_rule`: Specifies the rule determining the filled areas.
+    /// - `clip_rule`: The rule for clipping, determining how the boundary and inversion settings affect the result.
+    /// # Returns
+    /// The groups sorted by id. A part shared by several lines is returned for every one of them.
+    #[inline]
+    pub fn clip_string_lines_by_source(self, fill_rule: FillRule, clip_rule: ClipRule) -> Vec<SourcePieces> {
+        self.clip_string_lines_by_source_with_solver(fill_rule, clip_rule, Default::default())
+    }
+
+    /// Clips the string lines and groups the result by the id of the input lines.
+    /// - `fill_rule`: Specifies the rule determining the filled areas.
+    /// - `clip_rule`: The rule for clipping, determining how the boundary and inversion settings affect the result.
+    /// - `solver`: Type of solver to use.
+    /// # Returns
+    /// The groups sorted by id. A part shared by several lines is returned for every one of them.
+    pub fn clip_string_lines_by_source_with_solver(self, fill_rule: FillRule, clip_rule: ClipRule, solver: Solver) -> Vec<SourcePieces> {
+        let lines = self.string_geometry();
+        let ids = self.string_ids.clone();
+
+        let parts = self.clip_string_lines_classified_with_solver(fill_rule, solver);
+
+        let mut pieces: Vec<(usize, SourcePiece)> = parts.into_iter()
+            .filter(|part| clip_rule.is_included(part.class))
+            .map(|part| {
+                let range = lines[part.index].range(&part.path);
+                (ids[part.index], SourcePiece { path: part.path, range })
+            })
+            .collect();
+
+        pieces.sort_by(|(id0, p0), (id1, p1)| id0.cmp(id1).then(p0.range[0].total_cmp(&p1.range[0])));
+
+        let mut groups: Vec<SourcePieces> = Vec::new();
+        for (id, piece) in pieces.into_iter() {
+            match groups.last_mut() {
+                Some(group) if group.id == id => group.pieces.push(piece),
+                _ => groups.push(SourcePieces { id, pieces: vec![piece] }),
+            }
+        }
+
+        groups
+    }
+
+    /// Restores the directed segments of every string line.
+    fn string_geometry(&self) -> Vec<LineGeometry> {
+        let n = self.string_starts.len();
+        (0..n).map(|i| {
+            let start = self.string_starts[i];
+            let end = self.string_starts.get(i + 1).copied().unwrap_or(self.segments.len());
+            let mut length = 0.0;
+            let segments = self.segments[start..end].iter()
+                .filter(|s| s.count.subj == 0)
+                .map(|s| {
+                    let (a, b) = if s.count.clip == STRING_BACK_CLIP {
+                        (s.x_segment.a, s.x_segment.b)
+                    } else {
+                        (s.x_segment.b, s.x_segment.a)
+                    };
+                    let offset = length;
+                    length += distance(a, b);
+                    (a, b, offset)
+                })
+                .collect();
+            LineGeometry { segments }
+        }).collect()
+    }
+}
+
+/// The directed segments of an input line with the distance from the line start to every segment.
+struct LineGeometry {
+    segments: Vec<(IntPoint, IntPoint, f64)>,
+}
+
+impl LineGeometry {
+    /// The range of a part along the line. The first and the last edges of the part are located on
+    /// the segments going in the same direction, the last one is searched after the first one.
+    fn range(&self, path: &[IntPoint]) -> [f64; 2] {
+        let n = path.len();
+        let start = self.locate(path[0], path[1], 0.0).map_or(0.0, |t| t[0]);
+        let end = self.locate(path[n - 2], path[n - 1], start).map_or(start, |t| t[1]);
+        [start, end]
+    }
+
+    fn locate(&self, p: IntPoint, q: IntPoint, min_distance: f64) -> Option<[f64; 2]> {
+        self.segments.iter()
+            .filter(|&&(s, e, _)| is_near(p, s, e) && is_near(q, s, e) && dot(p, q, s, e) > 0)
+            .map(|&(s, e, offset)| [offset + projection(p, s, e), offset + projection(q, s, e)])
+            .find(|t| t[1] >= min_distance)
+    }
+}
+
+#[inline]
+fn distance(a: IntPoint, b: IntPoint) -> f64 {
+    let dx = b.x as f64 - a.x as f64;
+    let dy = b.y as f64 - a.y as f64;
+    FloatNumber::sqrt(dx * dx + dy * dy)
+}
+
+/// The distance from `s` to the projection of `p` on the segment `s -> e`.
+#[inline]
+fn projection(p: IntPoint, s: IntPoint, e: IntPoint) -> f64 {
+    let len = distance(s, e);
+    let t = dot(s, p, s, e) as f64 / len;
+    t.clamp(0.0, len)
+}
+
+/// The dot product of the vectors `a -> b` and `s -> e`.
+#[inline]
+fn dot(a: IntPoint, b: IntPoint, s: IntPoint, e: IntPoint) -> i128 {
+    let (x0, y0) = (b.x as i128 - a.x as i128, b.y as i128 - a.y as i128);
+    let (x1, y1) = (e.x as i128 - s.x as i128, e.y as i128 - s.y as i128);
+    x0 * x1 + y0 * y1
+}
+
+/// Returns `true` if the point is not farther than one unit from the segment `s -> e`.
+#[inline]
+fn is_near(p: IntPoint, s: IntPoint, e: IntPoint) -> bool {
+    let (dx, dy) = (e.x as i128 - s.x as i128, e.y as i128 - s.y as i128);
+    let (vx, vy) = (p.x as i128 - s.x as i128, p.y as i128 - s.y as i128);
+    let sqr_len = dx * dx + dy * dy;
+    let cross = dx * vy - dy * vx;
+    if cross * cross > sqr_len {
+        return false;
+    }
+
+    let t0 = dx * vx + dy * vy;
+    let t1 = sqr_len - t0;
+
+    (t0 >= 0 || t0 * t0 <= sqr_len) && (t1 >= 0 || t1 * t1 <= sqr_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use i_float::int::point::IntPoint;
+    use crate::core::fill_rule::FillRule;
+    use crate::string::clip::ClipRule;
+    use crate::string::overlay::StringOverlay;
+    use crate::string::source::SourcePiece;
+
+    fn square() -> vec::Vec<IntPoint> {
+        vec![IntPoint::new(0, 0), IntPoint::new(0, 10), IntPoint::new(10, 10), IntPoint::new(10, 0)]
+    }
+
+    #[test]
+    fn test_ids_and_ranges() {
+        let mut overlay = StringOverlay::with_shape_contour(&square());
+        overlay.add_string_path_with_id(&[IntPoint::new(-5, 5), IntPoint::new(15, 5), IntPoint::new(15, 8), IntPoint::new(-5, 8)], 7);
+        // the shared part belongs to both lines
+        overlay.add_string_path_with_id(&[IntPoint::new(2, 5), IntPoint::new(4, 5)], 5);
+        overlay.add_string_path_with_id(&[IntPoint::new(5, -5), IntPoint::new(5, 15)], 3);
+
+        let rule = ClipRule { invert: false, boundary_included: false };
+        let groups = overlay.clip_string_lines_by_source(FillRule::NonZero, rule);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].id, 3);
+        assert_eq!(groups[0].pieces, vec![SourcePiece {
+            path: vec![IntPoint::new(5, 0), IntPoint::new(5, 5), IntPoint::new(5, 8), IntPoint::new(5, 10)],
+            range: [5.0, 15.0],
+        }]);
+
+        assert_eq!(groups[1].id, 5);
+        assert_eq!(groups[1].pieces, vec![SourcePiece {
+            path: vec![IntPoint::new(2, 5), IntPoint::new(4, 5)],
+            range: [0.0, 2.0],
+        }]);
+
+        assert_eq!(groups[2].id, 7);
+        assert_eq!(groups[2].pieces, vec![
+            SourcePiece {
+                path: vec![IntPoint::new(0, 5), IntPoint::new(2, 5), IntPoint::new(4, 5), IntPoint::new(5, 5), IntPoint::new(10, 5)],
+                range: [5.0, 15.0],
+            },
+            SourcePiece {
+                path: vec![IntPoint::new(10, 8), IntPoint::new(5, 8), IntPoint::new(0, 8)],
+                range: [28.0, 38.0],
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_default_ids_after_explicit() {
+        // a line without an id never joins the group of an explicit id
+        let mut overlay = StringOverlay::with_shape_contour(&square());
+        overlay.add_string_line_with_id([IntPoint::new(-5, 2), IntPoint::new(15, 2)], 1);
+        overlay.add_string_line([IntPoint::new(-5, 4), IntPoint::new(15, 4)]);
+        overlay.add_string_line([IntPoint::new(-5, 6), IntPoint::new(15, 6)]);
+
+        let rule = ClipRule { invert: false, boundary_included: false };
+        let groups = overlay.clip_string_lines_by_source(FillRule::NonZero, rule);
+
+        let ids: vec::Vec<_> = groups.iter().map(|g| (g.id, g.pieces.len())).collect();
+        assert_eq!(ids, vec![(1, 1), (2, 1), (3, 1)]);
+    }
+
+    #[test]
+    fn test_outside() {
+        let mut overlay = StringOverlay::with_shape_contour(&square());
+        overlay.add_string_line([IntPoint::new(15, 5), IntPoint::new(-5, 5)]);
+        overlay.add_string_line([IntPoint::new(-5, 0), IntPoint::new(15, 0)]);
+
+        let rule = ClipRule { invert: true, boundary_included: true };
+        let groups = overlay.clip_string_lines_by_source(FillRule::NonZero, rule);
+
+        let ranges: vec::Vec<_> = groups.iter().map(|g| (g.id, g.pieces.iter().map(|p| p.range).collect::<vec::Vec<_>>())).collect();
+        assert_eq!(ranges, vec![
+            (0, vec![[0.0, 5.0], [15.0, 20.0]]),
+            (1, vec![[0.0, 5.0], [15.0, 20.0]]),
+        ]);
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use alloc::vec::Vec;
 use i_float::int::point::IntPoint;
 use i_shape::int::path::{IntPath, PointPathExtension};
 
@@ -60,6 +61,7 @@ impl ValidateArea for IntPath {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
     use super::*;
 
     #[test]
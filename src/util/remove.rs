@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 pub(crate) trait SwapRemoveIndex<T> {
     fn swap_remove_index(&mut self, index: usize) -> T;
 }
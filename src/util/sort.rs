@@ -1,6 +1,6 @@
 #[cfg(feature = "allow_multithreading")]
 use rayon::prelude::*;
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 use i_key_sort::index::{BinKey, Offset};
 use crate::core::solver::Solver;
 use i_key_sort::key_sort::KeyBinSort;
@@ -40,6 +40,7 @@ where
 }
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
     use i_key_sort::index::BinLayout;
     use super::*;
 
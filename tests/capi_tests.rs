@@ -0,0 +1,172 @@
+#![cfg(feature = "capi")]
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+    use std::slice;
+    use i_overlay::capi::*;
+    use i_overlay::capi::float::{ioverlay_float_clip, ioverlay_float_overlay, ioverlay_float_slice};
+    use i_overlay::capi::int::{ioverlay_int_clip, ioverlay_int_overlay};
+
+    fn int_paths(xy: &[i32], offsets: &[usize]) -> IOverlayIntPaths {
+        IOverlayIntPaths { xy: xy.as_ptr(), points_count: xy.len() / 2, offsets: offsets.as_ptr(), paths_count: offsets.len() }
+    }
+
+    fn float_paths(xy: &[f64], offsets: &[usize]) -> IOverlayFloatPaths {
+        IOverlayFloatPaths { xy: xy.as_ptr(), points_count: xy.len() / 2, offsets: offsets.as_ptr(), paths_count: offsets.len() }
+    }
+
+    fn empty_int_shapes() -> IOverlayIntShapesResult {
+        IOverlayIntShapesResult {
+            xy: ptr::null_mut(),
+            points_count: 0,
+            path_offsets: ptr::null_mut(),
+            paths_count: 0,
+            shape_offsets: ptr::null_mut(),
+            shapes_count: 0,
+        }
+    }
+
+    fn empty_float_shapes() -> IOverlayFloatShapesResult {
+        IOverlayFloatShapesResult {
+            xy: ptr::null_mut(),
+            points_count: 0,
+            path_offsets: ptr::null_mut(),
+            paths_count: 0,
+            shape_offsets: ptr::null_mut(),
+            shapes_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_int_overlay() {
+        // two squares, the second one has a hole
+        let subj_xy = [0, 0, 0, 10, 10, 10, 10, 0];
+        let clip_xy = [20, 0, 20, 10, 30, 10, 30, 0, 22, 2, 28, 2, 28, 8, 22, 8];
+        let subj = int_paths(&subj_xy, &[0]);
+        let clip = int_paths(&clip_xy, &[0, 4]);
+
+        let mut result = empty_int_shapes();
+        let status = unsafe {
+            ioverlay_int_overlay(subj, clip, IOVERLAY_OVERLAY_RULE_UNION, IOVERLAY_FILL_RULE_NON_ZERO, &mut result)
+        };
+        assert_eq!(status, IOverlayStatus::Ok);
+        assert_eq!(result.shapes_count, 2);
+        assert_eq!(result.paths_count, 3);
+        assert_eq!(result.points_count, 12);
+
+        let shape_offsets = unsafe { slice::from_raw_parts(result.shape_offsets, result.shapes_count) };
+        assert_eq!(shape_offsets, &[0, 1]);
+
+        unsafe { ioverlay_int_shapes_result_free(&mut result) };
+        assert!(result.xy.is_null());
+        assert_eq!(result.points_count, 0);
+    }
+
+    #[test]
+    fn test_int_clip() {
+        let shape_xy = [0, 0, 0, 10, 10, 10, 10, 0];
+        let lines_xy = [-5, 5, 15, 5];
+
+        let mut result = IOverlayIntPathsResult { xy: ptr::null_mut(), points_count: 0, offsets: ptr::null_mut(), paths_count: 0 };
+        let status = unsafe {
+            ioverlay_int_clip(int_paths(&lines_xy, &[0]), int_paths(&shape_xy, &[0]), IOVERLAY_FILL_RULE_NON_ZERO, false, false, &mut result)
+        };
+        assert_eq!(status, IOverlayStatus::Ok);
+        assert_eq!(result.paths_count, 1);
+
+        let mut xy = unsafe { slice::from_raw_parts(result.xy, 2 * result.points_count) }.to_vec();
+        if xy[0] > xy[2] {
+            xy = vec![xy[2], xy[3], xy[0], xy[1]];
+        }
+        assert_eq!(xy, vec![0, 5, 10, 5]);
+
+        unsafe { ioverlay_int_paths_result_free(&mut result) };
+    }
+
+    #[test]
+    fn test_float_overlay_and_slice() {
+        let subj_xy = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0];
+        let clip_xy = [0.5, 0.0, 0.5, 1.0, 1.5, 1.0, 1.5, 0.0];
+
+        let mut result = empty_float_shapes();
+        let status = unsafe {
+            ioverlay_float_overlay(float_paths(&subj_xy, &[0]), float_paths(&clip_xy, &[0]), IOVERLAY_OVERLAY_RULE_INTERSECT, IOVERLAY_FILL_RULE_EVEN_ODD, &mut result)
+        };
+        assert_eq!(status, IOverlayStatus::Ok);
+        assert_eq!(result.shapes_count, 1);
+        assert_eq!(result.points_count, 4);
+        unsafe { ioverlay_float_shapes_result_free(&mut result) };
+
+        let line_xy = [0.5, -1.0, 0.5, 2.0];
+        let status = unsafe {
+            ioverlay_float_slice(float_paths(&subj_xy, &[0]), float_paths(&line_xy, &[0]), IOVERLAY_FILL_RULE_NON_ZERO, &mut result)
+        };
+        assert_eq!(status, IOverlayStatus::Ok);
+        assert_eq!(result.shapes_count, 2);
+        unsafe { ioverlay_float_shapes_result_free(&mut result) };
+    }
+
+    #[test]
+    fn test_errors() {
+        let xy = [0, 0, 0, 10, 10, 10, 10, 0];
+        let mut result = empty_int_shapes();
+
+        let status = unsafe { ioverlay_int_overlay(int_paths(&xy, &[0]), int_paths(&[], &[]), 42, IOVERLAY_FILL_RULE_NON_ZERO, &mut result) };
+        assert_eq!(status, IOverlayStatus::InvalidArgument);
+
+        let status = unsafe { ioverlay_int_overlay(int_paths(&xy, &[0, 5]), int_paths(&[], &[]), IOVERLAY_OVERLAY_RULE_UNION, IOVERLAY_FILL_RULE_NON_ZERO, &mut result) };
+        assert_eq!(status, IOverlayStatus::InvalidArgument);
+
+        let null_xy = IOverlayIntPaths { xy: ptr::null(), points_count: 4, offsets: [0].as_ptr(), paths_count: 1 };
+        let status = unsafe { ioverlay_int_overlay(null_xy, int_paths(&[], &[]), IOVERLAY_OVERLAY_RULE_UNION, IOVERLAY_FILL_RULE_NON_ZERO, &mut result) };
+        assert_eq!(status, IOverlayStatus::NullPointer);
+
+        let status = unsafe { ioverlay_int_overlay(int_paths(&xy, &[0]), int_paths(&[], &[]), IOVERLAY_OVERLAY_RULE_UNION, IOVERLAY_FILL_RULE_NON_ZERO, ptr::null_mut()) };
+        assert_eq!(status, IOverlayStatus::NullPointer);
+
+        let nan_xy = [0.0, 0.0, 0.0, f64::NAN, 1.0, 1.0];
+        let mut lines = IOverlayFloatPathsResult { xy: ptr::null_mut(), points_count: 0, offsets: ptr::null_mut(), paths_count: 0 };
+        let status = unsafe { ioverlay_float_clip(float_paths(&nan_xy, &[0]), float_paths(&nan_xy, &[0]), IOVERLAY_FILL_RULE_NON_ZERO, false, false, &mut lines) };
+        assert_eq!(status, IOverlayStatus::InvalidArgument);
+        assert!(lines.xy.is_null());
+
+        let huge = IOverlayIntPaths { xy: xy.as_ptr(), points_count: usize::MAX, offsets: [0].as_ptr(), paths_count: 1 };
+        let status = unsafe { ioverlay_int_overlay(huge, int_paths(&[], &[]), IOVERLAY_OVERLAY_RULE_UNION, IOVERLAY_FILL_RULE_NON_ZERO, &mut result) };
+        assert_eq!(status, IOverlayStatus::InvalidArgument);
+    }
+
+    #[test]
+    fn test_filled_result_is_rejected() {
+        let xy = [0, 0, 0, 10, 10, 10, 10, 0];
+        let mut result = empty_int_shapes();
+
+        let status = unsafe { ioverlay_int_overlay(int_paths(&xy, &[0]), int_paths(&[], &[]), IOVERLAY_OVERLAY_RULE_UNION, IOVERLAY_FILL_RULE_NON_ZERO, &mut result) };
+        assert_eq!(status, IOverlayStatus::Ok);
+        let xy_ptr = result.xy;
+
+        // the filled result is kept as is, so the caller can still release it
+        let status = unsafe { ioverlay_int_overlay(int_paths(&xy, &[0]), int_paths(&[], &[]), IOVERLAY_OVERLAY_RULE_UNION, IOVERLAY_FILL_RULE_NON_ZERO, &mut result) };
+        assert_eq!(status, IOverlayStatus::InvalidArgument);
+        assert_eq!(result.xy, xy_ptr);
+        assert_eq!(result.points_count, 4);
+
+        unsafe { ioverlay_int_shapes_result_free(&mut result) };
+        let status = unsafe { ioverlay_int_overlay(int_paths(&xy, &[0]), int_paths(&[], &[]), IOVERLAY_OVERLAY_RULE_UNION, IOVERLAY_FILL_RULE_NON_ZERO, &mut result) };
+        assert_eq!(status, IOverlayStatus::Ok);
+        unsafe { ioverlay_int_shapes_result_free(&mut result) };
+    }
+
+    #[test]
+    fn test_header_is_complete() {
+        let header = include_str!("../include/i_overlay.h");
+        for source in [include_str!("../src/capi/mod.rs"), include_str!("../src/capi/int.rs"), include_str!("../src/capi/float.rs")] {
+            for line in source.lines() {
+                if let Some(rest) = line.strip_prefix("pub unsafe extern \"C\" fn ") {
+                    let name = &rest[..rest.find('(').unwrap()];
+                    assert!(header.contains(&format!(" {}(", name)), "{} is missing in the header", name);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+    use i_float::int::point::IntPoint;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::float::string_overlay::FloatStringOverlay;
+    use i_overlay::string::classify::{ClassifiedPath, ClipClass};
+    use i_overlay::string::clip::ClipRule;
+    use i_overlay::string::overlay::StringOverlay;
+    use i_shape::int::path::IntPath;
+
+    fn random_path(len: usize, range: i32) -> IntPath {
+        let mut rng = rand::thread_rng();
+        (0..len).map(|_| IntPoint::new(rng.gen_range(-range..=range), rng.gen_range(-range..=range))).collect()
+    }
+
+    fn edges<'a, I: Iterator<Item=&'a IntPath>>(paths: I) -> Vec<[IntPoint; 2]> {
+        let mut edges: Vec<_> = paths
+            .flat_map(|path| path.windows(2).map(|w| if w[0] < w[1] { [w[0], w[1]] } else { [w[1], w[0]] }))
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        edges
+    }
+
+    fn class_edges(parts: &[ClassifiedPath], classes: &[ClipClass]) -> Vec<[IntPoint; 2]> {
+        edges(parts.iter().filter(|part| classes.contains(&part.class)).map(|part| &part.path))
+    }
+
+    #[test]
+    fn test_random_matches_clip_rules() {
+        let rules = [
+            (ClipRule { invert: false, boundary_included: false }, vec![ClipClass::Inside]),
+            (ClipRule { invert: false, boundary_included: true }, vec![ClipClass::Inside, ClipClass::Boundary]),
+            (ClipRule { invert: true, boundary_included: false }, vec![ClipClass::Outside, ClipClass::Boundary]),
+            (ClipRule { invert: true, boundary_included: true }, vec![ClipClass::Outside]),
+        ];
+
+        for _ in 0..300 {
+            let shape = random_path(6, 10);
+            let lines: Vec<IntPath> = (0..3).map(|_| random_path(4, 12)).collect();
+
+            let overlay = || {
+                let mut overlay = StringOverlay::with_shape_contour(&shape);
+                overlay.add_string_paths(&lines);
+                overlay
+            };
+
+            for solver in [Solver::LIST, Solver::TREE] {
+                let parts = overlay().clip_string_lines_classified_with_solver(FillRule::NonZero, solver);
+                assert!(parts.iter().all(|part| part.index < lines.len()));
+
+                for (rule, classes) in rules.iter() {
+                    let clipped = overlay().clip_string_lines_with_solver(FillRule::NonZero, *rule, solver);
+                    assert_eq!(class_edges(&parts, classes), edges(clipped.iter()), "{:?} {:?} {:?}", shape, lines, rule);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_float() {
+        let square = vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]];
+        let lines = vec![
+            vec![[-1.0, 0.5], [2.0, 0.5]],
+            vec![[0.5, 0.5], [0.5, 2.0]],
+        ];
+
+        let parts = FloatStringOverlay::with_shape_and_string(&square, &lines).clip_string_lines_classified(FillRule::NonZero);
+        let summary: Vec<_> = parts.iter().map(|part| (part.index, part.class, part.path.len())).collect();
+        assert_eq!(summary, vec![
+            (0, ClipClass::Inside, 3),
+            (0, ClipClass::Outside, 2),
+            (0, ClipClass::Outside, 2),
+            (1, ClipClass::Inside, 2),
+            (1, ClipClass::Outside, 2),
+        ]);
+    }
+
+    #[test]
+    fn test_float_by_source() {
+        let square = vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]];
+        let road = [[-1.0, 0.5], [2.0, 0.5f64]];
+
+        // the first line sets the bounding box of the overlay
+        let groups = FloatStringOverlay::with_shape_and_string(&square, &[[-1.0, -1.0], [2.0, 2.0]])
+            .unsafe_add_string_line_with_id(&road, 42)
+            .clip_string_lines_by_source(FillRule::NonZero, ClipRule { invert: false, boundary_included: true });
+
+        assert_eq!(groups.iter().map(|g| g.id).collect::<Vec<_>>(), vec![0, 42]);
+        let piece = &groups[1].pieces[0];
+        assert_eq!(piece.path.first(), Some(&[0.0, 0.5]));
+        assert_eq!(piece.path.last(), Some(&[1.0, 0.5]));
+
+        let [t0, t1] = piece.range;
+        assert!((t0 - 1.0).abs() < 1e-6 && (t1 - 2.0).abs() < 1e-6, "{:?}", piece.range);
+    }
+}
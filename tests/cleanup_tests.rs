@@ -0,0 +1,75 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::int::point::IntPoint;
+    use i_shape::int::path::{IntPath, PointPathExtension};
+    use i_overlay::core::cleanup::{Clean, Cleanup};
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::validation::{Issue, Validate};
+    use crate::data::overlay::BooleanTest;
+    use crate::util::corpus::boolean_indices;
+
+    #[test]
+    fn test_overlay_results() {
+        let cleanups = [
+            Cleanup { min_outer_area: 100, min_hole_area: 100, ..Default::default() },
+            Cleanup { min_sliver_ratio: 5, ..Default::default() },
+            Cleanup { max_spike_angle: 15.0, ..Default::default() },
+            Cleanup { min_width: 10, ..Default::default() },
+        ];
+        for index in boolean_indices() {
+            let test = BooleanTest::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            for rule in [OverlayRule::Union, OverlayRule::Difference, OverlayRule::Xor] {
+                for cleanup in cleanups.iter() {
+                    let result = Overlay::with_contours(&test.subj_paths, &test.clip_paths)
+                        .overlay_with_cleanup_and_solver(rule, fill_rule, cleanup, Default::default());
+
+                    for issue in result.validate().issues.iter() {
+                        assert!(matches!(issue, Issue::Touching { .. }), "test_{} {} {:?}: {:?}", index, rule, cleanup, issue);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_circle_with_spikes() {
+        // a circle keeps its area, the spikes narrower than the width are cut off
+        let n = 256;
+        let mut circle: IntPath = Vec::new();
+        let point = |i: usize, r: f64| {
+            let a = -(i as f64) * core::f64::consts::TAU / n as f64;
+            IntPoint::new((r * a.cos()) as i32, (r * a.sin()) as i32)
+        };
+        for i in 0..n {
+            let p = point(i, 1000.0);
+            circle.push(p);
+            if i % 32 == 0 {
+                // the spike is 3 units wide at its base on the edge to the next vertex
+                let q = point(i + 1, 1000.0);
+                let (dx, dy) = ((q.x - p.x) as f64, (q.y - p.y) as f64);
+                let len = (dx * dx + dy * dy).sqrt();
+                circle.push(point(i, 1500.0));
+                circle.push(IntPoint::new(p.x + (3.0 * dx / len).round() as i32, p.y + (3.0 * dy / len).round() as i32));
+            }
+        }
+
+        let shapes = Overlay::with_contour(&circle, &[]).overlay(OverlayRule::Subject, FillRule::NonZero);
+        let cleanup = Cleanup { min_width: 20, ..Default::default() };
+        let result = shapes.clean(&cleanup);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].len(), 1);
+        let max = result[0][0].iter().map(|p| (p.x as i64).pow(2) + (p.y as i64).pow(2)).max().unwrap();
+        assert!(max <= 1010 * 1010);
+
+        let area = result[0][0].unsafe_area() as f64;
+        let expected = 2.0 * core::f64::consts::PI * 1000.0 * 1000.0;
+        assert!((area - expected).abs() < 0.01 * expected);
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "cli")]
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process::{Command, Output};
+
+    fn run(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_i_overlay"))
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .expect("the binary is built")
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("i_overlay_cli_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_json() {
+        let output = run(&["overlay", "union", "tests/boolean/test_0.json"]);
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.starts_with("{\"union\":[[[[["));
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains("result: 1 shapes"));
+        assert!(stderr.contains("overlay:"));
+    }
+
+    #[test]
+    fn test_geojson() {
+        let dir = temp_dir("geojson");
+        let subj = dir.join("subj.geojson");
+        let clip = dir.join("clip.geojson");
+        let result = dir.join("result.geojson");
+        fs::write(&subj, r#"{"type":"Polygon","coordinates":[[[0,0],[2,0],[2,2],[0,2],[0,0]]]}"#).unwrap();
+        fs::write(&clip, r#"{"type":"Polygon","coordinates":[[[1,1],[3,1],[3,3],[1,3],[1,1]]]}"#).unwrap();
+
+        let output = run(&[
+            "overlay", "intersect", "--solver", "list", "--repeat", "3",
+            subj.to_str().unwrap(), clip.to_str().unwrap(), "-o", result.to_str().unwrap(),
+        ]);
+        assert!(output.status.success());
+        assert!(output.stdout.is_empty());
+
+        let text = fs::read_to_string(&result).unwrap();
+        assert!(text.contains("MultiPolygon"));
+        assert!(text.contains("[1.0,1.0]"));
+        assert!(text.contains("[2.0,2.0]"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_regress() {
+        let dir = temp_dir("regress");
+        fs::copy("tests/boolean/test_0.json", dir.join("test_0.json")).unwrap();
+        fs::copy("tests/boolean/test_1.json", dir.join("test_1.json")).unwrap();
+
+        let output = run(&["regress", dir.to_str().unwrap()]);
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains("2 files, 56 checks, 0 failed"));
+
+        // break the expected union of the first case
+        let text = fs::read_to_string(dir.join("test_0.json")).unwrap();
+        let text = text.replacen("\"union\"", "\"unused\"", 1).replacen("\"xor\"", "\"union\"", 1);
+        fs::write(dir.join("test_0.json"), text).unwrap();
+
+        let output = run(&["regress", "--solver", "tree", dir.to_str().unwrap()]);
+        assert!(!output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains("FAIL test_0.json union Tree: different area"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_args() {
+        let output = run(&["overlay", "merge", "tests/boolean/test_0.json"]);
+        assert_eq!(output.status.code(), Some(2));
+        assert!(String::from_utf8(output.stderr).unwrap().contains("unknown overlay rule"));
+    }
+}
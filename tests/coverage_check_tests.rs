@@ -0,0 +1,59 @@
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_shape::int::path::IntPath;
+    use i_shape::int::shape::IntShape;
+    use rand::Rng;
+    use i_overlay::core::coverage::SimplifyCoverage;
+    use i_overlay::core::coverage_check::CheckCoverage;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::generalize::Tolerance;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::string::slice::IntSlice;
+    use crate::util::shapes::{noisy_circle, noisy_cut};
+
+    #[test]
+    fn test_random_partition() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let n = rng.gen_range(50..200);
+            let circle = noisy_circle(&mut rng, n);
+            let m = rng.gen_range(1..5);
+            let cuts: Vec<IntPath> = (0..m).map(|_| noisy_cut(&mut rng)).collect();
+            let coverage = circle.slice_by_paths(&cuts, FillRule::NonZero);
+
+            for solver in [Solver::LIST, Solver::TREE] {
+                assert!(coverage.check_coverage_with_solver(None, solver).is_valid());
+
+                // a simplified coverage stays a coverage
+                let simplified: Vec<IntShape> = coverage.simplify_coverage_with_solver(Tolerance::Distance(20), solver)
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                assert!(simplified.check_coverage_with_solver(None, solver).is_valid());
+            }
+
+            if coverage.len() < 2 {
+                continue;
+            }
+
+            // the cuts are across the y axis, so a polygon moved along it overlaps a neighbor in one of
+            // the directions, all overlaps involve the moved polygon
+            let mut has_overlaps = false;
+            for dy in [-10, 10] {
+                let mut moved = coverage.clone();
+                for p in moved[0].iter_mut().flatten() {
+                    p.y += dy;
+                }
+                let report = moved.check_coverage(None);
+                has_overlaps |= !report.overlaps.is_empty();
+                for overlap in report.overlaps.iter() {
+                    assert!(overlap.polygons.len() > 1);
+                    assert_eq!(overlap.polygons[0], 0);
+                }
+            }
+            assert!(has_overlaps);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_shape::int::path::IntPath;
+    use i_shape::int::shape::IntShape;
+    use rand::Rng;
+    use i_overlay::core::coverage::SimplifyCoverage;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::generalize::Tolerance;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::core::validation::Validate;
+    use i_overlay::string::slice::IntSlice;
+    use crate::util::shapes::{area, noisy_circle, noisy_cut};
+
+    #[test]
+    fn test_random_partition() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let n = rng.gen_range(50..200);
+            let circle = noisy_circle(&mut rng, n);
+            let m = rng.gen_range(1..5);
+            let cuts: Vec<IntPath> = (0..m).map(|_| noisy_cut(&mut rng)).collect();
+            let coverage = circle.slice_by_paths(&cuts, FillRule::NonZero);
+
+            for solver in [Solver::LIST, Solver::TREE] {
+                for tolerance in [Tolerance::Distance(20), Tolerance::Area(2000)] {
+                    let result = coverage.simplify_coverage_with_solver(tolerance, solver);
+                    assert_eq!(result.len(), coverage.len());
+
+                    let count = |shapes: &[IntShape]| shapes.iter().flatten().map(|c| c.len()).sum::<usize>();
+                    assert!(result.iter().map(|shapes| count(shapes)).sum::<usize>() < count(&coverage));
+
+                    for shapes in result.iter() {
+                        assert!(!shapes.is_empty());
+                        assert!(shapes.validate().is_valid());
+                    }
+
+                    // no overlaps: the union has the same area, no gaps: the union has no holes
+                    let all: Vec<IntShape> = result.into_iter().flatten().collect();
+                    let union = Overlay::with_shapes(&all, &[]).overlay(OverlayRule::Subject, FillRule::NonZero);
+                    assert_eq!(union.len(), 1);
+                    assert_eq!(union[0].len(), 1);
+                    assert_eq!(area(&union), area(&all));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::int::point::IntPoint;
+    use i_shape::int::path::IntPath;
+    use i_shape::int::shape::IntShapes;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use i_overlay::core::csg::{Csg, CsgExpression};
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use crate::util::shapes::{area, random_polygon};
+
+    const RULES: [OverlayRule; 7] = [
+        OverlayRule::Subject, OverlayRule::Clip, OverlayRule::Intersect, OverlayRule::Union,
+        OverlayRule::Difference, OverlayRule::InverseDifference, OverlayRule::Xor,
+    ];
+
+    fn random_rect<R: Rng>(rng: &mut R) -> IntPath {
+        let (x0, y0) = (rng.gen_range(-100..100), rng.gen_range(-100..100));
+        let (x1, y1) = (x0 + rng.gen_range(1..100), y0 + rng.gen_range(1..100));
+        vec![IntPoint::new(x0, y0), IntPoint::new(x0, y1), IntPoint::new(x1, y1), IntPoint::new(x1, y0)]
+    }
+
+    #[test]
+    fn test_random_operation() {
+        // an expression of two leaves is exactly the overlay with the same rule
+        let mut rng = StdRng::seed_from_u64(50);
+        for range in [5, 20, 1000] {
+            for _ in 0..300 {
+                let n = rng.gen_range(3..=6);
+                let subj = random_polygon(&mut rng, n, range);
+                let clip = random_polygon(&mut rng, n, range);
+
+                for fill_rule in [FillRule::EvenOdd, FillRule::NonZero, FillRule::Positive, FillRule::Negative] {
+                    for rule in RULES {
+                        let mut csg = Csg::new(2 * n);
+                        let a = csg.add_leaf(core::slice::from_ref(&subj));
+                        let b = csg.add_leaf(core::slice::from_ref(&clip));
+                        let result = csg.evaluate(&a.apply(rule, b), fill_rule);
+
+                        let expected = Overlay::with_contour(&subj, &clip).overlay(rule, fill_rule);
+                        assert_eq!(result, expected, "{} {:?} {:?} {:?}", rule, fill_rule, subj, clip);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_expression() {
+        // a random expression over axis aligned rectangles is equal to the chain of overlays,
+        // every intersection is a grid point so nothing is rounded
+        let mut rng = StdRng::seed_from_u64(50);
+        for _ in 0..500 {
+            let mut csg = Csg::new(0);
+            let mut nodes: Vec<(CsgExpression, IntShapes)> = (0..rng.gen_range(2..8))
+                .map(|_| {
+                    let rect = random_rect(&mut rng);
+                    (csg.add_leaf(core::slice::from_ref(&rect)), vec![vec![rect]])
+                })
+                .collect();
+
+            while nodes.len() > 1 {
+                let (subj, subj_shapes) = nodes.swap_remove(rng.gen_range(0..nodes.len()));
+                let (clip, clip_shapes) = nodes.swap_remove(rng.gen_range(0..nodes.len()));
+                let rule = RULES[rng.gen_range(0..RULES.len())];
+                let shapes = Overlay::with_shapes(&subj_shapes, &clip_shapes).overlay(rule, FillRule::NonZero);
+                nodes.push((subj.apply(rule, clip), shapes));
+            }
+
+            let (expression, chain) = nodes.pop().unwrap();
+            let result = csg.evaluate(&expression, FillRule::NonZero);
+            assert_eq!(area(&result), area(&chain), "{:?}", expression);
+
+            let union = Overlay::with_shapes(&result, &[]).overlay(OverlayRule::Subject, FillRule::NonZero);
+            assert_eq!(area(&result), area(&union));
+        }
+    }
+
+    #[test]
+    fn test_shared_leaf() {
+        // a leaf can be used many times, A − (A ∩ B) is A − B
+        let mut rng = StdRng::seed_from_u64(50);
+        for _ in 0..200 {
+            let subj = random_polygon(&mut rng, 6, 1000);
+            let clip = random_polygon(&mut rng, 6, 1000);
+
+            let mut csg = Csg::new(12);
+            let a = csg.add_leaf(core::slice::from_ref(&subj));
+            let b = csg.add_leaf(core::slice::from_ref(&clip));
+            let result = csg.evaluate(&a.clone().apply(OverlayRule::Difference, a.apply(OverlayRule::Intersect, b)), FillRule::EvenOdd);
+
+            let expected = Overlay::with_contour(&subj, &clip).overlay(OverlayRule::Difference, FillRule::EvenOdd);
+            assert_eq!(result, expected);
+        }
+    }
+}
@@ -6,17 +6,36 @@ pub mod overlay {
     use serde::{Deserialize, Deserializer};
     use i_overlay::core::fill_rule::FillRule;
 
+    /// The corpus stores the rule as an index (`0` even-odd, `1` non-zero, ...) or by its name.
     fn deserialize_fill_rule<'de, D>(deserializer: D) -> Result<Option<FillRule>, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let val = Option::<i32>::deserialize(deserializer)?;
-        match val {
-            Some(0) => Ok(Some(FillRule::EvenOdd)),
-            Some(1) => Ok(Some(FillRule::NonZero)),
-            None => Ok(None), // This covers the case where the field is missing
-            _ => Err(serde::de::Error::custom("Invalid value for FillRule")),
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Value {
+            Index(u32),
+            Name(String),
         }
+
+        let value = match Option::<Value>::deserialize(deserializer)? {
+            Some(value) => value,
+            None => return Ok(None), // This covers the case where the field is missing
+        };
+
+        let fill_rule = match value {
+            Value::Index(0) => FillRule::EvenOdd,
+            Value::Index(1) => FillRule::NonZero,
+            Value::Index(2) => FillRule::Positive,
+            Value::Index(3) => FillRule::Negative,
+            Value::Name(name) if name == "EvenOdd" => FillRule::EvenOdd,
+            Value::Name(name) if name == "NonZero" => FillRule::NonZero,
+            Value::Name(name) if name == "Positive" => FillRule::Positive,
+            Value::Name(name) if name == "Negative" => FillRule::Negative,
+            _ => return Err(serde::de::Error::custom("Invalid value for FillRule")),
+        };
+
+        Ok(Some(fill_rule))
     }
 
     #[allow(dead_code)]
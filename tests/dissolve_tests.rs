@@ -0,0 +1,91 @@
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::int::point::IntPoint;
+    use i_shape::int::path::IntPath;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use i_overlay::core::dissolve::Dissolve;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use crate::util::shapes::{area, random_polygon};
+
+    fn cell(x: i32, y: i32, size: i32) -> IntPath {
+        vec![IntPoint::new(x, y), IntPoint::new(x, y + size), IntPoint::new(x + size, y + size), IntPoint::new(x + size, y)]
+    }
+
+    #[test]
+    fn test_random_polygons() {
+        // the union of every key is the overlay of its contours split by the contours of the other keys
+        let mut rng = StdRng::seed_from_u64(49);
+        for _ in 0..300 {
+            let pairs: Vec<(u8, IntPath)> = (0..rng.gen_range(2..8))
+                .map(|_| {
+                    let n = rng.gen_range(3..=6);
+                    (rng.gen_range(0..3), random_polygon(&mut rng, n, 1000))
+                })
+                .collect();
+
+            for fill_rule in [FillRule::EvenOdd, FillRule::NonZero, FillRule::Positive, FillRule::Negative] {
+                let dissolved = Dissolve::with_pairs(&pairs).into_dissolved(fill_rule, false);
+
+                for key in 0..3 {
+                    let (subj, clip): (Vec<_>, Vec<_>) = pairs.iter().partition(|(k, _)| *k == key);
+                    let subj: Vec<IntPath> = subj.into_iter().map(|(_, contour)| contour.clone()).collect();
+                    let clip: Vec<IntPath> = clip.into_iter().map(|(_, contour)| contour.clone()).collect();
+                    let expected = Overlay::with_contours(&subj, &clip).overlay(OverlayRule::Subject, fill_rule);
+
+                    let result = dissolved.shapes.iter()
+                        .find(|(k, _)| *k == key)
+                        .map_or(Vec::new(), |(_, shapes)| shapes.clone());
+                    assert_eq!(result, expected, "{:?} {:?}", fill_rule, pairs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_grid() {
+        // every cell of a grid gets a random key, the union of a key covers its cells exactly
+        // and the boundaries are the cell sides between different keys
+        let mut rng = rand::thread_rng();
+        let (n, size) = (8, 10);
+        for _ in 0..100 {
+            let keys: Vec<Vec<u8>> = (0..n).map(|_| (0..n).map(|_| rng.gen_range(0..4)).collect()).collect();
+
+            let mut pairs = Vec::new();
+            for (i, row) in keys.iter().enumerate() {
+                for (j, &key) in row.iter().enumerate() {
+                    pairs.push((key, cell(i as i32 * size, j as i32 * size, size)));
+                }
+            }
+
+            let dissolved = Dissolve::with_pairs(&pairs).into_dissolved(FillRule::NonZero, true);
+
+            for (key, shapes) in dissolved.shapes.iter() {
+                let count = keys.iter().flatten().filter(|&k| k == key).count() as i64;
+                assert_eq!(area(shapes), 2 * count * (size * size) as i64);
+                assert!(shapes.iter().flatten().all(|contour| contour.len() >= 4));
+            }
+
+            let mut expected = 0;
+            for i in 0..n {
+                for j in 0..n {
+                    if i + 1 < n && keys[i][j] != keys[i + 1][j] {
+                        expected += size as i64;
+                    }
+                    if j + 1 < n && keys[i][j] != keys[i][j + 1] {
+                        expected += size as i64;
+                    }
+                }
+            }
+            let length: i64 = dissolved.boundaries.iter()
+                .flat_map(|path| path.windows(2))
+                .map(|w| ((w[0].x - w[1].x).abs() + (w[0].y - w[1].y).abs()) as i64)
+                .sum();
+            assert_eq!(length, expected);
+        }
+    }
+}
@@ -3,13 +3,14 @@ mod tests {
     use rand::Rng;
     use std::f64::consts::PI;
     use i_float::int::point::IntPoint;
+    use i_float::triangle::Triangle;
     use i_shape::base::data::Path;
     use i_shape::int::path::IntPath;
     use i_shape::int::shape::IntShape;
     use i_overlay::core::fill_rule::FillRule;
     use i_overlay::core::overlay::{Overlay, ShapeType};
     use i_overlay::core::overlay_rule::OverlayRule;
-    use i_overlay::core::solver::Solver;
+    use i_overlay::core::solver::{Precision, Solver};
     use i_overlay::float::overlay::FloatOverlay;
 
     const SOLVERS: [Solver; 3] = [
@@ -263,6 +264,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_14() {
+        let n = 8;
+        for &solver in SOLVERS.iter() {
+            let solver = Solver { precision: Precision::SnapRounding, ..solver };
+            for _ in 0..2000 {
+                let subj_path = random(100, n);
+                let clip_path = random(100, n);
+                let overlay = Overlay::with_contour(&subj_path, &clip_path);
+                let edges = overlay.into_separate_vectors(FillRule::NonZero, solver);
+
+                for (i, e0) in edges.iter().enumerate() {
+                    for e1 in edges.iter().skip(i + 1) {
+                        let is_common_end = e0.a == e1.a || e0.a == e1.b || e0.b == e1.a || e0.b == e1.b;
+                        assert!(is_common_end || !is_cross(e0.a, e0.b, e1.a, e1.b));
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_cross(a0: IntPoint, b0: IntPoint, a1: IntPoint, b1: IntPoint) -> bool {
+        let d0 = Triangle::clock_direction_point(a0, b0, a1);
+        let d1 = Triangle::clock_direction_point(a0, b0, b1);
+        let d2 = Triangle::clock_direction_point(a1, b1, a0);
+        let d3 = Triangle::clock_direction_point(a1, b1, b0);
+
+        if d0 == 0 && d1 == 0 {
+            // collinear, check projection overlap
+            let (min0, max0) = if a0 < b0 { (a0, b0) } else { (b0, a0) };
+            let (min1, max1) = if a1 < b1 { (a1, b1) } else { (b1, a1) };
+            return min0 <= max1 && min1 <= max0;
+        }
+
+        d0 * d1 <= 0 && d2 * d3 <= 0
+    }
+
     fn create_star(r0: f64, r1: f64, count: usize, angle: f64) -> IntShape {
         let da = PI / count as f64;
         let mut a = angle;
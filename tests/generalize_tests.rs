@@ -0,0 +1,66 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::generalize::{Generalize, Tolerance};
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::validation::{Issue, Validate};
+    use i_overlay::float::generalize::FloatGeneralize;
+    use i_overlay::float::validation::FloatValidate;
+    use rand::Rng;
+    use crate::data::overlay::BooleanTest;
+    use crate::util::corpus::boolean_indices;
+
+    #[test]
+    fn test_overlay_results() {
+        for index in boolean_indices() {
+            let test = BooleanTest::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            for rule in [OverlayRule::Union, OverlayRule::Difference, OverlayRule::Xor] {
+                let shapes = Overlay::with_contours(&test.subj_paths, &test.clip_paths).overlay(rule, fill_rule);
+                let count: usize = shapes.iter().flatten().map(|c| c.len()).sum();
+
+                for tolerance in [Tolerance::Distance(1), Tolerance::Distance(1000), Tolerance::Area(100), Tolerance::Area(1_000_000)] {
+                    let result = shapes.generalize(tolerance);
+                    assert!(result.iter().flatten().map(|c| c.len()).sum::<usize>() <= count);
+
+                    let report = result.validate();
+                    for issue in report.issues.iter() {
+                        assert!(matches!(issue, Issue::Touching { .. }), "test_{} {} {:?}: {:?}", index, rule, tolerance, issue);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_rings() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            // a noisy ring with a noisy hole, the gap between them is narrow
+            let n = rng.gen_range(8..40);
+            let ring = |r: f64, rng: &mut rand::rngs::ThreadRng| -> Vec<[f64; 2]> {
+                (0..n).map(|i| {
+                    let a = -(i as f64) * core::f64::consts::TAU / n as f64;
+                    let r = r + rng.gen_range(-1.0..1.0);
+                    [r * a.cos(), r * a.sin()]
+                }).collect()
+            };
+            let outer = ring(10.0, &mut rng);
+            let mut hole = ring(7.5, &mut rng);
+            hole.reverse();
+            let shapes = vec![vec![outer, hole]];
+            if !shapes.validate().is_valid() {
+                continue;
+            }
+
+            for tolerance in [Tolerance::Distance(1.0), Tolerance::Distance(3.0), Tolerance::Area(2.0), Tolerance::Area(10.0)] {
+                let result = shapes.generalize(tolerance);
+                assert!(result.validate().is_valid(), "{:?} {:?}", shapes, tolerance);
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "glam")]
+
+#[cfg(test)]
+mod tests {
+    use glam::{DVec2, Vec2};
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::float::clip::FloatClip;
+    use i_overlay::float::single::SingleFloatOverlay;
+    use i_overlay::string::clip::ClipRule;
+
+    #[test]
+    fn test_overlay() {
+        let subj = vec![Vec2::new(0.0, 0.0), Vec2::new(0.0, 2.0), Vec2::new(2.0, 2.0), Vec2::new(2.0, 0.0)];
+        let clip = vec![vec![Vec2::new(1.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(3.0, 2.0), Vec2::new(3.0, 0.0)]];
+
+        let shapes = subj.overlay(&clip, OverlayRule::Intersect, FillRule::NonZero);
+        assert_eq!(shapes, vec![vec![vec![Vec2::new(1.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 2.0), Vec2::new(2.0, 0.0)]]]);
+    }
+
+    #[test]
+    fn test_clip() {
+        let shape = vec![DVec2::new(0.0, 0.0), DVec2::new(0.0, 2.0), DVec2::new(2.0, 2.0), DVec2::new(2.0, 0.0)];
+        let line = vec![DVec2::new(-1.0, 1.0), DVec2::new(3.0, 1.0)];
+
+        let clip_rule = ClipRule { invert: false, boundary_included: false };
+        let lines = line.clip_by(&shape, FillRule::NonZero, clip_rule);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].len(), 2);
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+    use i_float::int::point::IntPoint;
+    use i_overlay::core::noding::Noder;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::string::network::LineNetwork;
+    use i_shape::int::path::IntPath;
+
+    const SOLVERS: [Solver; 3] = [Solver::LIST, Solver::TREE, Solver::FRAG];
+
+    fn random_paths(count: usize, len: usize, range: i32) -> Vec<IntPath> {
+        let mut rng = rand::thread_rng();
+        (0..count)
+            .map(|_| (0..len)
+                .map(|_| IntPoint::new(rng.gen_range(-range..=range), rng.gen_range(-range..=range)))
+                .collect())
+            .collect()
+    }
+
+    fn edge_count(paths: &[IntPath]) -> usize {
+        paths.iter().map(|path| path.len() - 1).sum()
+    }
+
+    #[test]
+    fn test_random_union_is_planar() {
+        for _ in 0..200 {
+            let subj = random_paths(3, 4, 50);
+            let clip = random_paths(3, 4, 50);
+            for solver in SOLVERS {
+                let noded = LineNetwork::with_lines(&subj, &clip).overlay_with_solver(OverlayRule::Union, false, solver);
+                let joined = LineNetwork::with_lines(&subj, &clip).overlay_with_solver(OverlayRule::Union, true, solver);
+
+                // joining only removes the nodes of degree 2
+                assert_eq!(edge_count(&noded), edge_count(&joined));
+
+                // the network has no crossings left
+                let again = Noder::with_paths(&joined, false).node_with_solver(solver);
+                assert!(again.intersections.is_empty(), "{:?} {:?}", subj, clip);
+                assert_eq!(again.segments.len(), noded.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_rules_partition_union() {
+        for _ in 0..200 {
+            let subj = random_paths(3, 4, 20);
+            let clip = random_paths(3, 4, 20);
+            let count = |rule| edge_count(&LineNetwork::with_lines(&subj, &clip).overlay(rule, false));
+
+            let union = count(OverlayRule::Union);
+            let intersect = count(OverlayRule::Intersect);
+            let difference = count(OverlayRule::Difference);
+            let inverse = count(OverlayRule::InverseDifference);
+
+            assert_eq!(union, intersect + difference + inverse);
+            assert_eq!(count(OverlayRule::Xor), difference + inverse);
+            assert_eq!(count(OverlayRule::Subject), intersect + difference);
+            assert_eq!(count(OverlayRule::Clip), intersect + inverse);
+        }
+    }
+
+    #[test]
+    fn test_dissolve() {
+        // the same road digitized twice in opposite directions
+        let roads = vec![
+            vec![IntPoint::new(0, 0), IntPoint::new(10, 0), IntPoint::new(20, 0)],
+            vec![IntPoint::new(20, 0), IntPoint::new(0, 0)],
+        ];
+
+        let result = LineNetwork::with_lines(&roads, &[]).overlay(OverlayRule::Subject, true);
+        assert_eq!(result, vec![vec![IntPoint::new(0, 0), IntPoint::new(10, 0), IntPoint::new(20, 0)]]);
+    }
+}
@@ -0,0 +1,87 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+    use i_float::int::point::IntPoint;
+    use i_overlay::core::noding::Noder;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::solver::{Precision, Solver};
+    use crate::data::overlay::BooleanTest;
+    use crate::util::corpus::boolean_indices;
+
+    const SOLVERS: [Solver; 3] = [Solver::LIST, Solver::TREE, Solver::FRAG];
+
+    fn random_segments(count: usize, range: i32) -> Vec<[IntPoint; 2]> {
+        let mut rng = rand::thread_rng();
+        (0..count)
+            .map(|_| [
+                IntPoint::new(rng.gen_range(-range..=range), rng.gen_range(-range..=range)),
+                IntPoint::new(rng.gen_range(-range..=range), rng.gen_range(-range..=range)),
+            ])
+            .collect()
+    }
+
+    #[test]
+    fn test_grid() {
+        // 5 horizontal and 5 vertical lines
+        let mut segments = Vec::new();
+        for i in 0..5 {
+            segments.push([IntPoint::new(0, 10 * i), IntPoint::new(40, 10 * i)]);
+            segments.push([IntPoint::new(10 * i, 0), IntPoint::new(10 * i, 40)]);
+        }
+
+        for solver in SOLVERS {
+            let result = Noder::with_segments(&segments).node_with_solver(solver);
+            assert_eq!(result.segments.len(), 40);
+            // the corners are ends of both lines, the other 21 points are inside at least one of them
+            assert_eq!(result.intersections.len(), 21);
+        }
+    }
+
+    #[test]
+    fn test_random_is_stable() {
+        for _ in 0..200 {
+            let segments = random_segments(12, 100);
+            for solver in SOLVERS {
+                let result = Noder::with_segments(&segments).node_with_solver(solver);
+
+                // noding the result again changes nothing
+                let again = Noder::with_segments(&result.segments).node_with_solver(solver);
+                assert!(again.intersections.is_empty(), "{:?}", segments);
+                assert_eq!(again.segments, result.segments);
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_snap_rounding() {
+        let solver = Solver { precision: Precision::SnapRounding, ..Default::default() };
+        for _ in 0..100 {
+            let segments = random_segments(20, 50);
+            let result = Noder::with_segments(&segments).node_with_solver(solver);
+            let again = Noder::with_segments(&result.segments).node_with_solver(solver);
+            assert!(again.intersections.is_empty(), "{:?}", segments);
+        }
+    }
+
+    #[test]
+    fn test_corpus_snap_rounding() {
+        let solver = Solver { precision: Precision::SnapRounding, ..Default::default() };
+        for index in boolean_indices() {
+            let test = BooleanTest::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            let segments: Vec<_> = Overlay::with_contours(&test.subj_paths, &test.clip_paths)
+                .into_separate_vectors(fill_rule, solver)
+                .into_iter()
+                .map(|edge| [edge.a, edge.b])
+                .collect();
+
+            // split edges may only meet at their ends
+            let result = Noder::with_segments(&segments).node();
+            assert!(result.intersections.is_empty(), "test_{}", index);
+        }
+    }
+}
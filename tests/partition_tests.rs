@@ -0,0 +1,134 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::int::point::IntPoint;
+    use i_shape::int::path::{IntPath, PointPathExtension};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use i_shape::int::shape::IntShapes;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::{Overlay, ShapeType};
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::partition::Partition;
+    use i_overlay::core::solver::Solver;
+    use crate::data::overlay::BooleanTest;
+    use crate::util::corpus::boolean_indices;
+    use crate::util::shapes::area;
+
+    #[test]
+    fn test_overlay_results() {
+        for index in boolean_indices() {
+            let test = BooleanTest::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+
+            let mut partition = Partition::new(0);
+            partition.add_contours(&test.subj_paths, 0);
+            partition.add_contours(&test.clip_paths, 1);
+            let faces = partition.into_faces(fill_rule);
+
+            let graph = Overlay::with_contours(&test.subj_paths, &test.clip_paths).into_graph(fill_rule);
+            let graph_faces = graph.extract_faces();
+
+            for (rule, membership) in [
+                (OverlayRule::Difference, vec![0]),
+                (OverlayRule::Intersect, vec![0, 1]),
+                (OverlayRule::InverseDifference, vec![1]),
+            ] {
+                let expected = area(&graph.extract_shapes(rule));
+                let faces_area: i64 = faces.iter()
+                    .filter(|face| face.membership == membership)
+                    .map(|face| face.shape.iter().map(|contour| contour.unsafe_area()).sum::<i64>())
+                    .sum();
+                let graph_area: i64 = graph_faces.iter()
+                    .filter(|face| face.membership == membership)
+                    .map(|face| face.shape.iter().map(|contour| contour.unsafe_area()).sum::<i64>())
+                    .sum();
+
+                assert_eq!(faces_area, expected, "test_{} {}", index, rule);
+                assert_eq!(graph_area, expected, "test_{} {}", index, rule);
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_groups() {
+        // the faces do not overlap and every group covers the area of its own overlay,
+        // up to the rounding of the intersections with the other groups
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            let groups: Vec<Vec<IntPath>> = (0..4)
+                .map(|_| (0..2).map(|_| (0..5).map(|_| IntPoint::new(rng.gen_range(-1000..=1000), rng.gen_range(-1000..=1000))).collect()).collect())
+                .collect();
+
+            for fill_rule in [FillRule::EvenOdd, FillRule::NonZero, FillRule::Positive, FillRule::Negative] {
+                let mut partition = Partition::new(0);
+                for (group, contours) in groups.iter().enumerate() {
+                    partition.add_contours(contours, group);
+                }
+                let faces = partition.clone().into_faces_with_solver(fill_rule, Solver::LIST);
+                assert_eq!(faces, partition.into_faces_with_solver(fill_rule, Solver::TREE));
+
+                let shapes: IntShapes = faces.iter().map(|face| face.shape.clone()).collect();
+                let union = Overlay::with_shapes(&shapes, &[]).overlay(OverlayRule::Subject, FillRule::NonZero);
+                assert_eq!(area(&shapes), area(&union));
+
+                for (group, contours) in groups.iter().enumerate() {
+                    let perimeter: i64 = contours.iter()
+                        .map(|contour| (0..contour.len()).map(|i| {
+                            let (a, b) = (contour[i], contour[(i + 1) % contour.len()]);
+                            (a.x - b.x).abs().max((a.y - b.y).abs()) as i64
+                        }).sum::<i64>())
+                        .sum();
+
+                    let mut overlay = Overlay::new(0);
+                    overlay.add_contours(contours, ShapeType::Subject);
+                    let expected = area(&overlay.overlay(OverlayRule::Subject, fill_rule));
+
+                    let faces_area: i64 = faces.iter()
+                        .filter(|face| face.membership.contains(&group))
+                        .map(|face| face.shape.iter().map(|contour| contour.unsafe_area()).sum::<i64>())
+                        .sum();
+
+                    assert!((faces_area - expected).abs() <= 4 * perimeter, "{:?} {:?}", fill_rule, groups);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_pairs() {
+        // every face of a pair is exactly the result of the matching boolean operation,
+        // the seed is fixed to keep the comparison reproducible
+        let mut rng = StdRng::seed_from_u64(46);
+        for range in [5, 20, 1000] {
+            for _ in 0..2000 {
+                let n = rng.gen_range(3..=6);
+                let [subj, clip]: [IntPath; 2] = core::array::from_fn(|_| (0..n)
+                    .map(|_| IntPoint::new(rng.gen_range(-range..=range), rng.gen_range(-range..=range)))
+                    .collect());
+
+                for fill_rule in [FillRule::EvenOdd, FillRule::NonZero, FillRule::Positive, FillRule::Negative] {
+                    let mut partition = Partition::new(2 * n);
+                    partition.add_contour(&subj, 0);
+                    partition.add_contour(&clip, 1);
+                    let faces = partition.into_faces(fill_rule);
+
+                    for (rule, membership) in [
+                        (OverlayRule::Difference, vec![0]),
+                        (OverlayRule::Intersect, vec![0, 1]),
+                        (OverlayRule::InverseDifference, vec![1]),
+                    ] {
+                        let shapes: IntShapes = faces.iter()
+                            .filter(|face| face.membership == membership)
+                            .map(|face| face.shape.clone())
+                            .collect();
+                        let expected = Overlay::with_contour(&subj, &clip).overlay(rule, fill_rule);
+                        assert_eq!(shapes, expected, "{:?} {:?} {:?} {:?}", rule, fill_rule, subj, clip);
+                    }
+                }
+            }
+        }
+    }
+}
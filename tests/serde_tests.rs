@@ -0,0 +1,59 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod tests {
+    use i_float::int::point::IntPoint;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::ShapeType;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::{Precision, Solver, Strategy};
+    use i_overlay::float::filter::ContourFilter;
+    use i_overlay::string::clip::ClipRule;
+    use i_overlay::string::rule::StringRule;
+    use i_overlay::vector::edge::{VectorEdge, SUBJ_LEFT};
+
+    #[test]
+    fn test_enums() {
+        assert_eq!(serde_json::to_string(&FillRule::NonZero).unwrap(), "\"NonZero\"");
+        assert_eq!(serde_json::to_string(&OverlayRule::Union).unwrap(), "\"Union\"");
+        assert_eq!(serde_json::to_string(&ShapeType::Clip).unwrap(), "\"Clip\"");
+        assert_eq!(serde_json::to_string(&StringRule::Slice).unwrap(), "\"Slice\"");
+
+        let fill_rule: FillRule = serde_json::from_str("\"EvenOdd\"").unwrap();
+        assert_eq!(fill_rule, FillRule::EvenOdd);
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver = Solver { strategy: Strategy::Tree, precision: Precision::Absolute, multithreading: None };
+        let json = serde_json::to_string(&solver).unwrap();
+        assert_eq!(json, r#"{"strategy":"Tree","precision":"Absolute","multithreading":null}"#);
+
+        let restored: Solver = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.strategy, Strategy::Tree);
+        assert_eq!(restored.precision, Precision::Absolute);
+        assert!(restored.multithreading.is_none());
+
+        // missing fields are taken from the default solver
+        let partial: Solver = serde_json::from_str(r#"{"strategy":"List"}"#).unwrap();
+        assert_eq!(partial.strategy, Strategy::List);
+        assert_eq!(partial.precision, Solver::AUTO.precision);
+        assert!(partial.multithreading.is_some());
+    }
+
+    #[test]
+    fn test_structs() {
+        let clip_rule = ClipRule { invert: true, boundary_included: false };
+        let json = serde_json::to_string(&clip_rule).unwrap();
+        assert_eq!(json, r#"{"invert":true,"boundary_included":false}"#);
+
+        let filter: ContourFilter<f64> = serde_json::from_str(r#"{"min_area":2.5,"simplify":true}"#).unwrap();
+        assert_eq!(filter.min_area, 2.5);
+        assert!(filter.simplify);
+
+        let edge = VectorEdge { a: IntPoint::new(0, 0), b: IntPoint::new(1, 2), fill: SUBJ_LEFT };
+        let json = serde_json::to_string(&edge).unwrap();
+        assert_eq!(json, r#"{"a":{"x":0,"y":0},"b":{"x":1,"y":2},"fill":1}"#);
+        assert_eq!(serde_json::from_str::<VectorEdge>(&json).unwrap(), edge);
+    }
+}
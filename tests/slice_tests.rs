@@ -4,6 +4,7 @@ mod tests {
     use i_shape::int::path::IntPath;
     use rand::Rng;
     use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::string::label::CutSide;
     use i_overlay::string::line::IntLine;
     use i_overlay::string::slice::IntSlice;
 
@@ -520,4 +521,34 @@ mod tests {
 
         lines
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_random_labeled() {
+        let square = [
+            IntPoint::new(-100, -100),
+            IntPoint::new(-100, 100),
+            IntPoint::new(100, 100),
+            IntPoint::new(100, -100)
+        ].to_vec();
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let a = IntPoint::new(-200, rng.gen_range(-90..=90));
+            let b = IntPoint::new(200, rng.gen_range(-90..=90));
+            let line = if rng.gen_bool(0.5) { [a, b] } else { [b, a] };
+
+            let pieces = square.slice_by_lines_labeled(&[line], FillRule::NonZero);
+            assert_eq!(pieces.len(), 2);
+
+            for piece in pieces.iter() {
+                // the doubled signed area of the triangle formed by the line and a vertex, positive on the left
+                let area: i64 = piece.shape[0].iter().map(|p| {
+                    let (dx, dy) = ((line[1].x - line[0].x) as i64, (line[1].y - line[0].y) as i64);
+                    dx * (p.y - line[0].y) as i64 - dy * (p.x - line[0].x) as i64
+                }).sum();
+                let expected = if area > 0 { CutSide::Left } else { CutSide::Right };
+                assert_eq!(piece.sides, vec![expected], "{:?}", line);
+            }
+        }
+    }
+}
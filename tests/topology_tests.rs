@@ -0,0 +1,71 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use i_float::int::point::IntPoint;
+    use i_shape::int::path::PointPathExtension;
+    use i_shape::int::shape::IntShape;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::topology::TopologyPolicy;
+    use crate::data::overlay::BooleanTest;
+    use crate::util::corpus::boolean_indices;
+    use crate::util::shapes::area;
+
+    fn root(parents: &mut [usize], mut i: usize) -> usize {
+        while parents[i] != i {
+            i = parents[i];
+        }
+        i
+    }
+
+    /// Every ring is simple, holes are counterclockwise and the rings touching at points do not cut the interior.
+    fn is_ogc_valid(shape: &IntShape) -> bool {
+        let mut parents: Vec<usize> = (0..shape.len()).collect();
+        let mut touches: BTreeMap<IntPoint, usize> = BTreeMap::new();
+        for (i, ring) in shape.iter().enumerate() {
+            if (i == 0) != (ring.unsafe_area() > 0) {
+                return false;
+            }
+            let mut points = ring.clone();
+            points.sort_unstable();
+            if points.windows(2).any(|w| w[0] == w[1]) {
+                return false;
+            }
+            for p in points.into_iter() {
+                if let Some(&j) = touches.get(&p) {
+                    let (ri, rj) = (root(&mut parents, i), root(&mut parents, j));
+                    if ri == rj {
+                        return false;
+                    }
+                    parents[ri] = rj;
+                } else {
+                    touches.insert(p, i);
+                }
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn test_overlay_results() {
+        for index in boolean_indices() {
+            let test = BooleanTest::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            let graph = Overlay::with_contours(&test.subj_paths, &test.clip_paths).into_graph(fill_rule);
+            for rule in [OverlayRule::Subject, OverlayRule::Clip, OverlayRule::Union, OverlayRule::Intersect, OverlayRule::Difference, OverlayRule::Xor] {
+                let minimal = graph.extract_shapes_with_policy(rule, TopologyPolicy::MinimalRings);
+                assert_eq!(minimal, graph.extract_shapes(rule));
+
+                let ogc = graph.extract_shapes_with_policy(rule, TopologyPolicy::OgcValid);
+                assert_eq!(area(&ogc), area(&minimal), "test_{} {}", index, rule);
+                for shape in ogc.iter() {
+                    assert!(is_ogc_valid(shape), "test_{} {}: {:?}", index, rule, shape);
+                }
+            }
+        }
+    }
+}
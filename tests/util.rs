@@ -3,75 +3,16 @@ pub mod overlay {
     use i_float::int::point::IntPoint;
     use i_shape::int::count::IntShapes;
     use i_shape::int::path::{IntPath, IntPaths};
-    use i_shape::int::shape::IntShape;
-
-    pub trait CircleCompare {
-        fn are_equal(&self, other: &Self) -> bool;
-    }
-
-    impl CircleCompare for Vec<IntPoint> {
-        fn are_equal(&self, other: &Self) -> bool {
-            if self.len() != other.len() {
-                return false;
-            }
-
-            let len = other.len();
-
-            for shift in 0..len {
-                let mut is_equal = true;
-                for i in 0..len {
-                    if self[(i + shift) % len] != other[i] {
-                        is_equal = false;
-                        break;
-                    }
-                }
-                if is_equal {
-                    return true;
-                }
-            }
+    use i_overlay::core::canonical::Canonical;
 
-            false
-        }
-    }
-
-    impl CircleCompare for Vec<IntShape> {
-        fn are_equal(&self, other: &Self) -> bool {
-            if self.len() != other.len() {
-                return false;
-            }
-
-            for i in 0..self.len() {
-                let shape_0 = &self[i];
-                let shape_1 = &other[i];
-                if shape_0.len() != shape_1.len() {
-                    return false;
-                }
-
-                for j in 0..shape_0.len() {
-                    let path_0 = &shape_0[j];
-                    let path_1 = &shape_1[j];
-                    if !path_0.are_equal(path_1) {
-                        return false;
-                    }
-                }
-            }
-
-            true
-        }
-    }
-
-    pub fn is_group_of_shapes_one_of(group: &Vec<IntShape>, groups: &Vec<Vec<IntShape>>) -> bool {
-        for item in groups.iter() {
-            if item.are_equal(group) {
-                return true;
-            }
-        }
-
-        false
+    #[allow(dead_code)]
+    pub fn is_group_of_shapes_one_of(group: &IntShapes, groups: &[IntShapes]) -> bool {
+        let group = group.to_canonical();
+        groups.iter().any(|item| item.to_canonical() == group)
     }
 
     #[allow(dead_code)]
-    pub fn is_paths_one_of(paths: &IntPaths, groups: &Vec<IntPaths>) -> bool {
+    pub fn is_paths_one_of(paths: &IntPaths, groups: &[IntPaths]) -> bool {
         for item in groups.iter() {
             if item.eq(paths) {
                 return true;
@@ -81,6 +22,7 @@ pub mod overlay {
         false
     }
 
+    #[allow(dead_code)]
     pub trait JsonPrint {
         fn json_print(&self) -> String;
     }
@@ -137,3 +79,62 @@ pub mod overlay {
     }
 }
 
+#[cfg(test)]
+pub mod shapes {
+    use i_float::int::point::IntPoint;
+    use i_shape::int::path::{IntPath, PointPathExtension};
+    use i_shape::int::shape::IntShape;
+    use rand::Rng;
+
+    /// The doubled area of shapes, positive for clockwise outer contours.
+    #[allow(dead_code)]
+    pub fn area(shapes: &[IntShape]) -> i64 {
+        shapes.iter().flatten().map(|contour| contour.unsafe_area()).sum()
+    }
+
+    #[allow(dead_code)]
+    pub fn random_polygon<R: Rng>(rng: &mut R, n: usize, range: i32) -> IntPath {
+        (0..n).map(|_| IntPoint::new(rng.gen_range(-range..=range), rng.gen_range(-range..=range))).collect()
+    }
+
+    /// A clockwise circle of radius about 1000 with `n` noisy vertices.
+    #[allow(dead_code)]
+    pub fn noisy_circle<R: Rng>(rng: &mut R, n: usize) -> IntPath {
+        (0..n).map(|i| {
+            let a = -(i as f64) * core::f64::consts::TAU / n as f64;
+            let r = 1000.0 + rng.gen_range(-30.0..30.0);
+            IntPoint::new((r * a.cos()) as i32, (r * a.sin()) as i32)
+        }).collect()
+    }
+
+    /// A noisy open path that crosses `noisy_circle` from left to right.
+    #[allow(dead_code)]
+    pub fn noisy_cut<R: Rng>(rng: &mut R) -> IntPath {
+        let y0 = rng.gen_range(-800..800);
+        let y1 = rng.gen_range(-800..800);
+        (0..=40).map(|i| {
+            let x = -1200 + 60 * i;
+            let y = y0 + (y1 - y0) * i / 40 + rng.gen_range(-15..15);
+            IntPoint::new(x, y)
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+pub mod corpus {
+    use std::fs;
+
+    /// The indices of all `tests/boolean/test_*.json` files, sorted.
+    #[allow(dead_code)]
+    pub fn boolean_indices() -> Vec<usize> {
+        let mut indices: Vec<usize> = fs::read_dir("./tests/boolean")
+            .expect("tests/boolean is readable")
+            .filter_map(|entry| {
+                let name = entry.ok()?.file_name().into_string().ok()?;
+                name.strip_prefix("test_")?.strip_suffix(".json")?.parse().ok()
+            })
+            .collect();
+        indices.sort_unstable();
+        indices
+    }
+}
@@ -0,0 +1,42 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::validation::{Issue, Validate};
+    use crate::data::overlay::BooleanTest;
+    use crate::util::corpus::boolean_indices;
+
+    #[test]
+    fn test_overlay_results() {
+        for index in boolean_indices() {
+            let test = BooleanTest::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            for rule in [OverlayRule::Union, OverlayRule::Intersect, OverlayRule::Difference, OverlayRule::Xor] {
+                let shapes = Overlay::with_contours(&test.subj_paths, &test.clip_paths).overlay(rule, fill_rule);
+
+                // a result contour may pass twice through the same vertex, nothing else is allowed
+                let report = shapes.validate();
+                for issue in report.issues.iter() {
+                    assert!(matches!(issue, Issue::Touching { .. }), "test_{} {}: {:?}", index, rule, issue);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_input_issues() {
+        // test_0 subject is valid, the self-intersecting input of other cases is not
+        let test = BooleanTest::load(0);
+        assert!(test.subj_paths.validate().is_valid());
+
+        let invalid = boolean_indices().into_iter()
+            .map(BooleanTest::load)
+            .filter(|test| !test.subj_paths.validate().is_valid())
+            .count();
+        assert!(invalid > 0);
+    }
+}